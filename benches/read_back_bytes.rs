@@ -0,0 +1,72 @@
+use std::{
+    fs::File,
+    io::{Seek, SeekFrom},
+};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use read_collection::{BufReadBack, BufReadBacker, ReadBack};
+
+const TEST_FILE: &str = "./tests/file/test_file1.txt";
+
+/// Both iterators read back the same file and the same slice, so their results are comparable.
+fn read_back_bytes(c: &mut Criterion) {
+    let content = std::fs::read(TEST_FILE).unwrap();
+
+    let mut group = c.benchmark_group("read_back_bytes");
+
+    group.bench_function("read_back_bytes (file)", |b| {
+        b.iter(|| {
+            let mut file = File::open(TEST_FILE).unwrap();
+            file.seek(SeekFrom::End(0)).unwrap();
+
+            file.read_back_bytes()
+                .map(|b| b.unwrap() as u64)
+                .sum::<u64>()
+        })
+    });
+
+    group.bench_function("read_back_bytes (BufReadBacker over file)", |b| {
+        b.iter(|| {
+            BufReadBacker::new(File::open(TEST_FILE).unwrap())
+                .unwrap()
+                .read_back_bytes()
+                .map(|b| b.unwrap() as u64)
+                .sum::<u64>()
+        })
+    });
+
+    group.bench_function("read_back_bytes_buffered (BufReadBacker over file)", |b| {
+        b.iter(|| {
+            BufReadBacker::new(File::open(TEST_FILE).unwrap())
+                .unwrap()
+                .read_back_bytes_buffered()
+                .map(|b| b.unwrap() as u64)
+                .sum::<u64>()
+        })
+    });
+
+    group.bench_function("read_back_bytes (slice)", |b| {
+        b.iter(|| {
+            content
+                .as_slice()
+                .read_back_bytes()
+                .map(|b| b.unwrap() as u64)
+                .sum::<u64>()
+        })
+    });
+
+    group.bench_function("read_back_bytes_buffered (slice)", |b| {
+        b.iter(|| {
+            content
+                .as_slice()
+                .read_back_bytes_buffered()
+                .map(|b| b.unwrap() as u64)
+                .sum::<u64>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, read_back_bytes);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use read_collection::ReadBack;
+
+fn read_back_to_string(c: &mut Criterion) {
+    let ascii = "The quick brown fox jumps over the lazy dog.\n".repeat(1 << 14);
+    let multi_byte = "Größe: 10 €, Notenschlüssel: 𝄞\n".repeat(1 << 14);
+
+    let mut group = c.benchmark_group("read_back_to_string");
+
+    group.bench_function("ascii", |b| {
+        b.iter(|| {
+            let mut buffer = String::new();
+            ascii.as_bytes().read_back_to_string(&mut buffer).unwrap()
+        })
+    });
+
+    group.bench_function("multi byte", |b| {
+        b.iter(|| {
+            let mut buffer = String::new();
+            multi_byte
+                .as_bytes()
+                .read_back_to_string(&mut buffer)
+                .unwrap()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, read_back_to_string);
+criterion_main!(benches);
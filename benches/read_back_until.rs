@@ -0,0 +1,44 @@
+use std::io::Cursor;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use read_collection::{BufReadBack, BufReadBacker};
+
+fn read_back_until(c: &mut Criterion) {
+    let lines = "The quick brown fox jumps over the lazy dog.\n".repeat(1 << 14);
+    let no_delimiter = vec![b'x'; 1 << 20];
+
+    let mut group = c.benchmark_group("read_back_until");
+
+    group.bench_function("lines (slice)", |b| {
+        b.iter(|| {
+            let mut data = lines.as_bytes();
+            let mut buffer = Vec::new();
+            while data.read_back_until(b'\n', &mut buffer).unwrap() != 0 {
+                buffer.clear();
+            }
+        })
+    });
+
+    group.bench_function("lines (BufReadBacker)", |b| {
+        b.iter(|| {
+            let mut reader = BufReadBacker::new(Cursor::new(lines.as_bytes())).unwrap();
+            let mut buffer = Vec::new();
+            while reader.read_back_until(b'\n', &mut buffer).unwrap() != 0 {
+                buffer.clear();
+            }
+        })
+    });
+
+    group.bench_function("no delimiter (BufReadBacker)", |b| {
+        b.iter(|| {
+            let mut reader = BufReadBacker::new(Cursor::new(no_delimiter.as_slice())).unwrap();
+            let mut buffer = Vec::new();
+            reader.read_back_until(b'\n', &mut buffer).unwrap()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, read_back_until);
+criterion_main!(benches);
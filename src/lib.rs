@@ -39,4 +39,27 @@ const DEFAULT_BUF_SIZE: usize = if cfg!(target_os = "espidf") {
     8 * 1024
 };
 
-pub use read_back::{BufReadBack, ReadBack, ReadBackBytes, ReadBackChain, ReadBackSplit};
+pub use read_back::{
+    read_back_copy, Base32Alphabet, BufReadBack, BufReadBacker, LineEnding, ReadBack,
+    ReadBackBytes, ReadBackBytesBuffered, ReadBackChain, ReadBackSlurp, ReadBackSplit,
+    ReadBackTake, RevBase32Decoder, RevBorrowedBuf, RevBorrowedCursor, RevBorrowedRegion,
+    RevBorrowedSplit, RevChars, RevCounter, RevCursor, RevFixedRecords, RevIndexedRecords,
+    RevLines, RevLinesIndexed, RevLogicalLines, RevOwnedBuf, RevOwnedBufBorrow, RevRangeReader,
+    RevRecords, RevSeek, RevSeekFrom, RevShardedReader, RevToForward, RevUntilStop, Reverser,
+    SharedVecReadBacker,
+};
+
+#[cfg(feature = "gzip")]
+pub use read_back::{FrameLength, FramedGzReadBacker, IndexedGzReadBacker};
+
+#[cfg(feature = "metrics")]
+pub use read_back::RevMetrics;
+
+#[cfg(feature = "mmap")]
+pub use read_back::MmapReadBacker;
+
+#[cfg(feature = "tokio")]
+pub use read_back::{
+    AsyncBufReadBacker, AsyncReadBack, AsyncReadBackExt, ReadBackExactFuture, ReadBackFuture,
+    ReadBackToEndFuture,
+};
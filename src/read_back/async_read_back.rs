@@ -0,0 +1,522 @@
+use std::future::Future;
+use std::io::{self, ErrorKind, SeekFrom};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::{cmp, mem};
+
+use tokio::io::{AsyncRead, AsyncSeek, AsyncSeekExt, ReadBuf};
+
+use crate::DEFAULT_BUF_SIZE;
+
+/// The asynchronous version of [`ReadBack`], mirroring tokio's [`AsyncRead`].
+///
+/// Most users want the methods of [`AsyncReadBackExt`] instead of calling [`poll_read_back`] directly.
+///
+/// [`ReadBack`]: crate::ReadBack
+/// [`poll_read_back`]: AsyncReadBack::poll_read_back
+pub trait AsyncReadBack {
+    /// Attempts to read back the bytes in front of the current position into the unfilled part of `buf`.
+    ///
+    /// Like for [`ReadBack::read_back`], the read bytes are the last ones in front of the position and they're
+    /// appended to the filled part of `buf` in the order of the source. Not appending anything while `buf` has
+    /// space left means that the start of the source has been reached.
+    ///
+    /// If no bytes are available yet, `Poll::Pending` is returned and the current task is woken up once reading
+    /// back can make progress.
+    ///
+    /// [`ReadBack::read_back`]: crate::ReadBack::read_back
+    fn poll_read_back(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>>;
+}
+
+impl AsyncReadBack for &[u8] {
+    fn poll_read_back(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let amount = cmp::min(this.len(), buf.remaining());
+        let (left, right) = this.split_at(this.len() - amount);
+
+        buf.put_slice(right);
+        *this = left;
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T: AsyncReadBack + Unpin + ?Sized> AsyncReadBack for &mut T {
+    fn poll_read_back(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut **self).poll_read_back(cx, buf)
+    }
+}
+
+/// Adds the asynchronous versions of the methods of [`ReadBack`] to every [`AsyncReadBack`]er.
+///
+/// # Example
+/// ```
+/// use read_collection::AsyncReadBackExt;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let mut data = b"first\nsecond".as_slice();
+///
+///     let mut last = [0; 6];
+///     data.read_back_exact(&mut last).await.unwrap();
+///     assert_eq!(&last, b"second");
+///
+///     let mut rest = Vec::new();
+///     data.read_back_to_end(&mut rest).await.unwrap();
+///     assert_eq!(rest, b"first\n");
+/// }
+/// ```
+///
+/// [`ReadBack`]: crate::ReadBack
+pub trait AsyncReadBackExt: AsyncReadBack {
+    /// Reads back some bytes into `buf`, like [`ReadBack::read_back`].
+    ///
+    /// The returned future resolves to the amount of bytes which have been read back into `buf[..n]`.
+    ///
+    /// [`ReadBack::read_back`]: crate::ReadBack::read_back
+    fn read_back<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadBackFuture<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadBackFuture { reader: self, buf }
+    }
+
+    /// Reads back all bytes until the start of the source and appends them to `buf` in the order of the source,
+    /// like [`ReadBack::read_back_to_end`].
+    ///
+    /// The returned future resolves to the amount of bytes which have been appended.
+    ///
+    /// [`ReadBack::read_back_to_end`]: crate::ReadBack::read_back_to_end
+    fn read_back_to_end<'a>(&'a mut self, buf: &'a mut Vec<u8>) -> ReadBackToEndFuture<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadBackToEndFuture {
+            reader: self,
+            buf,
+            reversed: Vec::new(),
+        }
+    }
+
+    /// Reads back exactly enough bytes to fill `buf`, like [`ReadBack::read_back_exact`].
+    ///
+    /// # Errors
+    /// The future resolves to an [`ErrorKind::UnexpectedEof`] error if the start of the source is reached before
+    /// `buf` is filled. The content of `buf` is unspecified in this case.
+    ///
+    /// [`ReadBack::read_back_exact`]: crate::ReadBack::read_back_exact
+    fn read_back_exact<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadBackExactFuture<'a, Self>
+    where
+        Self: Unpin,
+    {
+        ReadBackExactFuture { reader: self, buf }
+    }
+}
+
+impl<R: AsyncReadBack + ?Sized> AsyncReadBackExt for R {}
+
+/// The future of [`AsyncReadBackExt::read_back`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadBackFuture<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+}
+
+impl<R: AsyncReadBack + Unpin + ?Sized> Future for ReadBackFuture<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut buf = ReadBuf::new(this.buf);
+
+        ready!(Pin::new(&mut *this.reader).poll_read_back(cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+}
+
+/// The future of [`AsyncReadBackExt::read_back_to_end`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadBackToEndFuture<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut Vec<u8>,
+    /// The bytes which have been read back so far, in reversed order.
+    reversed: Vec<u8>,
+}
+
+impl<R: AsyncReadBack + Unpin + ?Sized> Future for ReadBackToEndFuture<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        loop {
+            this.reversed.reserve(DEFAULT_BUF_SIZE);
+            let len = this.reversed.len();
+
+            let mut buf = ReadBuf::uninit(this.reversed.spare_capacity_mut());
+            let ptr = buf.filled().as_ptr();
+            ready!(Pin::new(&mut *this.reader).poll_read_back(cx, &mut buf))?;
+            // like in tokio's `read_buf`, the length of a swapped buffer doesn't say anything about our memory
+            assert_eq!(ptr, buf.filled().as_ptr(), "buf must not be swapped");
+            let amount = buf.filled().len();
+
+            if amount == 0 {
+                break;
+            }
+
+            // SAFETY: `poll_read_back` initialized the first `amount` bytes of the spare capacity
+            unsafe { this.reversed.set_len(len + amount) };
+            this.reversed[len..].reverse();
+        }
+
+        let mut content = mem::take(&mut this.reversed);
+        let amount = content.len();
+        content.reverse();
+        this.buf.append(&mut content);
+
+        Poll::Ready(Ok(amount))
+    }
+}
+
+/// The future of [`AsyncReadBackExt::read_back_exact`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadBackExactFuture<'a, R: ?Sized> {
+    reader: &'a mut R,
+    /// The part of the buffer which hasn't been filled yet.
+    buf: &'a mut [u8],
+}
+
+impl<R: AsyncReadBack + Unpin + ?Sized> Future for ReadBackExactFuture<'_, R> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        while !this.buf.is_empty() {
+            let mut buf = ReadBuf::new(this.buf);
+            ready!(Pin::new(&mut *this.reader).poll_read_back(cx, &mut buf))?;
+            let amount = buf.filled().len();
+
+            if amount == 0 {
+                return Poll::Ready(Err(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "failed to fill whole buffer",
+                )));
+            }
+
+            // the read bytes are the last ones of `buf`
+            let buf = mem::take(&mut this.buf);
+            let buf_len = buf.len();
+            buf.copy_within(..amount, buf_len - amount);
+            this.buf = &mut buf[..buf_len - amount];
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// The state of a refill of the buffer of an [`AsyncBufReadBacker`].
+#[derive(Debug, Clone, Copy)]
+enum FillState {
+    Idle,
+    /// The underlying reader is seeking to `start`.
+    Seeking {
+        start: u64,
+        amount: usize,
+    },
+    /// `read` bytes of the block at `start` have been read into the buffer.
+    Reading {
+        start: u64,
+        amount: usize,
+        read: usize,
+    },
+}
+
+/// The asynchronous version of [`BufReadBacker`], for example for a tokio [`File`].
+///
+/// The position of the reader is stored as the offset of the buffered block in the source, so a refill which is
+/// interrupted by an `.await` (or dropped) simply starts again at the next poll: each refill seeks to the start of
+/// the block in front of the buffered one and reads it, so the position of the underlying reader doesn't matter
+/// in between.
+///
+/// # Example
+/// ```no_run
+/// use read_collection::{AsyncBufReadBacker, AsyncReadBackExt};
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() -> std::io::Result<()> {
+///     let file = tokio::fs::File::open("log.txt").await?;
+///     let mut reader = AsyncBufReadBacker::new(file).await?;
+///
+///     let mut tail = [0; 64];
+///     reader.read_back_exact(&mut tail).await?;
+///     println!("{}", String::from_utf8_lossy(&tail));
+///     Ok(())
+/// }
+/// ```
+///
+/// [`BufReadBacker`]: crate::BufReadBacker
+/// [`File`]: https://docs.rs/tokio/latest/tokio/fs/struct.File.html
+#[derive(Debug)]
+pub struct AsyncBufReadBacker<R> {
+    inner: R,
+    buf: Box<[u8]>,
+    /// The index (exclusively) until which `buf` hasn't been read back yet.
+    pos: usize,
+    /// The offset of `inner` where the buffered block starts.
+    block_start: u64,
+    state: FillState,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncBufReadBacker<R> {
+    /// Creates a new `AsyncBufReadBacker` with a default buffer capacity (currently 8 KiB) which starts reading
+    /// back at the end of `inner`.
+    pub async fn new(inner: R) -> io::Result<Self> {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner).await
+    }
+
+    /// Creates a new `AsyncBufReadBacker` with the given buffer capacity which starts reading back at the end of
+    /// `inner`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    pub async fn with_capacity(capacity: usize, mut inner: R) -> io::Result<Self> {
+        assert!(capacity > 0, "capacity has to be greater than 0");
+        let block_start = inner.seek(SeekFrom::End(0)).await?;
+
+        Ok(Self {
+            inner,
+            buf: vec![0; capacity].into_boxed_slice(),
+            pos: 0,
+            block_start,
+            state: FillState::Idle,
+        })
+    }
+
+    /// Returns the buffered bytes which haven't been read back yet, reading the block in front of them if there
+    /// aren't any. An empty slice is only returned at the start of the source.
+    pub fn poll_read_back_fill_buf(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        while self.pos == 0 && self.block_start > 0 {
+            match self.state {
+                FillState::Idle => {
+                    let amount = self.block_start.min(self.buf.len() as u64) as usize;
+                    let start = self.block_start - amount as u64;
+
+                    Pin::new(&mut self.inner).start_seek(SeekFrom::Start(start))?;
+                    self.state = FillState::Seeking { start, amount };
+                }
+                FillState::Seeking { start, amount } => {
+                    if let Err(err) = ready!(Pin::new(&mut self.inner).poll_complete(cx)) {
+                        self.state = FillState::Idle;
+                        return Poll::Ready(Err(err));
+                    }
+                    self.state = FillState::Reading {
+                        start,
+                        amount,
+                        read: 0,
+                    };
+                }
+                FillState::Reading {
+                    start,
+                    amount,
+                    read,
+                } if read == amount => {
+                    self.block_start = start;
+                    self.pos = amount;
+                    self.state = FillState::Idle;
+                }
+                FillState::Reading {
+                    start,
+                    amount,
+                    read,
+                } => {
+                    let mut buf = ReadBuf::new(&mut self.buf[read..amount]);
+                    if let Err(err) = ready!(Pin::new(&mut self.inner).poll_read(cx, &mut buf)) {
+                        self.state = FillState::Idle;
+                        return Poll::Ready(Err(err));
+                    }
+
+                    let n = buf.filled().len();
+                    if n == 0 {
+                        self.state = FillState::Idle;
+                        return Poll::Ready(Err(io::Error::new(
+                            ErrorKind::UnexpectedEof,
+                            "source ended before the read back position",
+                        )));
+                    }
+                    self.state = FillState::Reading {
+                        start,
+                        amount,
+                        read: read + n,
+                    };
+                }
+            }
+        }
+
+        Poll::Ready(Ok(self.buffer()))
+    }
+
+    /// Marks `amt` bytes of the buffer as read back, so they aren't returned anymore.
+    pub fn read_back_consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+}
+
+impl<R> AsyncBufReadBacker<R> {
+    /// Returns the number of bytes the internal buffer can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the buffered bytes which haven't been read back yet.
+    ///
+    /// Unlike [`poll_read_back_fill_buf`], this never reads from the underlying reader.
+    ///
+    /// [`poll_read_back_fill_buf`]: AsyncBufReadBacker::poll_read_back_fill_buf
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps this `AsyncBufReadBacker`, returning the underlying reader.
+    ///
+    /// The position of the underlying reader is unspecified and the bytes which are still in the buffer are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncReadBack for AsyncBufReadBacker<R> {
+    fn poll_read_back(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        let remaining = ready!(this.poll_read_back_fill_buf(cx))?;
+        let amount = cmp::min(remaining.len(), buf.remaining());
+        buf.put_slice(&remaining[remaining.len() - amount..]);
+        this.read_back_consume(amount);
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[tokio::test]
+    async fn read_back() {
+        let mut data = b"0123456789".as_slice();
+        let mut buffer = [0; 4];
+
+        assert_eq!(data.read_back(&mut buffer).await.ok(), Some(4));
+        assert_eq!(&buffer, b"6789");
+        assert_eq!(data.read_back(&mut buffer).await.ok(), Some(4));
+        assert_eq!(&buffer, b"2345");
+        assert_eq!(data.read_back(&mut buffer).await.ok(), Some(2));
+        assert_eq!(&buffer[..2], b"01");
+        assert_eq!(data.read_back(&mut buffer).await.ok(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn read_back_to_end_appends() {
+        let data = (0..3 * DEFAULT_BUF_SIZE + 5)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<u8>>();
+        let mut reader = AsyncBufReadBacker::with_capacity(100, Cursor::new(data.as_slice()))
+            .await
+            .unwrap();
+        let mut buffer = vec![1, 2];
+
+        assert_eq!(
+            reader.read_back_to_end(&mut buffer).await.ok(),
+            Some(data.len())
+        );
+        assert_eq!(buffer[..2], [1, 2]);
+        assert_eq!(buffer[2..], data);
+    }
+
+    #[tokio::test]
+    async fn read_back_exact_across_blocks() {
+        let data = b"first|second|third";
+        let mut reader = AsyncBufReadBacker::with_capacity(4, Cursor::new(data.as_slice()))
+            .await
+            .unwrap();
+
+        let mut third = [0; 6];
+        reader.read_back_exact(&mut third).await.unwrap();
+        assert_eq!(&third, b"|third");
+
+        let mut too_long = [0; 20];
+        let err = reader.read_back_exact(&mut too_long).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[tokio::test]
+    async fn fill_buf_and_consume() {
+        let data = b"0123456789";
+        let mut reader = AsyncBufReadBacker::with_capacity(4, Cursor::new(data.as_slice()))
+            .await
+            .unwrap();
+
+        let block =
+            std::future::poll_fn(|cx| reader.poll_read_back_fill_buf(cx).map_ok(<[u8]>::to_vec))
+                .await
+                .unwrap();
+        assert_eq!(block, b"6789");
+        reader.read_back_consume(1);
+        assert_eq!(reader.buffer(), b"678");
+
+        reader.read_back_consume(3);
+        let mut rest = Vec::new();
+        reader.read_back_to_end(&mut rest).await.unwrap();
+        assert_eq!(rest, b"012345");
+    }
+    /// A reader which hands out its own buffer instead of filling the given one.
+    struct Swapping;
+
+    impl AsyncReadBack for Swapping {
+        fn poll_read_back(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            let other: &'static mut [u8] =
+                Box::leak(vec![0; 4 * DEFAULT_BUF_SIZE].into_boxed_slice());
+            *buf = ReadBuf::new(other);
+            buf.set_filled(4 * DEFAULT_BUF_SIZE);
+
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "buf must not be swapped")]
+    async fn read_back_to_end_swapped_buf() {
+        let mut buffer = Vec::new();
+        let _ = Swapping.read_back_to_end(&mut buffer).await;
+    }
+}
@@ -0,0 +1,301 @@
+use std::io;
+
+use crate::{BufReadBack, ReadBack, DEFAULT_BUF_SIZE};
+
+/// The alphabets which can be decoded by a [`RevBase32Decoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Base32Alphabet {
+    /// The standard alphabet of RFC 4648 (`A-Z` and `2-7`).
+    #[default]
+    Rfc4648,
+    /// The alphabet of Douglas Crockford (`0-9` and `A-Z` without `I`, `L`, `O` and `U`).
+    ///
+    /// As the alphabet intends, lower case letters are accepted as well, `I` and `L` are decoded as `1`, `O` as `0`
+    /// and hyphens are ignored.
+    Crockford,
+}
+
+impl Base32Alphabet {
+    /// Returns the 5 bit value of the given character, `None` if it isn't part of the alphabet.
+    fn decode(self, c: u8) -> Option<u8> {
+        match self {
+            Self::Rfc4648 => match c {
+                b'A'..=b'Z' => Some(c - b'A'),
+                b'2'..=b'7' => Some(c - b'2' + 26),
+                _ => None,
+            },
+            Self::Crockford => {
+                const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+                let c = match c.to_ascii_uppercase() {
+                    b'O' => b'0',
+                    b'I' | b'L' => b'1',
+                    c => c,
+                };
+                ALPHABET.iter().position(|&a| a == c).map(|v| v as u8)
+            }
+        }
+    }
+
+    /// Whether the character can be skipped, like the hyphens which Crockford's alphabet allows for readability.
+    fn is_ignored(self, c: u8) -> bool {
+        self == Self::Crockford && c == b'-'
+    }
+}
+
+/// Marks a padding character (`=`) in the pending symbols.
+const PADDING: u8 = u8::MAX;
+
+/// Decodes a base32 encoded [`ReadBack`]er while reading it back, so the last decoded byte comes first.
+///
+/// Base32 maps each group of 8 characters to 5 bytes, starting at the front of the encoded text. Reading back
+/// therefore needs the groups to line up with the end of the text as well, which is why the encoded text has to
+/// be padded with `=` to a multiple of 8 characters. Padding is only allowed at the very end.
+///
+/// # Example
+/// ```
+/// use read_collection::{Base32Alphabet, ReadBack, RevBase32Decoder};
+///
+/// fn main() {
+///     let encoded = b"MZXW6YTBOI======";
+///     let mut decoder = RevBase32Decoder::new(encoded.as_slice(), Base32Alphabet::Rfc4648);
+///
+///     let mut buffer = [0; 3];
+///     decoder.read_back_exact(&mut buffer).unwrap();
+///     assert_eq!(&buffer, b"bar");
+///     decoder.read_back_exact(&mut buffer).unwrap();
+///     assert_eq!(&buffer, b"foo");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RevBase32Decoder<R> {
+    inner: R,
+    alphabet: Base32Alphabet,
+    /// The 5 bit values of the characters which have been read back but don't make up a whole group yet, in
+    /// forward order.
+    pending: Vec<u8>,
+    /// Whether the last group of the encoded text has been decoded, the only one which may contain padding.
+    started: bool,
+    decoded: Vec<u8>,
+    /// The index (exclusively) until which `decoded` hasn't been read back yet.
+    pos: usize,
+    /// The scratch buffer which the encoded characters are read back into, reused for each refill.
+    chunk: Box<[u8]>,
+}
+
+impl<R: ReadBack> RevBase32Decoder<R> {
+    /// Creates a new `RevBase32Decoder` which decodes `inner` with the given alphabet.
+    pub fn new(inner: R, alphabet: Base32Alphabet) -> Self {
+        Self {
+            inner,
+            alphabet,
+            pending: Vec::new(),
+            started: false,
+            decoded: Vec::new(),
+            pos: 0,
+            chunk: vec![0; DEFAULT_BUF_SIZE].into_boxed_slice(),
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps this `RevBase32Decoder`, returning the underlying reader.
+    ///
+    /// Encoded characters which have been read back but not decoded yet are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads back encoded characters until at least one group can be decoded and decodes all whole groups at the
+    /// end of them.
+    fn decode_prev_groups(&mut self) -> io::Result<()> {
+        while self.pending.len() < 8 {
+            let amount = match self.inner.read_back(&mut self.chunk) {
+                Ok(0) if self.pending.is_empty() => return Ok(()),
+                Ok(0) => {
+                    return Err(invalid_data(
+                        "base32 input isn't padded to a multiple of 8 characters",
+                    ))
+                }
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            let mut symbols = Vec::with_capacity(amount + self.pending.len());
+            for &c in &self.chunk[..amount] {
+                if self.alphabet.is_ignored(c) {
+                    continue;
+                } else if c == b'=' {
+                    symbols.push(PADDING);
+                } else {
+                    let value = self
+                        .alphabet
+                        .decode(c)
+                        .ok_or_else(|| invalid_data("invalid base32 character"))?;
+                    symbols.push(value);
+                }
+            }
+            symbols.append(&mut self.pending);
+            self.pending = symbols;
+        }
+
+        let groups_start = self.pending.len() % 8;
+        let last_group = (self.pending.len() - groups_start) / 8 - 1;
+        self.decoded.clear();
+        for (index, group) in self.pending[groups_start..]
+            .as_chunks::<8>()
+            .0
+            .iter()
+            .enumerate()
+        {
+            let padding = group.iter().rev().take_while(|&&v| v == PADDING).count();
+            if padding > 0 && (self.started || index != last_group) {
+                return Err(invalid_data("base32 padding in front of the end"));
+            }
+            let amount = match padding {
+                0 => 5,
+                1 => 4,
+                3 => 3,
+                4 => 2,
+                6 => 1,
+                _ => return Err(invalid_data("invalid amount of base32 padding")),
+            };
+
+            let mut bits: u64 = 0;
+            for &value in &group[..8 - padding] {
+                if value == PADDING {
+                    return Err(invalid_data("base32 padding in front of the end"));
+                }
+                bits = (bits << 5) | u64::from(value);
+            }
+            bits <<= 5 * padding;
+
+            self.decoded
+                .extend_from_slice(&bits.to_be_bytes()[3..3 + amount]);
+        }
+
+        self.pending.truncate(groups_start);
+        self.started = true;
+        self.pos = self.decoded.len();
+
+        Ok(())
+    }
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl<R: ReadBack> ReadBack for RevBase32Decoder<R> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.read_back_fill_buf()?.read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+}
+
+impl<R: ReadBack> BufReadBack for RevBase32Decoder<R> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == 0 {
+            self.decode_prev_groups()?;
+        }
+
+        Ok(&self.decoded[..self.pos])
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_back(encoded: &[u8], alphabet: Base32Alphabet) -> io::Result<Vec<u8>> {
+        let mut decoded = Vec::new();
+        RevBase32Decoder::new(encoded, alphabet).read_back_to_end(&mut decoded)?;
+        Ok(decoded)
+    }
+
+    #[test]
+    fn rfc4648() {
+        let encoded = b"JBSWY3DPFQQFO33SNRSCCICCMFZWKMZSEBZGKYLEEBRGCY3LFY======";
+
+        assert_eq!(
+            decode_back(encoded, Base32Alphabet::Rfc4648).unwrap(),
+            b"Hello, World! Base32 read back."
+        );
+    }
+
+    #[test]
+    fn crockford() {
+        assert_eq!(
+            decode_back(b"CSQPYRK1E8======", Base32Alphabet::Crockford).unwrap(),
+            b"foobar"
+        );
+        // lower case, hyphens and the ambiguous characters
+        assert_eq!(
+            decode_back(b"csqp-yrki-e8======", Base32Alphabet::Crockford).unwrap(),
+            b"foobar"
+        );
+    }
+
+    #[test]
+    fn tail_first() {
+        let mut decoder =
+            RevBase32Decoder::new(b"MZXW6YTBOI======".as_slice(), Base32Alphabet::Rfc4648);
+
+        let mut bytes = Vec::new();
+        let mut buffer = [0; 1];
+        while decoder.read_back(&mut buffer).unwrap() > 0 {
+            bytes.push(buffer[0]);
+        }
+        assert_eq!(&bytes, b"raboof");
+    }
+
+    #[test]
+    fn all_padding_lengths() {
+        let vectors: [(&[u8], &[u8]); 6] = [
+            (b"", b""),
+            (b"MY======", b"f"),
+            (b"MZXQ====", b"fo"),
+            (b"MZXW6===", b"foo"),
+            (b"MZXW6YQ=", b"foob"),
+            (b"MZXW6YTB", b"fooba"),
+        ];
+
+        for (encoded, decoded) in vectors {
+            assert_eq!(
+                decode_back(encoded, Base32Alphabet::Rfc4648).unwrap(),
+                decoded,
+                "encoded: {:?}",
+                encoded
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        for encoded in [
+            b"MZXW6YT".as_slice(),
+            b"MY======MZXW6YTB",
+            b"M=Y=====",
+            b"MZXW6YT1",
+            b"M=======",
+        ] {
+            let err = decode_back(encoded, Base32Alphabet::Rfc4648).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                io::ErrorKind::InvalidData,
+                "encoded: {:?}",
+                encoded
+            );
+        }
+    }
+}
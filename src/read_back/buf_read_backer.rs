@@ -0,0 +1,1878 @@
+use std::fmt;
+use std::io::{self, ErrorKind, IoSliceMut, Read, Seek, SeekFrom};
+
+use crate::read_back::{
+    default_buf_read_back_until, seek::invalid_rev_seek_error, seek_read_back_vectored,
+};
+use crate::{BufReadBack, ReadBack, RevSeek, RevSeekFrom, DEFAULT_BUF_SIZE};
+
+/// Adds buffering to any [`Read`]er which can [`Seek`], so it can be read back efficiently.
+///
+/// Reading back a [`File`] directly seeks for each call of [`read_back`], which is inefficient if the calls are
+/// small, like for [`read_back_line`]. A `BufReadBacker` instead reads the block in front of its position into an
+/// internal buffer with one seek and hands out the bytes from there. It's the read-back counterpart of
+/// [`BufReader`].
+///
+/// The block at the start of the source is usually smaller than the buffer, this is handled transparently.
+///
+/// With the `tracing` feature, each refill of the buffer is recorded in a `read_back_fill_buf` span with an event
+/// which contains the offset and the amount of the read bytes. Vectored reads which bypass the buffer are recorded
+/// the same way in a `read_back_vectored` span.
+///
+/// With the `metrics` feature, the operations on the underlying reader are counted in a `RevMetrics` which is
+/// returned by `metrics`.
+///
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use read_collection::{BufReadBack, BufReadBacker};
+///
+/// fn main() -> std::io::Result<()> {
+///     let reader = BufReadBacker::new(File::open("log.txt")?)?;
+///
+///     for line in reader.read_back_lines().take(10) {
+///         println!("{}", line?);
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// [`File`]: std::fs::File
+/// [`read_back`]: ReadBack::read_back
+/// [`read_back_line`]: BufReadBack::read_back_line
+/// [`BufReader`]: std::io::BufReader
+#[derive(Debug)]
+pub struct BufReadBacker<R> {
+    inner: R,
+    buf: Box<[u8]>,
+    /// The index (exclusively) until which `buf` hasn't been read back yet.
+    pos: usize,
+    /// The amount of bytes of the buffered block.
+    filled: usize,
+    /// The offset of `inner` where the buffered block starts, which is also where `inner` is positioned.
+    block_start: u64,
+    /// The length of `inner` if it has been passed to [`with_known_length`](Self::with_known_length).
+    len: Option<u64>,
+    /// The minimum amount of bytes which are read at once, see [`set_min_read`](Self::set_min_read).
+    min_read: usize,
+    refill_hook: Option<RefillHook>,
+    adaptive: Option<Adaptive>,
+    /// Holds the line of [`read_back_line_ref`](Self::read_back_line_ref) if it didn't fit into `buf`.
+    line: Vec<u8>,
+    #[cfg(feature = "metrics")]
+    metrics: RevMetrics,
+}
+
+/// The amount of average records which should fit into the buffer with [`BufReadBacker::enable_adaptive`].
+const ADAPTIVE_RECORDS: usize = 4;
+
+/// The record sizes which have been seen with [`BufReadBacker::enable_adaptive`].
+#[derive(Debug)]
+struct Adaptive {
+    min: usize,
+    max: usize,
+    records: usize,
+    bytes: usize,
+}
+
+impl Adaptive {
+    /// Adds a record of `len` bytes and returns the capacity which fits a few records of the average size.
+    fn record(&mut self, len: usize) -> usize {
+        self.records = self.records.saturating_add(1);
+        self.bytes = self.bytes.saturating_add(len);
+
+        (self.bytes / self.records)
+            .saturating_mul(ADAPTIVE_RECORDS)
+            .clamp(self.min, self.max)
+    }
+}
+
+/// The hook of [`BufReadBacker::set_refill_hook`].
+struct RefillHook {
+    every: usize,
+    /// The amount of successful refills since the hook has been set.
+    refills: usize,
+    hook: Box<dyn FnMut() -> io::Result<()> + Send>,
+}
+
+// SAFETY: the hook is only called through `&mut self`, so sharing a `&RefillHook` between threads can't call it
+// concurrently
+unsafe impl Sync for RefillHook {}
+
+impl fmt::Debug for RefillHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefillHook")
+            .field("every", &self.every)
+            .field("refills", &self.refills)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Counts the operations of a [`BufReadBacker`] on its underlying reader, for example to tune the buffer capacity
+/// for sources where each operation is expensive, like files on a network filesystem.
+///
+/// Only available with the `metrics` feature.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RevMetrics {
+    /// The amount of seeks of the underlying reader.
+    pub seeks: u64,
+    /// The amount of calls of [`Read::read`] or [`Read::read_vectored`] of the underlying reader.
+    pub reads: u64,
+    /// The amount of bytes which have been read from the underlying reader.
+    pub bytes_read: u64,
+    /// The amount of refills of the internal buffer.
+    pub refills: u64,
+}
+
+/// Counts the operations on the underlying reader of a [`BufReadBacker`] in its [`RevMetrics`].
+#[cfg(feature = "metrics")]
+struct Counted<'a, R> {
+    inner: &'a mut R,
+    metrics: &'a mut RevMetrics,
+}
+
+#[cfg(feature = "metrics")]
+impl<R: Read> Read for Counted<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.metrics.reads += 1;
+        let amount = self.inner.read(buf)?;
+        self.metrics.bytes_read += amount as u64;
+        Ok(amount)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.metrics.reads += 1;
+        let amount = self.inner.read_vectored(bufs)?;
+        self.metrics.bytes_read += amount as u64;
+        Ok(amount)
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl<R: Seek> Seek for Counted<'_, R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.metrics.seeks += 1;
+        self.inner.seek(pos)
+    }
+}
+
+impl<R: Read + Seek> BufReadBacker<R> {
+    /// Creates a new `BufReadBacker` with a default buffer capacity (currently 8 KiB) which starts reading back at
+    /// the end of `inner`.
+    pub fn new(inner: R) -> io::Result<Self> {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `BufReadBacker` with the given buffer capacity which starts reading back at the end of
+    /// `inner`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    pub fn with_capacity(capacity: usize, mut inner: R) -> io::Result<Self> {
+        assert!(capacity > 0, "capacity has to be greater than 0");
+        let block_start = inner.seek(SeekFrom::End(0))?;
+
+        Ok(Self::from_parts(capacity, inner, block_start, None))
+    }
+
+    /// Creates a new `BufReadBacker` with a default buffer capacity which starts reading back at `len`, the length
+    /// of `inner` which is known from somewhere else.
+    ///
+    /// This is for readers which only support seeking to a position from their start, like a reader which fetches
+    /// byte ranges over HTTP and gets the length from a header. `inner` is never asked for its end, not even by
+    /// [`rev_seek`], which uses `len` instead.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::{BufReadBacker, ReadBack};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut reader = BufReadBacker::with_known_length(Cursor::new(b"data and more"), 4)?;
+    ///
+    ///     let mut buffer = Vec::new();
+    ///     reader.read_back_to_end(&mut buffer)?;
+    ///     assert_eq!(buffer, b"data");
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`rev_seek`]: RevSeek::rev_seek
+    pub fn with_known_length(mut inner: R, len: u64) -> io::Result<Self> {
+        inner.seek(SeekFrom::Start(len))?;
+
+        Ok(Self::from_parts(DEFAULT_BUF_SIZE, inner, len, Some(len)))
+    }
+
+    fn from_parts(capacity: usize, inner: R, block_start: u64, len: Option<u64>) -> Self {
+        Self {
+            inner,
+            buf: vec![0; capacity].into_boxed_slice(),
+            pos: 0,
+            filled: 0,
+            block_start,
+            len,
+            min_read: 0,
+            refill_hook: None,
+            adaptive: None,
+            line: Vec::new(),
+            #[cfg(feature = "metrics")]
+            metrics: RevMetrics {
+                seeks: 1,
+                ..RevMetrics::default()
+            },
+        }
+    }
+}
+
+impl<R> BufReadBacker<R> {
+    /// Returns the number of bytes the internal buffer can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Grows the internal buffer so it can hold at least `n` bytes, nothing happens if it's big enough already.
+    ///
+    /// The buffered bytes are kept, so this can be called at any time, for example before reading back a record
+    /// which is known to be bigger than the current capacity. The following refills read blocks of the new
+    /// capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::{BufReadBack, BufReadBacker};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut reader = BufReadBacker::with_capacity(2, Cursor::new(b"0123456789"))?;
+    ///     assert_eq!(reader.read_back_fill_buf()?, b"89");
+    ///
+    ///     reader.ensure_capacity(6);
+    ///     assert_eq!(reader.capacity(), 6);
+    ///     assert_eq!(reader.buffer(), b"89");
+    ///
+    ///     reader.read_back_consume(2);
+    ///     assert_eq!(reader.read_back_fill_buf()?, b"234567");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn ensure_capacity(&mut self, n: usize) {
+        if n > self.buf.len() {
+            // the consumed bytes of the block are kept as well for `rev_seek_relative`
+            let mut buf = vec![0; n].into_boxed_slice();
+            buf[..self.filled].copy_from_slice(&self.buf[..self.filled]);
+            self.buf = buf;
+        }
+    }
+
+    /// Grows the internal buffer based on the sizes of the records which are read back with [`read_back_until`]
+    /// (and the methods which use it, like [`read_back_line`]), so that one refill reads a few records.
+    ///
+    /// The capacity is at least `min` bytes and it grows up to `max` bytes, but it never shrinks. This reduces the
+    /// refills for sources whose records are bigger than expected, like log files with long lines.
+    ///
+    /// # Panics
+    /// Panics if `min` is bigger than `max`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::{BufReadBack, BufReadBacker};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let data = format!("{}\n{}", "a".repeat(100), "b".repeat(100));
+    ///     let mut reader = BufReadBacker::with_capacity(16, Cursor::new(data))?;
+    ///     reader.enable_adaptive(16, 1024);
+    ///
+    ///     reader.read_back_until(b'\n', &mut Vec::new())?;
+    ///     assert!(reader.capacity() > 100);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_back_until`]: BufReadBack::read_back_until
+    /// [`read_back_line`]: BufReadBack::read_back_line
+    pub fn enable_adaptive(&mut self, min: usize, max: usize) {
+        assert!(
+            min <= max,
+            "the minimum capacity is bigger than the maximum"
+        );
+        self.ensure_capacity(min);
+        self.adaptive = Some(Adaptive {
+            min,
+            max,
+            records: 0,
+            bytes: 0,
+        });
+    }
+
+    /// Calls `hook` before every `every`-th refill of the internal buffer, for example to report the progress or to
+    /// cancel a long scan without another thread.
+    ///
+    /// An error of `hook` is returned instead of refilling the buffer, so reading back stops there. The hook is
+    /// called again when the next refill is attempted. Setting a new hook replaces the previous one.
+    ///
+    /// # Panics
+    /// Panics if `every` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::{self, Cursor, ErrorKind};
+    /// use read_collection::{BufReadBacker, ReadBack};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = BufReadBacker::with_capacity(4, Cursor::new(vec![0; 100]))?;
+    ///     reader.set_refill_hook(5, || Err(io::Error::other("cancelled")));
+    ///
+    ///     let err = reader.read_back_to_end(&mut Vec::new()).unwrap_err();
+    ///     assert_eq!(err.kind(), ErrorKind::Other);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_refill_hook<F>(&mut self, every: usize, hook: F)
+    where
+        F: FnMut() -> io::Result<()> + Send + 'static,
+    {
+        assert!(
+            every > 0,
+            "the hook has to be called every 1 or more refills"
+        );
+        self.refill_hook = Some(RefillHook {
+            every,
+            refills: 0,
+            hook: Box::new(hook),
+        });
+    }
+
+    /// Returns the operations on the underlying reader so far, including the seek to its end on creation.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::{BufReadBacker, ReadBack};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut reader = BufReadBacker::with_capacity(4, Cursor::new(b"0123456789"))?;
+    ///     reader.read_back_to_end(&mut Vec::new())?;
+    ///
+    ///     assert_eq!(reader.metrics().refills, 3);
+    ///     assert_eq!(reader.metrics().bytes_read, 10);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> &RevMetrics {
+        &self.metrics
+    }
+
+    /// Shrinks the internal buffer to `min` bytes, for example after [`peek`] or [`ensure_capacity`] grew it for a
+    /// large record which has been read back already. Nothing happens if it's small enough already.
+    ///
+    /// The bytes which haven't been read back yet are kept, so the buffer doesn't get smaller than them. It
+    /// always keeps a capacity of at least one byte.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::{BufReadBack, BufReadBacker};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut reader = BufReadBacker::with_capacity(2, Cursor::new(b"0123456789"))?;
+    ///     assert_eq!(reader.peek(8)?, b"23456789");
+    ///
+    ///     reader.read_back_consume(7);
+    ///     reader.shrink_buffer_to(2);
+    ///     assert_eq!(reader.capacity(), 2);
+    ///     assert_eq!(reader.buffer(), b"2");
+    ///
+    ///     reader.read_back_consume(1);
+    ///     assert_eq!(reader.read_back_fill_buf()?, b"01");
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`peek`]: Self::peek
+    /// [`ensure_capacity`]: Self::ensure_capacity
+    pub fn shrink_buffer_to(&mut self, min: usize) {
+        let capacity = min.max(self.pos).max(1);
+        if capacity < self.buf.len() {
+            // the consumed bytes directly behind the position are kept if they fit
+            self.filled = self.filled.min(capacity);
+            self.buf = self.buf[..capacity].into();
+        }
+    }
+
+    /// Reads at least `min` bytes from the underlying reader at once, unless there are less bytes left in front of
+    /// the buffered block.
+    ///
+    /// Refills usually read a whole buffer, but [`peek`] only reads the bytes which are missing to fill the buffer,
+    /// which can be just a few. A minimum avoids those small reads on storage where each request has a high latency.
+    /// If `min` is bigger than the [`capacity`], the buffer grows to `min` bytes on the next refill.
+    ///
+    /// [`peek`]: Self::peek
+    /// [`capacity`]: Self::capacity
+    pub fn set_min_read(&mut self, min: usize) {
+        self.min_read = min;
+    }
+
+    /// Returns the buffered bytes which haven't been read back yet.
+    ///
+    /// Unlike [`read_back_fill_buf`], this never reads from the underlying reader.
+    ///
+    /// [`read_back_fill_buf`]: BufReadBack::read_back_fill_buf
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// The `BufReadBacker` relies on the position of the underlying reader, so it mustn't be changed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this `BufReadBacker`, returning the underlying reader.
+    ///
+    /// The underlying reader is positioned at the start of the buffered block, so the bytes which are still in
+    /// the buffer are lost. Like dropping a `BufReadBacker`, this doesn't do any I/O.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read + Seek> BufReadBacker<R> {
+    /// Moves the position by `offset` bytes, like [`RevSeekFrom::Current`] does: positive values move it towards
+    /// the front, negative values back towards the end.
+    ///
+    /// If the new position is inside of the buffered block, only the position in the buffer is changed, so the
+    /// underlying reader isn't touched. Otherwise, the buffer is discarded and the underlying reader seeks, like
+    /// for [`rev_seek`]. This is the read-back counterpart of [`BufReader::seek_relative`].
+    ///
+    /// # Errors
+    /// Seeking in front of the start or behind the end of the source returns an [`ErrorKind::InvalidInput`] error.
+    ///
+    /// [`rev_seek`]: RevSeek::rev_seek
+    /// [`BufReader::seek_relative`]: std::io::BufReader::seek_relative
+    pub fn rev_seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        let new_pos = if offset >= 0 {
+            usize::try_from(offset)
+                .ok()
+                .and_then(|offset| self.pos.checked_sub(offset))
+        } else {
+            usize::try_from(offset.unsigned_abs())
+                .ok()
+                .and_then(|offset| self.pos.checked_add(offset))
+                .filter(|&new_pos| new_pos <= self.filled)
+        };
+
+        match new_pos {
+            Some(new_pos) => self.pos = new_pos,
+            None => {
+                self.rev_seek(RevSeekFrom::Current(offset))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads back the last line, without allocating if possible.
+    ///
+    /// The returned bytes are the same as the ones which [`read_back_until`] appends for `b'\n'`: the line starts
+    /// with its newline, unless it's the first line of the source, and the `\r` of a `\r\n` stays at the end of the
+    /// line in front of it. If the whole line is in the buffered block, it's borrowed from the internal buffer.
+    /// Otherwise, the line spans over multiple blocks and it's copied into a second buffer, which keeps its
+    /// allocation for the following calls. Use [`ensure_capacity`] to avoid this for lines with a known maximum
+    /// length.
+    ///
+    /// Returns `None` if the start of the source has been reached.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::BufReadBacker;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut reader = BufReadBacker::new(Cursor::new(b"first\nsecond"))?;
+    ///
+    ///     assert_eq!(reader.read_back_line_ref()?, Some(b"\nsecond".as_slice()));
+    ///     assert_eq!(reader.read_back_line_ref()?, Some(b"first".as_slice()));
+    ///     assert_eq!(reader.read_back_line_ref()?, None);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_back_until`]: BufReadBack::read_back_until
+    /// [`ensure_capacity`]: Self::ensure_capacity
+    pub fn read_back_line_ref(&mut self) -> io::Result<Option<&[u8]>> {
+        let available = self.read_back_fill_buf()?.len();
+        if available == 0 {
+            return Ok(None);
+        }
+
+        let start = memchr::memrchr(b'\n', self.buffer());
+        if start.is_some() || self.block_start == 0 {
+            let len = available - start.unwrap_or(0);
+            self.read_back_consume(len);
+            return Ok(Some(&self.buf[self.pos..self.pos + len]));
+        }
+
+        let mut line = std::mem::take(&mut self.line);
+        line.clear();
+        let result = self.read_back_until(b'\n', &mut line);
+        self.line = line;
+        result?;
+
+        Ok(Some(&self.line))
+    }
+
+    /// Returns the last `n` bytes which haven't been read back yet in forward order, without consuming them.
+    ///
+    /// Unlike [`read_back_fill_buf`], which only refills an empty buffer, this reads the blocks in front of the
+    /// buffered bytes until at least `n` bytes are buffered. If `n` is bigger than the [`capacity`], the internal
+    /// buffer grows like for [`ensure_capacity`] and keeps this capacity afterwards, but never beyond the bytes
+    /// which are left. Less than `n` bytes are returned only if the start of the source has been reached.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::{BufReadBack, BufReadBacker};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut reader = BufReadBacker::with_capacity(4, Cursor::new(b"header:value"))?;
+    ///
+    ///     assert_eq!(reader.peek(6)?, b":value");
+    ///     assert_eq!(reader.capacity(), 6);
+    ///
+    ///     reader.read_back_consume(6);
+    ///     assert_eq!(reader.peek(100)?, b"header");
+    ///     assert_eq!(reader.capacity(), 6);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_back_fill_buf`]: BufReadBack::read_back_fill_buf
+    /// [`capacity`]: Self::capacity
+    /// [`ensure_capacity`]: Self::ensure_capacity
+    pub fn peek(&mut self, n: usize) -> io::Result<&[u8]> {
+        let left = usize::try_from(self.block_start).unwrap_or(usize::MAX);
+        let n = n.min(self.pos.saturating_add(left));
+
+        if self.pos < n {
+            // fill the rest of the buffer as well
+            self.read_in_front(n.max(self.buf.len()) - self.pos)?;
+        }
+
+        Ok(&self.buf[self.pos - n..self.pos])
+    }
+
+    /// Reads the `amount` bytes in front of the buffered block in front of the bytes which haven't been read back
+    /// yet, but at least `min_read` bytes. The buffer grows if they don't fit and the consumed bytes of the block
+    /// are discarded.
+    fn read_in_front(&mut self, amount: usize) -> io::Result<()> {
+        let amount = amount
+            .max(self.min_read)
+            .min(usize::try_from(self.block_start).unwrap_or(usize::MAX));
+        let start = self.block_start - amount as u64;
+        self.run_refill_hook()?;
+        self.ensure_capacity(self.pos + amount);
+
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::trace_span!("read_back_fill_buf", offset = start, len = amount).entered();
+
+        // the new block is read into the front of the buffer, so the unconsumed bytes are moved behind it
+        self.buf.copy_within(..self.pos, amount);
+        if let Err(err) = self.read_block(start, amount) {
+            self.buf.copy_within(amount..amount + self.pos, 0);
+            self.filled = self.pos;
+            return Err(err);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(offset = start, bytes = amount, "read block");
+        self.count_refill();
+
+        self.block_start = start;
+        self.pos += amount;
+        self.filled = self.pos;
+        Ok(())
+    }
+
+    /// Reads `buf[..amount]` from the offset `start` of the underlying reader and moves it back to `start`.
+    fn read_block(&mut self, start: u64, amount: usize) -> io::Result<()> {
+        self.seek_inner(SeekFrom::Start(start))?;
+        #[cfg(feature = "metrics")]
+        let mut inner = Counted {
+            inner: &mut self.inner,
+            metrics: &mut self.metrics,
+        };
+        #[cfg(not(feature = "metrics"))]
+        let inner = &mut self.inner;
+        if let Err(err) = inner.read_exact(&mut self.buf[..amount]) {
+            self.seek_inner(SeekFrom::Start(self.block_start))?;
+            return Err(match err.kind() {
+                ErrorKind::UnexpectedEof => io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "source ended before the read back position",
+                ),
+                _ => err,
+            });
+        }
+        self.seek_inner(SeekFrom::Start(start))?;
+
+        Ok(())
+    }
+
+    /// Reads back the bytes in front of the buffer directly into `bufs` with vectored reads, which counts as a
+    /// refill.
+    fn read_back_vectored_in_front(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.run_refill_hook()?;
+
+        #[cfg(feature = "tracing")]
+        let (start, _span) = {
+            let total = bufs.iter().map(|b| b.len() as u64).sum::<u64>();
+            let len = total.min(self.block_start);
+            let start = self.block_start - len;
+            (
+                start,
+                tracing::trace_span!("read_back_vectored", offset = start, len).entered(),
+            )
+        };
+
+        #[cfg(feature = "metrics")]
+        let mut inner = Counted {
+            inner: &mut self.inner,
+            metrics: &mut self.metrics,
+        };
+        #[cfg(not(feature = "metrics"))]
+        let mut inner = &mut self.inner;
+        let amount = seek_read_back_vectored(&mut inner, self.block_start, bufs)?;
+        #[cfg(feature = "tracing")]
+        tracing::trace!(offset = start, bytes = amount, "read vectored");
+        self.count_refill();
+
+        self.block_start -= amount as u64;
+        self.filled = 0;
+        Ok(amount)
+    }
+
+    /// Calls the refill hook if the upcoming refill is one it has been set for.
+    fn run_refill_hook(&mut self) -> io::Result<()> {
+        if let Some(hook) = &mut self.refill_hook {
+            if (hook.refills + 1) % hook.every == 0 {
+                (hook.hook)()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts a successful refill for the metrics and the refill hook.
+    fn count_refill(&mut self) {
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.refills += 1;
+        }
+        if let Some(hook) = &mut self.refill_hook {
+            hook.refills += 1;
+        }
+    }
+
+    fn seek_inner(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.seeks += 1;
+        }
+        self.inner.seek(pos)
+    }
+}
+
+impl<R: Read + Seek> ReadBack for BufReadBacker<R> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.read_back_fill_buf()?.read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+
+    /// If the internal buffer is empty and the buffers are at least as big as it, the bytes are read directly from
+    /// the inner reader with one vectored read.
+    fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let total = bufs.iter().map(|b| b.len()).sum::<usize>();
+        if self.pos == 0 && self.block_start > 0 && total >= self.buf.len().max(self.min_read) {
+            return self.read_back_vectored_in_front(bufs);
+        }
+
+        let amount = self.read_back_fill_buf()?.read_back_vectored(bufs)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+
+    fn is_read_back_vectored(&self) -> bool {
+        true
+    }
+}
+
+impl<R: Read + Seek> BufReadBack for BufReadBacker<R> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == 0 && self.block_start > 0 {
+            self.read_in_front(self.buf.len())?;
+        }
+
+        Ok(self.buffer())
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+
+    /// With [`enable_adaptive`](Self::enable_adaptive), the size of the record grows the buffer if needed.
+    fn read_back_until(&mut self, delim: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let amount = default_buf_read_back_until(self, delim, buf)?;
+        if let Some(adaptive) = &mut self.adaptive {
+            let capacity = adaptive.record(amount);
+            self.ensure_capacity(capacity);
+        }
+
+        Ok(amount)
+    }
+}
+
+/// Seeking discards the internal buffer.
+impl<R: Read + Seek> RevSeek for BufReadBacker<R> {
+    fn rev_seek(&mut self, pos: RevSeekFrom) -> io::Result<u64> {
+        let current = self.block_start + self.pos as u64;
+        let len = match self.len {
+            Some(len) => len,
+            None => self.seek_inner(SeekFrom::End(0))?,
+        };
+
+        let target = match pos {
+            RevSeekFrom::Front(n) => Some(n),
+            RevSeekFrom::Back(n) => len.checked_sub(n),
+            RevSeekFrom::Current(n) if n >= 0 => current.checked_sub(n as u64),
+            RevSeekFrom::Current(n) => current.checked_add(n.unsigned_abs()),
+        }
+        .filter(|&target| target <= len);
+
+        let Some(target) = target else {
+            self.seek_inner(SeekFrom::Start(self.block_start))?;
+            return Err(invalid_rev_seek_error());
+        };
+
+        self.seek_inner(SeekFrom::Start(target))?;
+        self.block_start = target;
+        self.pos = 0;
+        self.filled = 0;
+
+        Ok(len - target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, Cursor};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Op {
+        Read(usize),
+        Seek(SeekFrom),
+    }
+
+    /// Records the operations on the underlying reader and fails the next `fail_reads` reads.
+    struct Recording<'a> {
+        inner: Cursor<&'a [u8]>,
+        ops: Vec<Op>,
+        fail_reads: usize,
+    }
+
+    impl<'a> Recording<'a> {
+        fn new(data: &'a [u8]) -> Self {
+            Self {
+                inner: Cursor::new(data),
+                ops: Vec::new(),
+                fail_reads: 0,
+            }
+        }
+    }
+
+    impl Recording<'_> {
+        fn reads(&self) -> Vec<usize> {
+            self.ops
+                .iter()
+                .filter_map(|op| match op {
+                    Op::Read(amount) => Some(*amount),
+                    Op::Seek(_) => None,
+                })
+                .collect()
+        }
+    }
+
+    impl Read for Recording<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.fail_reads > 0 {
+                self.fail_reads -= 1;
+                return Err(io::Error::other("transient error"));
+            }
+
+            let amount = self.inner.read(buf)?;
+            self.ops.push(Op::Read(amount));
+            Ok(amount)
+        }
+    }
+
+    impl Seek for Recording<'_> {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.ops.push(Op::Seek(pos));
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn blocks_from_the_end() {
+        let data = b"0123456789";
+        let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+        assert_eq!(reader.capacity(), 4);
+        assert!(reader.buffer().is_empty());
+
+        assert_eq!(reader.read_back_fill_buf().ok(), Some(b"6789".as_slice()));
+        reader.read_back_consume(1);
+        assert_eq!(reader.buffer(), b"678");
+        reader.read_back_consume(3);
+
+        assert_eq!(reader.read_back_fill_buf().ok(), Some(b"2345".as_slice()));
+        assert_eq!(reader.get_ref().position(), 2);
+        reader.read_back_consume(4);
+
+        // the first block is smaller than the buffer
+        assert_eq!(reader.read_back_fill_buf().ok(), Some(b"01".as_slice()));
+        reader.read_back_consume(2);
+        assert!(reader.read_back_fill_buf().unwrap().is_empty());
+    }
+
+    #[test]
+    fn capacity_larger_than_source() {
+        let mut reader = BufReadBacker::new(Cursor::new(b"data".as_slice())).unwrap();
+
+        assert_eq!(reader.read_back_fill_buf().ok(), Some(b"data".as_slice()));
+        reader.read_back_consume(10);
+        assert!(reader.read_back_fill_buf().unwrap().is_empty());
+    }
+
+    #[test]
+    fn empty_source() {
+        let mut reader = BufReadBacker::new(Cursor::new(b"".as_slice())).unwrap();
+
+        assert!(reader.read_back_fill_buf().unwrap().is_empty());
+        assert_eq!(reader.read_back(&mut [0; 4]).ok(), Some(0));
+    }
+
+    #[test]
+    fn same_as_read() {
+        let data = b"first line\nsecond\r\n\nfourth line which is longer\n";
+
+        for capacity in 1..data.len() + 2 {
+            let reader =
+                BufReadBacker::with_capacity(capacity, Cursor::new(data.as_slice())).unwrap();
+            let mut lines = reader
+                .read_back_lines()
+                .map(|line| line.unwrap())
+                .collect::<Vec<String>>();
+            lines.reverse();
+
+            let expected = data
+                .lines()
+                .map(|line| line.unwrap())
+                .collect::<Vec<String>>();
+            assert_eq!(lines, expected, "capacity: {}", capacity);
+
+            let mut reader =
+                BufReadBacker::with_capacity(capacity, Cursor::new(data.as_slice())).unwrap();
+            let mut content = Vec::new();
+            assert_eq!(reader.read_back_to_end(&mut content).ok(), Some(data.len()));
+            assert_eq!(content, data);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    #[tracing_test::traced_test]
+    fn traces_refills() {
+        let mut reader =
+            BufReadBacker::with_capacity(4, Cursor::new(b"0123456789".as_slice())).unwrap();
+        reader.read_back_to_end(&mut Vec::new()).unwrap();
+
+        assert!(logs_contain("read block offset=6 bytes=4"));
+        assert!(logs_contain("read block offset=2 bytes=4"));
+        assert!(logs_contain("read block offset=0 bytes=2"));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    #[tracing_test::traced_test]
+    fn traces_vectored_bypass() {
+        let mut reader =
+            BufReadBacker::with_capacity(4, Cursor::new(b"0123456789".as_slice())).unwrap();
+        let (mut a, mut b) = ([0; 3], [0; 3]);
+        reader
+            .read_back_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)])
+            .unwrap();
+
+        assert!(logs_contain("read_back_vectored{offset=4 len=6}"));
+        assert!(logs_contain("read vectored offset=4 bytes=6"));
+    }
+
+    #[test]
+    fn truncated_source() {
+        let data = vec![1, 2, 3, 4, 5, 6];
+        let mut reader = BufReadBacker::with_capacity(2, Cursor::new(data)).unwrap();
+        assert_eq!(reader.read_back_fill_buf().ok(), Some([5, 6].as_slice()));
+        reader.read_back_consume(2);
+
+        reader.get_mut().get_mut().truncate(1);
+        let err = reader.read_back_fill_buf().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity has to be greater than 0")]
+    fn zero_capacity() {
+        let _ = BufReadBacker::with_capacity(0, Cursor::new(b"data".as_slice()));
+    }
+
+    mod read_back_vectored {
+        use super::*;
+
+        #[test]
+        fn bypasses_the_buffer() {
+            let data = b"0123456789";
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+            let (mut a, mut b) = ([0; 2], [0; 4]);
+
+            let amount = reader
+                .read_back_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)])
+                .unwrap();
+            assert_eq!(amount, 6);
+            assert_eq!((&a, &b), (b"45", b"6789"));
+            assert!(reader.buffer().is_empty());
+            assert_eq!(reader.get_ref().position(), 4);
+
+            let mut rest = Vec::new();
+            reader.read_back_to_end(&mut rest).unwrap();
+            assert_eq!(rest, b"0123");
+        }
+
+        #[test]
+        fn from_the_buffer() {
+            let data = b"0123456789";
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+            reader.read_back_fill_buf().unwrap();
+            reader.read_back_consume(1);
+            let (mut a, mut b) = ([0; 2], [0; 4]);
+
+            let amount = reader
+                .read_back_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)])
+                .unwrap();
+            assert_eq!(amount, 3);
+            assert_eq!((&a, &b), (&[0; 2], b"678\0"));
+        }
+
+        #[test]
+        fn same_as_read_back_to_end() {
+            let data = (0..100u8).collect::<Vec<u8>>();
+            let mut reader = BufReadBacker::with_capacity(8, Cursor::new(data.as_slice())).unwrap();
+            let (mut a, mut b, mut c) = ([0; 13], [0; 5], [0; 21]);
+
+            let mut chunks = Vec::new();
+            loop {
+                let amount = reader
+                    .read_back_vectored(&mut [
+                        IoSliceMut::new(&mut a),
+                        IoSliceMut::new(&mut b),
+                        IoSliceMut::new(&mut c),
+                    ])
+                    .unwrap();
+                if amount == 0 {
+                    break;
+                }
+
+                let mut remaining = amount;
+                let mut parts = Vec::new();
+                for buf in [&a[..], &b[..], &c[..]].into_iter().rev() {
+                    let len = std::cmp::min(buf.len(), remaining);
+                    parts.insert(0, &buf[..len]);
+                    remaining -= len;
+                }
+                chunks.push(parts.concat());
+            }
+            chunks.reverse();
+
+            assert_eq!(chunks.concat(), data);
+            assert!(reader.is_read_back_vectored());
+        }
+    }
+
+    mod rev_seek {
+        use super::*;
+
+        fn read_back_all<R: Read + Seek>(reader: &mut BufReadBacker<R>) -> Vec<u8> {
+            let mut content = Vec::new();
+            reader.read_back_to_end(&mut content).unwrap();
+            content
+        }
+
+        #[test]
+        fn from_back_and_front() {
+            let data = b"0123456789";
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+
+            assert_eq!(reader.rev_seek(RevSeekFrom::Back(3)).ok(), Some(3));
+            assert_eq!(read_back_all(&mut reader), b"0123456");
+
+            assert_eq!(reader.rev_seek(RevSeekFrom::Front(4)).ok(), Some(6));
+            assert_eq!(read_back_all(&mut reader), b"0123");
+
+            assert_eq!(reader.rev_seek(RevSeekFrom::Back(0)).ok(), Some(0));
+            assert_eq!(read_back_all(&mut reader), data);
+        }
+
+        #[test]
+        fn from_current() {
+            let data = b"0123456789";
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+            let mut buffer = [0; 2];
+
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(reader.rev_seek(RevSeekFrom::Current(3)).ok(), Some(5));
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"34");
+
+            assert_eq!(reader.rev_seek(RevSeekFrom::Current(-4)).ok(), Some(3));
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"56");
+        }
+
+        #[test]
+        fn outside_of_the_source() {
+            let data = b"0123456789";
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+            let mut buffer = [0; 2];
+            reader.read_back_exact(&mut buffer).unwrap();
+
+            for pos in [
+                RevSeekFrom::Back(11),
+                RevSeekFrom::Front(11),
+                RevSeekFrom::Current(9),
+                RevSeekFrom::Current(-3),
+            ] {
+                let err = reader.rev_seek(pos).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidInput, "pos: {:?}", pos);
+            }
+
+            // the position hasn't changed
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"67");
+        }
+
+        /// Counts the seeks of the underlying reader.
+        struct CountingSeeks<'a> {
+            inner: Cursor<&'a [u8]>,
+            seeks: usize,
+        }
+
+        impl Read for CountingSeeks<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.inner.read(buf)
+            }
+        }
+
+        impl Seek for CountingSeeks<'_> {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                self.seeks += 1;
+                self.inner.seek(pos)
+            }
+        }
+
+        #[test]
+        fn relative_inside_of_the_buffer() {
+            let inner = CountingSeeks {
+                inner: Cursor::new(b"0123456789"),
+                seeks: 0,
+            };
+            let mut reader = BufReadBacker::with_capacity(4, inner).unwrap();
+            let mut buffer = [0; 1];
+
+            reader.read_back_exact(&mut buffer).unwrap();
+            let seeks = reader.get_ref().seeks;
+
+            reader.rev_seek_relative(2).unwrap();
+            assert_eq!(reader.buffer(), b"6");
+            reader.rev_seek_relative(-3).unwrap();
+            assert_eq!(reader.buffer(), b"6789");
+            reader.rev_seek_relative(4).unwrap();
+            assert_eq!(reader.buffer(), b"");
+            assert_eq!(reader.get_ref().seeks, seeks);
+
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"5");
+        }
+
+        #[test]
+        fn relative_outside_of_the_buffer() {
+            let inner = CountingSeeks {
+                inner: Cursor::new(b"0123456789"),
+                seeks: 0,
+            };
+            let mut reader = BufReadBacker::with_capacity(4, inner).unwrap();
+            let mut buffer = [0; 1];
+
+            reader.read_back_exact(&mut buffer).unwrap();
+            let seeks = reader.get_ref().seeks;
+
+            reader.rev_seek_relative(5).unwrap();
+            assert!(reader.get_ref().seeks > seeks);
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"3");
+
+            let seeks = reader.get_ref().seeks;
+            reader.rev_seek_relative(-6).unwrap();
+            assert!(reader.get_ref().seeks > seeks);
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"8");
+
+            let err = reader.rev_seek_relative(-3).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+    }
+
+    mod read_back_line_ref {
+        use super::*;
+
+        #[test]
+        fn in_buffer() {
+            let mut reader =
+                BufReadBacker::with_capacity(16, Cursor::new(b"one\ntwo\r\nthree".as_slice()))
+                    .unwrap();
+
+            let buf = reader.buf.as_ptr_range();
+            let line = reader.read_back_line_ref().unwrap().unwrap();
+            assert_eq!(line, b"\nthree");
+            assert!(buf.contains(&line.as_ptr()));
+
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"\ntwo\r".as_slice())
+            );
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"one".as_slice())
+            );
+            assert_eq!(reader.read_back_line_ref().unwrap(), None);
+        }
+
+        #[test]
+        fn spanning_blocks() {
+            let data = b"first\na line which is longer than the buffer\nlast";
+            let mut reader = BufReadBacker::with_capacity(8, Cursor::new(data.as_slice())).unwrap();
+
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"\nlast".as_slice())
+            );
+
+            let buf = reader.buf.as_ptr_range();
+            let line = reader.read_back_line_ref().unwrap().unwrap();
+            assert_eq!(line, b"\na line which is longer than the buffer");
+            assert!(!buf.contains(&line.as_ptr()));
+
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"first".as_slice())
+            );
+            assert_eq!(reader.read_back_line_ref().unwrap(), None);
+        }
+
+        #[test]
+        fn same_as_read_back_until() {
+            let data = b"a\n\nsome words\r\n\nthe end\n";
+
+            for capacity in 1..=data.len() {
+                let mut reader =
+                    BufReadBacker::with_capacity(capacity, Cursor::new(data.as_slice())).unwrap();
+                let mut expected = data.as_slice();
+
+                while let Some(line) = reader.read_back_line_ref().unwrap() {
+                    let mut until = Vec::new();
+                    expected.read_back_until(b'\n', &mut until).unwrap();
+                    assert_eq!(line, until, "capacity: {}", capacity);
+                }
+                assert!(expected.is_empty(), "capacity: {}", capacity);
+            }
+        }
+    }
+
+    mod peek {
+        use super::*;
+
+        #[test]
+        fn read_error_keeps_the_block() {
+            let mut reader =
+                BufReadBacker::with_capacity(4, Recording::new(b"0123456789")).unwrap();
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"6789".as_slice()));
+            reader.read_back_consume(2);
+
+            reader.get_mut().fail_reads = 1;
+            assert!(reader.peek(4).is_err());
+            assert_eq!(reader.buffer(), b"67");
+
+            // the consumed bytes are gone, so the reader seeks
+            reader.rev_seek_relative(-2).unwrap();
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"6789".as_slice()));
+
+            reader.read_back_consume(2);
+            assert_eq!(reader.peek(4).ok(), Some(b"4567".as_slice()));
+        }
+
+        #[test]
+        fn grows_only_to_the_remaining_bytes() {
+            let mut reader =
+                BufReadBacker::with_capacity(2, Cursor::new(b"0123456789".as_slice())).unwrap();
+
+            assert_eq!(reader.peek(usize::MAX).ok(), Some(b"0123456789".as_slice()));
+            assert_eq!(reader.capacity(), 10);
+            assert_eq!(reader.peek(100).ok(), Some(b"0123456789".as_slice()));
+        }
+
+        #[test]
+        fn across_block_boundary() {
+            let mut reader =
+                BufReadBacker::with_capacity(4, Cursor::new(b"0123456789".as_slice())).unwrap();
+
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"6789".as_slice()));
+            reader.read_back_consume(2);
+
+            // "67" is still buffered, "2345" is read in front of it
+            assert_eq!(reader.peek(3).ok(), Some(b"567".as_slice()));
+            assert_eq!(reader.capacity(), 4);
+            assert_eq!(reader.buffer(), b"4567");
+
+            reader.read_back_consume(3);
+            let mut rest = Vec::new();
+            reader.read_back_to_end(&mut rest).unwrap();
+            assert_eq!(rest, b"01234");
+        }
+
+        #[test]
+        fn grows_the_buffer() {
+            let mut reader =
+                BufReadBacker::with_capacity(2, Cursor::new(b"0123456789".as_slice())).unwrap();
+            // "89" is buffered, only "8" is left
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"89".as_slice()));
+            reader.read_back_consume(1);
+
+            assert_eq!(reader.peek(5).ok(), Some(b"45678".as_slice()));
+            assert_eq!(reader.capacity(), 5);
+
+            reader.read_back_consume(5);
+            let mut rest = Vec::new();
+            reader.read_back_to_end(&mut rest).unwrap();
+            assert_eq!(rest, b"0123");
+        }
+
+        #[test]
+        fn more_than_available() {
+            let mut reader =
+                BufReadBacker::with_capacity(3, Cursor::new(b"abcde".as_slice())).unwrap();
+
+            assert_eq!(reader.peek(10).ok(), Some(b"abcde".as_slice()));
+            assert_eq!(reader.peek(2).ok(), Some(b"de".as_slice()));
+            reader.read_back_consume(5);
+            assert!(reader.peek(1).unwrap().is_empty());
+        }
+    }
+
+    mod ensure_capacity {
+        use super::*;
+
+        #[test]
+        fn mid_stream() {
+            let data = b"first line\na very long line in the middle\nlast";
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"last".as_slice()));
+            reader.read_back_consume(1);
+
+            reader.ensure_capacity(64);
+            assert_eq!(reader.capacity(), 64);
+            assert_eq!(reader.buffer(), b"las");
+
+            // the consumed byte is still part of the block
+            reader.rev_seek_relative(-1).unwrap();
+            assert_eq!(reader.buffer(), b"last");
+
+            let buf = reader.buf.as_ptr_range();
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"\nlast".as_slice())
+            );
+            let line = reader.read_back_line_ref().unwrap().unwrap();
+            assert_eq!(line, b"\na very long line in the middle");
+            // the bigger buffer holds the whole line, so it's borrowed
+            assert!(buf.contains(&line.as_ptr()));
+
+            let mut rest = Vec::new();
+            reader.read_back_to_end(&mut rest).unwrap();
+            assert_eq!(rest, b"first line");
+        }
+
+        #[test]
+        fn never_shrinks() {
+            let mut reader =
+                BufReadBacker::with_capacity(8, Cursor::new(b"0123456789".as_slice())).unwrap();
+            assert_eq!(
+                reader.read_back_fill_buf().ok(),
+                Some(b"23456789".as_slice())
+            );
+
+            reader.ensure_capacity(2);
+            assert_eq!(reader.capacity(), 8);
+            assert_eq!(reader.buffer(), b"23456789");
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    mod metrics {
+        use super::*;
+
+        #[test]
+        fn scan() {
+            let mut reader =
+                BufReadBacker::with_capacity(4, Recording::new(b"0123456789")).unwrap();
+            assert_eq!(reader.metrics().seeks, 1);
+
+            let mut byte = [0];
+            for refills in 1..=3 {
+                let buffered = reader.read_back_fill_buf().unwrap().len();
+                assert_eq!(reader.metrics().refills, refills);
+                assert_eq!(reader.metrics().reads, refills);
+
+                // reading back the buffered bytes doesn't touch the underlying reader
+                let metrics = *reader.metrics();
+                for _ in 0..buffered {
+                    reader.read_back_exact(&mut byte).unwrap();
+                }
+                assert_eq!(*reader.metrics(), metrics);
+            }
+            assert_eq!(reader.metrics().bytes_read, 10);
+        }
+
+        #[test]
+        fn same_as_the_operations() {
+            let mut reader =
+                BufReadBacker::with_capacity(4, Recording::new(b"0123456789")).unwrap();
+            reader.read_back_to_end(&mut Vec::new()).unwrap();
+
+            let metrics = *reader.metrics();
+            assert_eq!(metrics.refills, 3);
+            assert_eq!(metrics.reads, 3);
+            assert_eq!(metrics.bytes_read, 10);
+
+            let ops = &reader.get_ref().ops;
+            let seeks = ops.iter().filter(|op| matches!(op, Op::Seek(_))).count();
+            assert_eq!(metrics.seeks, seeks as u64);
+
+            // seeking discards the buffer, so the block is read again
+            reader.rev_seek(RevSeekFrom::Back(0)).unwrap();
+            reader.read_back_fill_buf().unwrap();
+            assert_eq!(reader.metrics().seeks, metrics.seeks + 4);
+            assert_eq!(reader.metrics().refills, 4);
+            assert_eq!(reader.metrics().bytes_read, 14);
+        }
+
+        #[test]
+        fn read_back_vectored() {
+            let mut reader =
+                BufReadBacker::with_capacity(4, Recording::new(b"0123456789")).unwrap();
+
+            // bypasses the buffer, the default `read_vectored` reads into one buffer per call
+            let (mut a, mut b) = ([0; 3], [0; 3]);
+            let amount = reader
+                .read_back_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)])
+                .unwrap();
+            assert_eq!(amount, 6);
+            assert_eq!([a, b].concat(), b"456789");
+
+            let metrics = *reader.metrics();
+            let ops = &reader.get_ref().ops;
+            let seeks = ops.iter().filter(|op| matches!(op, Op::Seek(_))).count();
+            assert_eq!(metrics.seeks, seeks as u64);
+            assert_eq!(metrics.reads, reader.get_ref().reads().len() as u64);
+            assert_eq!(metrics.reads, 2);
+            assert_eq!(metrics.bytes_read, 6);
+            assert_eq!(metrics.refills, 1);
+        }
+    }
+
+    mod with_known_length {
+        use super::*;
+
+        /// Only supports seeking from the start, like a reader which fetches byte ranges.
+        struct Positioned<'a>(Cursor<&'a [u8]>);
+
+        impl Read for Positioned<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.0.read(buf)
+            }
+        }
+
+        impl Seek for Positioned<'_> {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                match pos {
+                    SeekFrom::Start(_) => self.0.seek(pos),
+                    _ => Err(ErrorKind::Unsupported.into()),
+                }
+            }
+        }
+
+        #[test]
+        fn reads_back_from_the_length() {
+            let data = b"first\nsecond\nthird";
+            let inner = Positioned(Cursor::new(data.as_slice()));
+            let reader = BufReadBacker::with_known_length(inner, data.len() as u64).unwrap();
+
+            let lines = reader
+                .read_back_lines()
+                .map(|line| line.unwrap())
+                .collect::<Vec<String>>();
+            assert_eq!(lines, ["third", "second", "first"]);
+        }
+
+        #[test]
+        fn rev_seek_uses_the_length() {
+            let data = b"0123456789";
+            let inner = Positioned(Cursor::new(data.as_slice()));
+            let mut reader = BufReadBacker::with_known_length(inner, 8).unwrap();
+
+            let mut buffer = [0; 3];
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"567");
+
+            assert_eq!(reader.rev_seek(RevSeekFrom::Back(1)).ok(), Some(1));
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"456");
+
+            let err = reader.rev_seek(RevSeekFrom::Front(9)).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+    }
+
+    mod set_min_read {
+        use super::*;
+
+        const DATA: &[u8] = b"abcdefghijklmnopqrst";
+
+        #[test]
+        fn bigger_than_the_capacity() {
+            let mut reader = BufReadBacker::with_capacity(4, Recording::new(DATA)).unwrap();
+            reader.set_min_read(6);
+
+            let mut buffer = Vec::new();
+            reader.read_back_to_end(&mut buffer).unwrap();
+            assert_eq!(buffer, DATA);
+
+            // only the block at the front is smaller
+            assert_eq!(reader.get_ref().reads(), [6, 6, 6, 2]);
+            assert_eq!(reader.capacity(), 6);
+        }
+
+        #[test]
+        fn peek_reads_at_least_min() {
+            let mut reader = BufReadBacker::with_capacity(8, Recording::new(DATA)).unwrap();
+            reader.set_min_read(5);
+
+            reader.read_back_fill_buf().unwrap();
+            reader.read_back_consume(1);
+            // only one byte is missing for a full buffer
+            assert_eq!(reader.peek(8).ok(), Some(b"lmnopqrs".as_slice()));
+            assert_eq!(reader.buffer(), b"hijklmnopqrs");
+
+            let mut buffer = Vec::new();
+            reader.read_back_to_end(&mut buffer).unwrap();
+            assert_eq!(buffer, &DATA[..19]);
+            assert!(reader.get_ref().reads().iter().all(|&amount| amount >= 5));
+        }
+
+        #[test]
+        fn vectored_reads_at_least_min() {
+            let mut reader = BufReadBacker::with_capacity(2, Recording::new(DATA)).unwrap();
+            reader.set_min_read(8);
+
+            let mut first = [0; 2];
+            let mut second = [0; 2];
+            let mut bufs = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+            assert_eq!(reader.read_back_vectored(&mut bufs).ok(), Some(4));
+            assert_eq!([first, second], [*b"qr", *b"st"]);
+            assert_eq!(reader.get_ref().reads(), [8]);
+        }
+    }
+
+    mod leftover_bytes {
+        use super::*;
+
+        /// Reads back one byte, so the block at the front of the source is only partially consumed.
+        fn partially_consumed(capacity: usize) -> BufReadBacker<Cursor<&'static [u8]>> {
+            let mut reader =
+                BufReadBacker::with_capacity(capacity, Cursor::new(b"first\nsecond".as_slice()))
+                    .unwrap();
+            reader.read_back_exact(&mut [0]).unwrap();
+            reader
+        }
+
+        #[test]
+        fn read_back_to_end() {
+            for capacity in [8, 12, 16] {
+                let mut reader = partially_consumed(capacity);
+                assert!(reader.get_ref().position() < 13);
+
+                let mut buffer = Vec::new();
+                assert_eq!(reader.read_back_to_end(&mut buffer).ok(), Some(11));
+                assert_eq!(buffer, b"first\nsecon", "capacity: {}", capacity);
+            }
+        }
+
+        #[test]
+        fn iterators() {
+            let bytes = partially_consumed(16)
+                .read_back_bytes()
+                .map(|b| b.unwrap())
+                .collect::<Vec<u8>>();
+            assert_eq!(bytes, b"noces\ntsrif");
+
+            let lines = partially_consumed(16)
+                .read_back_lines()
+                .map(|line| line.unwrap())
+                .collect::<Vec<String>>();
+            assert_eq!(lines, ["secon", "first"]);
+        }
+
+        #[test]
+        fn vectored() {
+            let mut reader = partially_consumed(16);
+
+            let mut first = [0; 8];
+            let mut second = [0; 8];
+            let mut bufs = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+            assert_eq!(reader.read_back_vectored(&mut bufs).ok(), Some(11));
+            assert_eq!(&first[..3], b"fir");
+            assert_eq!(&second, b"st\nsecon");
+        }
+    }
+
+    mod shrink_buffer_to {
+        use super::*;
+
+        #[test]
+        fn after_growing() {
+            let data = b"first\na much longer line\nlast";
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+
+            reader.ensure_capacity(32);
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"\nlast".as_slice())
+            );
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"\na much longer line".as_slice())
+            );
+
+            reader.shrink_buffer_to(4);
+            assert_eq!(reader.capacity(), 5);
+            assert_eq!(reader.buffer(), b"first");
+            assert_eq!(
+                reader.read_back_line_ref().unwrap(),
+                Some(b"first".as_slice())
+            );
+            assert_eq!(reader.read_back_line_ref().unwrap(), None);
+        }
+
+        #[test]
+        fn keeps_the_position() {
+            let mut reader =
+                BufReadBacker::with_capacity(8, Cursor::new(b"0123456789".as_slice())).unwrap();
+            reader.read_back_fill_buf().unwrap();
+            reader.read_back_consume(6);
+
+            reader.shrink_buffer_to(3);
+            assert_eq!(reader.capacity(), 3);
+            assert_eq!(reader.buffer(), b"23");
+
+            // one of the consumed bytes is still there
+            reader.rev_seek_relative(-1).unwrap();
+            assert_eq!(reader.buffer(), b"234");
+            // the other ones have been dropped, so the reader seeks
+            reader.rev_seek_relative(-1).unwrap();
+            assert!(reader.buffer().is_empty());
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"345".as_slice()));
+
+            let mut buffer = Vec::new();
+            reader.read_back_to_end(&mut buffer).unwrap();
+            assert_eq!(buffer, b"012345");
+        }
+
+        #[test]
+        fn never_below_one_byte() {
+            let mut reader =
+                BufReadBacker::with_capacity(4, Cursor::new(b"abc".as_slice())).unwrap();
+
+            reader.shrink_buffer_to(0);
+            assert_eq!(reader.capacity(), 1);
+
+            let mut buffer = Vec::new();
+            reader.read_back_to_end(&mut buffer).unwrap();
+            assert_eq!(buffer, b"abc");
+        }
+    }
+
+    mod set_refill_hook {
+        use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        #[test]
+        fn error_stops_the_scan() {
+            let data = [b'x'; 40];
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+
+            let calls = Arc::new(AtomicUsize::new(0));
+            let hook_calls = Arc::clone(&calls);
+            // fails on the third call, which is before the 6th refill
+            reader.set_refill_hook(2, move || match hook_calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Ok(()),
+                _ => Err(io::Error::other("cancelled")),
+            });
+
+            let mut bytes = 0;
+            let err = loop {
+                match reader.read_back_exact(&mut [0]) {
+                    Ok(()) => bytes += 1,
+                    Err(err) => break err,
+                }
+            };
+            assert_eq!(err.to_string(), "cancelled");
+            assert_eq!(bytes, 20);
+            assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+            // the hook is asked again
+            assert!(reader.read_back_fill_buf().is_err());
+            assert_eq!(calls.load(Ordering::SeqCst), 4);
+        }
+
+        #[test]
+        fn called_every_k_refills() {
+            let data = [b'x'; 40];
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+
+            let calls = Arc::new(AtomicUsize::new(0));
+            let hook_calls = Arc::clone(&calls);
+            reader.set_refill_hook(3, move || {
+                hook_calls.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            });
+
+            reader.read_back_to_end(&mut Vec::new()).unwrap();
+            // 10 refills
+            assert_eq!(calls.load(Ordering::SeqCst), 3);
+        }
+
+        #[test]
+        fn cancels_read_back_vectored() {
+            let data = [b'x'; 40];
+            let mut reader = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+            reader.set_refill_hook(2, || Err(io::Error::other("cancelled")));
+
+            let (mut a, mut b) = ([0; 4], [0; 4]);
+            let mut read_back_vectored = |reader: &mut BufReadBacker<_>| {
+                reader.read_back_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)])
+            };
+
+            // the first refill goes through, the second one is cancelled
+            assert_eq!(read_back_vectored(&mut reader).ok(), Some(8));
+            let err = read_back_vectored(&mut reader).unwrap_err();
+            assert_eq!(err.to_string(), "cancelled");
+
+            // nothing has been read back
+            let mut rest = Vec::new();
+            reader.set_refill_hook(1, || Ok(()));
+            reader.read_back_to_end(&mut rest).unwrap();
+            assert_eq!(rest.len(), 32);
+        }
+
+        #[test]
+        fn buf_read_backer_stays_send_and_sync() {
+            fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+            let mut reader = BufReadBacker::new(Cursor::new(b"data")).unwrap();
+            reader.set_refill_hook(1, || Ok(()));
+            assert_send_sync(&reader);
+        }
+    }
+
+    mod drop_and_into_inner {
+        use super::*;
+
+        #[test]
+        fn drop_does_no_io() {
+            let mut inner = Recording::new(b"0123456789");
+            let ops = {
+                let mut reader = BufReadBacker::with_capacity(4, &mut inner).unwrap();
+                reader.read_back_exact(&mut [0; 5]).unwrap();
+                reader.get_ref().ops.len()
+            };
+
+            assert_eq!(inner.ops.len(), ops);
+        }
+
+        #[test]
+        fn into_inner_is_at_the_block_start() {
+            let mut reader =
+                BufReadBacker::with_capacity(4, Recording::new(b"0123456789")).unwrap();
+            reader.read_back_exact(&mut [0; 5]).unwrap();
+            assert_eq!(reader.buffer(), b"234");
+            let ops = reader.get_ref().ops.len();
+
+            let mut inner = reader.into_inner();
+            assert_eq!(inner.ops.len(), ops);
+            assert_eq!(inner.inner.position(), 2);
+
+            let mut reader = BufReadBacker::with_capacity(4, &mut inner).unwrap();
+            reader.rev_seek(RevSeekFrom::Front(7)).unwrap();
+            assert_eq!(reader.into_inner().inner.position(), 7);
+        }
+    }
+
+    mod enable_adaptive {
+        use super::*;
+
+        /// Lines with 40 to 240 bytes.
+        fn records() -> Vec<u8> {
+            (0..60)
+                .flat_map(|i| {
+                    let mut line = vec![b'a' + (i % 26) as u8; 40 + (i * 37) % 200];
+                    line.push(b'\n');
+                    line
+                })
+                .collect()
+        }
+
+        /// Reads back all records and returns them with the amount of refills.
+        fn read_back_records(reader: &mut BufReadBacker<Recording<'_>>) -> (Vec<u8>, usize) {
+            let mut records = Vec::new();
+            loop {
+                let mut record = Vec::new();
+                if reader.read_back_until(b'\n', &mut record).unwrap() == 0 {
+                    break;
+                }
+                record.append(&mut records);
+                records = record;
+            }
+
+            (records, reader.get_ref().reads().len())
+        }
+
+        #[test]
+        fn fewer_refills() {
+            let data = records();
+
+            let mut fixed = BufReadBacker::with_capacity(32, Recording::new(&data)).unwrap();
+            let (fixed_records, fixed_refills) = read_back_records(&mut fixed);
+
+            let mut adaptive = BufReadBacker::with_capacity(32, Recording::new(&data)).unwrap();
+            adaptive.enable_adaptive(32, 2048);
+            let (adaptive_records, adaptive_refills) = read_back_records(&mut adaptive);
+
+            assert_eq!(fixed_records, data);
+            assert_eq!(adaptive_records, data);
+            assert!(
+                adaptive_refills * 4 < fixed_refills,
+                "adaptive: {}, fixed: {}",
+                adaptive_refills,
+                fixed_refills
+            );
+            assert!(adaptive.capacity() > 32);
+            assert_eq!(fixed.capacity(), 32);
+        }
+
+        #[test]
+        fn stays_within_the_bounds() {
+            let data = records();
+
+            let mut reader = BufReadBacker::with_capacity(8, Recording::new(&data)).unwrap();
+            reader.enable_adaptive(64, 300);
+            assert_eq!(reader.capacity(), 64);
+
+            let (records, _) = read_back_records(&mut reader);
+            assert_eq!(records, data);
+            assert_eq!(reader.capacity(), 300);
+        }
+    }
+
+    mod premature_eof {
+        use super::*;
+
+        /// Claims to be `len` bytes long, but every read returns `Ok(0)` as if the source ended right away.
+        struct Stalled {
+            len: u64,
+            pos: u64,
+        }
+
+        impl Read for Stalled {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Ok(0)
+            }
+        }
+
+        impl Seek for Stalled {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                self.pos = match pos {
+                    SeekFrom::Start(offset) => offset,
+                    SeekFrom::End(offset) => self.len.checked_add_signed(offset).unwrap(),
+                    SeekFrom::Current(offset) => self.pos.checked_add_signed(offset).unwrap(),
+                };
+                Ok(self.pos)
+            }
+        }
+
+        #[test]
+        fn read_back_exact() {
+            let mut reader = BufReadBacker::with_capacity(4, Stalled { len: 100, pos: 0 }).unwrap();
+
+            let err = reader.read_back_exact(&mut [0; 10]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+            // the reader stays where it was
+            assert_eq!(reader.get_ref().pos, 100);
+        }
+
+        #[test]
+        fn looping_methods_terminate() {
+            let mut reader = BufReadBacker::with_capacity(4, Stalled { len: 100, pos: 0 }).unwrap();
+
+            let err = reader.read_back_to_end(&mut Vec::new()).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+            let err = reader.read_back_until(b'\n', &mut Vec::new()).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+            let mut lines = reader.read_back_lines();
+            assert_eq!(
+                lines.next().map(|line| line.unwrap_err().kind()),
+                Some(ErrorKind::UnexpectedEof)
+            );
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::io::{self, ErrorKind};
+
+use crate::BufReadBack;
+
+/// The maximum length of the UTF-8 encoding of a `char`.
+const MAX_UTF8_LEN: usize = 4;
+
+/// An iterator over the `char`s of an UTF-8 encoded instance of [`BufReadBack`], starting with the last one.
+///
+/// The bytes are read back until the first byte of a code point (a byte which isn't a continuation byte `0b10xx_xxxx`)
+/// has been found, afterwards these 1 to 4 bytes are decoded forwards. A code point can span over multiple refills
+/// of the buffer of the reader.
+///
+/// Invalid UTF-8 yields an [`ErrorKind::InvalidData`] error, unless a replacement is set with [`replace_invalid`].
+/// Only the invalid bytes are skipped in both cases, so the iterator can continue with the `char`s in front of them.
+///
+/// This struct is generally created by calling [`read_back_chars`] on a [`BufReadBack`].
+///
+/// # Example
+/// ```
+/// use read_collection::BufReadBack;
+///
+/// fn main() {
+///     let chars = "aä€😀"
+///         .as_bytes()
+///         .read_back_chars()
+///         .map(|c| c.unwrap())
+///         .collect::<String>();
+///
+///     assert_eq!(chars, "😀€äa");
+/// }
+/// ```
+///
+/// [`replace_invalid`]: RevChars::replace_invalid
+/// [`read_back_chars`]: BufReadBack::read_back_chars
+#[derive(Debug)]
+pub struct RevChars<B> {
+    buf: B,
+    replacement: Option<char>,
+    /// Bytes which have been read back already but haven't been yielded yet. They're stored at the end of the array.
+    stash: [u8; MAX_UTF8_LEN],
+    /// The amount of bytes in `stash`.
+    stashed: usize,
+}
+
+impl<B> RevChars<B> {
+    pub(crate) fn new(buf: B) -> Self {
+        Self {
+            buf,
+            replacement: None,
+            stash: [0; MAX_UTF8_LEN],
+            stashed: 0,
+        }
+    }
+
+    /// Yields `replacement` for invalid UTF-8 instead of an error, for example [`char::REPLACEMENT_CHARACTER`].
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let chars = b"a\xffb"
+    ///         .as_slice()
+    ///         .read_back_chars()
+    ///         .replace_invalid(char::REPLACEMENT_CHARACTER)
+    ///         .map(|c| c.unwrap())
+    ///         .collect::<String>();
+    ///
+    ///     assert_eq!(chars, "b\u{FFFD}a");
+    /// }
+    /// ```
+    pub fn replace_invalid(mut self, replacement: char) -> Self {
+        self.replacement = Some(replacement);
+        self
+    }
+
+    /// Unwraps this `RevChars`, returning the underlying reader.
+    ///
+    /// The bytes of a code point which has only been partially read back are lost.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    fn stashed(&self) -> &[u8] {
+        &self.stash[MAX_UTF8_LEN - self.stashed..]
+    }
+
+    /// Drops the last `n` bytes of the stash.
+    fn drop_stashed(&mut self, n: usize) {
+        let start = MAX_UTF8_LEN - self.stashed;
+        self.stash.copy_within(start..MAX_UTF8_LEN - n, start + n);
+        self.stashed -= n;
+    }
+
+    fn invalid(&self) -> io::Result<char> {
+        self.replacement.ok_or_else(|| {
+            io::Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+        })
+    }
+}
+
+impl<B: BufReadBack> RevChars<B> {
+    /// Reads back bytes into the stash until it starts with the first byte of a code point, the stash is full or the
+    /// start of the reader has been reached.
+    fn fill_stash(&mut self) -> io::Result<()> {
+        while self.stashed < MAX_UTF8_LEN
+            && self.stashed().first().is_none_or(|&b| is_continuation(b))
+        {
+            let byte = match self.buf.read_back_fill_buf() {
+                Ok(available) => match available.last() {
+                    Some(&byte) => byte,
+                    None => break,
+                },
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.buf.read_back_consume(1);
+
+            self.stashed += 1;
+            self.stash[MAX_UTF8_LEN - self.stashed] = byte;
+        }
+
+        Ok(())
+    }
+}
+
+impl<B: BufReadBack> Iterator for RevChars<B> {
+    type Item = io::Result<char>;
+
+    fn next(&mut self) -> Option<io::Result<char>> {
+        if let Err(e) = self.fill_stash() {
+            return Some(Err(e));
+        }
+
+        let stashed = self.stashed();
+        let first = *stashed.first()?;
+
+        if let Ok(s) = std::str::from_utf8(stashed) {
+            // there's at most one code point in the stash since it starts with the first byte of the last one
+            let c = s.chars().next();
+            self.stashed = 0;
+            return c.map(Ok);
+        }
+
+        // A truncated code point is one invalid sequence, like for `String::from_utf8_lossy`. Otherwise, only the
+        // last byte is invalid, the bytes in front of it might still be valid on their own.
+        let expected_len = utf8_len(first);
+        if expected_len > stashed.len() && stashed[1..].iter().all(|&b| is_continuation(b)) {
+            self.stashed = 0;
+        } else {
+            self.drop_stashed(1);
+        }
+
+        Some(self.invalid())
+    }
+}
+
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+/// Returns the length of the code point which starts with `first`, `0` if it can't start one.
+fn utf8_len(first: u8) -> usize {
+    match first {
+        0x00..=0x7f => 1,
+        0xc2..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf4 => 4,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BufReadBacker;
+    use std::io::Cursor;
+
+    const MIXED: &str = "a\u{e4}\u{20ac}\u{1f600}b\u{10348}\u{e9}\u{2603}c";
+
+    fn reversed(s: &str) -> String {
+        s.chars().rev().collect()
+    }
+
+    #[test]
+    fn all_lengths() {
+        let chars = MIXED
+            .as_bytes()
+            .read_back_chars()
+            .collect::<io::Result<String>>()
+            .unwrap();
+
+        assert_eq!(chars, reversed(MIXED));
+    }
+
+    #[test]
+    fn across_refills() {
+        for capacity in 1..=8 {
+            let reader =
+                BufReadBacker::with_capacity(capacity, Cursor::new(MIXED.as_bytes())).unwrap();
+            let chars = reader
+                .read_back_chars()
+                .collect::<io::Result<String>>()
+                .unwrap();
+
+            assert_eq!(chars, reversed(MIXED), "capacity: {}", capacity);
+        }
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let mut chars = b"a\x80\x80b\xff".as_slice().read_back_chars();
+
+        assert_eq!(
+            chars.next().unwrap().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(chars.next().unwrap().ok(), Some('b'));
+        assert!(chars.next().unwrap().is_err());
+        assert!(chars.next().unwrap().is_err());
+        assert_eq!(chars.next().unwrap().ok(), Some('a'));
+        assert!(chars.next().is_none());
+    }
+
+    #[test]
+    fn truncated_code_point() {
+        // the last byte of the euro sign is missing
+        let chars = b"a\xe2\x82"
+            .as_slice()
+            .read_back_chars()
+            .replace_invalid('?')
+            .collect::<io::Result<String>>()
+            .unwrap();
+
+        assert_eq!(chars, "?a");
+    }
+
+    #[test]
+    fn too_many_continuation_bytes() {
+        let chars = [0xc3, 0xa4, 0x80, 0x80, 0x80, 0x80, 0x80];
+
+        let chars = chars
+            .as_slice()
+            .read_back_chars()
+            .replace_invalid('?')
+            .collect::<io::Result<String>>()
+            .unwrap();
+
+        assert_eq!(chars, "?????\u{e4}");
+    }
+}
@@ -0,0 +1,128 @@
+use std::io::{self, ErrorKind, Write};
+use std::mem::MaybeUninit;
+
+use crate::{ReadBack, RevBorrowedBuf, DEFAULT_BUF_SIZE};
+
+/// Reads back the whole `reader` and writes its bytes into `writer`, like [`io::copy`] does for a [`Read`]er.
+///
+/// The bytes are written in the order of the source, so copying a file which has been read back from its end
+/// results in the file itself. Since the reader yields the last bytes first, nothing can be written before the
+/// start of the source has been reached: all chunks are collected in memory first and written in the order of the
+/// source with [`write_all`] afterwards. Reading back goes through a stack buffer which doesn't need to be zeroed.
+///
+/// On success, the total amount of copied bytes is returned. [`ErrorKind::Interrupted`] errors of the reader and
+/// the writer are retried, all other errors are returned and nothing has been written if reading back failed.
+///
+/// # Example
+/// ```
+/// use read_collection::read_back_copy;
+///
+/// fn main() {
+///     let mut data = b"Hello there".as_slice();
+///     let mut out = Vec::new();
+///
+///     assert_eq!(read_back_copy(&mut data, &mut out).ok(), Some(11));
+///     assert_eq!(out, b"Hello there");
+///     assert!(data.is_empty());
+/// }
+/// ```
+///
+/// [`Read`]: std::io::Read
+/// [`write_all`]: Write::write_all
+pub fn read_back_copy<R, W>(reader: &mut R, writer: &mut W) -> io::Result<u64>
+where
+    R: ReadBack + ?Sized,
+    W: Write + ?Sized,
+{
+    let mut stack_buf = [MaybeUninit::<u8>::uninit(); DEFAULT_BUF_SIZE];
+    let mut buf = RevBorrowedBuf::from(stack_buf.as_mut_slice());
+
+    // the chunks which have been read back, the first one of the source is at the end
+    let mut chunks = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_back_buf(buf.unfilled()) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+
+        if buf.is_empty() {
+            break;
+        }
+        chunks.push(buf.filled().to_vec());
+    }
+
+    let mut amount = 0;
+    for chunk in chunks.iter().rev() {
+        writer.write_all(chunk)?;
+        amount += chunk.len() as u64;
+    }
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BufReadBacker;
+    use std::io::Cursor;
+
+    /// Accepts at most 3 bytes per write and is interrupted before every write.
+    #[derive(Default)]
+    struct Choppy {
+        written: Vec<u8>,
+        interrupt: bool,
+    }
+
+    impl Write for Choppy {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(ErrorKind::Interrupted.into());
+            }
+
+            let amount = buf.len().min(3);
+            self.written.extend_from_slice(&buf[..amount]);
+            Ok(amount)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn empty_reader() {
+        let mut out = Vec::new();
+
+        assert_eq!(read_back_copy(&mut io::empty(), &mut out).ok(), Some(0));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn bigger_than_the_stack_buffer() {
+        let data = (0..3 * DEFAULT_BUF_SIZE + 5)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<u8>>();
+        let mut reader = BufReadBacker::with_capacity(100, Cursor::new(data.as_slice())).unwrap();
+        let mut out = vec![1, 2];
+
+        assert_eq!(
+            read_back_copy(&mut reader, &mut out).ok(),
+            Some(data.len() as u64)
+        );
+        assert_eq!(out[..2], [1, 2]);
+        assert_eq!(out[2..], data);
+    }
+
+    #[test]
+    fn interrupted_writes() {
+        let mut writer = Choppy::default();
+
+        assert_eq!(
+            read_back_copy(&mut b"interrupted".as_slice(), &mut writer).ok(),
+            Some(11)
+        );
+        assert_eq!(writer.written, b"interrupted");
+    }
+}
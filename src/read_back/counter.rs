@@ -0,0 +1,200 @@
+use std::io::{self, IoSliceMut};
+
+use crate::{BufReadBack, ReadBack, RevBorrowedCursor};
+
+/// Reader adapter which counts the bytes which have been read back from the underlying reader, for example to
+/// report the progress while reading back a file.
+///
+/// Every successful call of [`read_back`], [`read_back_vectored`] and [`read_back_buf`] adds the amount of read
+/// bytes. If the underlying reader is a [`BufReadBack`], the bytes are counted when they're consumed with
+/// [`read_back_consume`] instead of when the buffer is refilled, so the bytes which are still buffered aren't
+/// counted yet. Failed reads, like [`ErrorKind::Interrupted`] ones, don't count anything.
+///
+/// # Example
+/// ```
+/// use read_collection::{ReadBack, RevCounter};
+///
+/// fn main() {
+///     let mut reader = RevCounter::new(b"some bytes".as_slice());
+///     let mut buffer = [0; 5];
+///
+///     reader.read_back_exact(&mut buffer).unwrap();
+///     assert_eq!(reader.count(), 5);
+///
+///     reader.read_back_to_end(&mut Vec::new()).unwrap();
+///     assert_eq!(reader.count(), 10);
+/// }
+/// ```
+///
+/// [`read_back`]: ReadBack::read_back
+/// [`read_back_vectored`]: ReadBack::read_back_vectored
+/// [`read_back_buf`]: ReadBack::read_back_buf
+/// [`read_back_consume`]: BufReadBack::read_back_consume
+/// [`ErrorKind::Interrupted`]: io::ErrorKind::Interrupted
+#[derive(Debug)]
+pub struct RevCounter<R> {
+    inner: R,
+    count: u64,
+    /// The length of the buffer which has been returned by the last `read_back_fill_buf`, so consuming more than
+    /// that isn't counted.
+    buffered: usize,
+}
+
+impl<R> RevCounter<R> {
+    /// Creates a new `RevCounter` which hasn't counted any bytes yet.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            count: 0,
+            buffered: 0,
+        }
+    }
+
+    /// Returns the number of bytes which have been read back so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Bytes which are read back from it directly aren't counted.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this `RevCounter`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn add(&mut self, amount: usize) {
+        self.count += amount as u64;
+        // the buffer of the underlying reader has changed, so it has to be filled again before consuming
+        self.buffered = 0;
+    }
+}
+
+impl<R: ReadBack> ReadBack for RevCounter<R> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.inner.read_back(buf)?;
+        self.add(amount);
+        Ok(amount)
+    }
+
+    fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let amount = self.inner.read_back_vectored(bufs)?;
+        self.add(amount);
+        Ok(amount)
+    }
+
+    fn is_read_back_vectored(&self) -> bool {
+        self.inner.is_read_back_vectored()
+    }
+
+    fn read_back_buf(&mut self, mut cursor: RevBorrowedCursor<'_>) -> io::Result<()> {
+        let written = cursor.written();
+        self.inner.read_back_buf(cursor.reborrow())?;
+        self.add(cursor.written() - written);
+        Ok(())
+    }
+}
+
+impl<R: BufReadBack> BufReadBack for RevCounter<R> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        let buf = self.inner.read_back_fill_buf()?;
+        self.buffered = buf.len();
+        Ok(buf)
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        let amt = amt.min(self.buffered);
+        self.count += amt as u64;
+        self.buffered -= amt;
+        self.inner.read_back_consume(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BufReadBacker;
+    use std::io::{Cursor, ErrorKind};
+
+    /// Returns an `Interrupted` error for every second call.
+    struct Interrupting<'a> {
+        data: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl ReadBack for Interrupting<'_> {
+        fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(ErrorKind::Interrupted.into());
+            }
+
+            let amount = buf.len().min(self.data.len()).min(3);
+            self.data.read_back(&mut buf[..amount])
+        }
+    }
+
+    #[test]
+    fn full_drain() {
+        let data = b"0123456789";
+        let mut reader = RevCounter::new(data.as_slice());
+
+        let mut buffer = Vec::new();
+        reader.read_back_to_end(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+        assert_eq!(reader.count(), data.len() as u64);
+    }
+
+    #[test]
+    fn interrupted_reads() {
+        let data = b"0123456789";
+        let mut reader = RevCounter::new(Interrupting {
+            data,
+            interrupt: false,
+        });
+
+        let mut buffer = Vec::new();
+        reader.read_back_to_end(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+        assert_eq!(reader.count(), data.len() as u64);
+    }
+
+    #[test]
+    fn counts_consumed_bytes() {
+        let data = b"first\nsecond\nthird";
+        let inner = BufReadBacker::with_capacity(4, Cursor::new(data.as_slice())).unwrap();
+        let mut reader = RevCounter::new(inner);
+
+        assert_eq!(reader.read_back_fill_buf().unwrap(), b"hird");
+        assert_eq!(reader.count(), 0);
+        reader.read_back_consume(10);
+        assert_eq!(reader.count(), 4);
+
+        let mut line = String::new();
+        reader.read_back_line(&mut line).unwrap();
+        assert_eq!(reader.count(), 6);
+
+        let lines = reader.read_back_lines().count();
+        assert_eq!(lines, 2);
+    }
+
+    #[test]
+    fn read_back_buf() {
+        let mut reader = RevCounter::new(b"abc".as_slice());
+        let mut data = [0; 2];
+        let mut buf = crate::RevBorrowedBuf::from(data.as_mut_slice());
+
+        reader.read_back_buf(buf.unfilled()).unwrap();
+        assert_eq!(buf.filled(), b"bc");
+        assert_eq!(reader.count(), 2);
+    }
+}
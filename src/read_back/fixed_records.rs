@@ -0,0 +1,111 @@
+use std::io;
+
+use crate::read_back::read_back_fixed_record;
+use crate::ReadBack;
+
+/// An iterator over the records of an instance of [`ReadBack`] which all have the same size, starting with the last
+/// one.
+///
+/// Each record is in forward order. If the length of the source isn't a multiple of the record size, the bytes in
+/// front of the last complete record yield an [`ErrorKind::InvalidData`] error and the iteration ends afterwards.
+///
+/// This struct is generally created by calling [`read_back_fixed_records`] on a [`ReadBack`].
+///
+/// # Example
+/// ```
+/// use read_collection::ReadBack;
+///
+/// fn main() {
+///     let mut records = b"xabcd".as_slice().read_back_fixed_records(2);
+///
+///     assert_eq!(records.next().unwrap().ok(), Some(b"cd".to_vec()));
+///     assert_eq!(records.next().unwrap().ok(), Some(b"ab".to_vec()));
+///     assert!(records.next().unwrap().is_err());
+///     assert!(records.next().is_none());
+/// }
+/// ```
+///
+/// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+/// [`read_back_fixed_records`]: ReadBack::read_back_fixed_records
+#[derive(Debug)]
+pub struct RevFixedRecords<R> {
+    inner: R,
+    size: usize,
+}
+
+impl<R> RevFixedRecords<R> {
+    pub(crate) fn new(inner: R, size: usize) -> Self {
+        assert!(size > 0, "record size has to be greater than 0");
+        Self { inner, size }
+    }
+
+    /// Unwraps this `RevFixedRecords`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: ReadBack> Iterator for RevFixedRecords<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = vec![0; self.size];
+
+        match read_back_fixed_record(&mut self.inner, &mut record) {
+            Ok(true) => Some(Ok(record)),
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BufReadBacker;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn exact_multiple() {
+        for capacity in 1..=9 {
+            let reader = BufReadBacker::with_capacity(capacity, Cursor::new(b"abcdefghi")).unwrap();
+            let records = reader
+                .read_back_fixed_records(3)
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+
+            assert_eq!(records, [b"ghi", b"def", b"abc"], "capacity: {}", capacity);
+        }
+    }
+
+    #[test]
+    fn partial_record_at_the_front() {
+        let mut records = b"abcdefg".as_slice().read_back_fixed_records(3);
+
+        assert_eq!(records.next().unwrap().ok(), Some(b"efg".to_vec()));
+        assert_eq!(records.next().unwrap().ok(), Some(b"bcd".to_vec()));
+
+        let err = records.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(records.next().is_none());
+    }
+
+    #[test]
+    fn const_record() {
+        let mut data = b"\x01\x02\x03\x04\x05".as_slice();
+
+        assert_eq!(data.read_back_record::<2>().ok(), Some(Some([4, 5])));
+        assert_eq!(data.read_back_record::<2>().ok(), Some(Some([2, 3])));
+        assert_eq!(
+            data.read_back_record::<2>().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(data.read_back_record::<2>().ok(), Some(None));
+    }
+
+    #[test]
+    fn empty_source() {
+        assert!(b"".as_slice().read_back_fixed_records(4).next().is_none());
+        assert_eq!(b"".as_slice().read_back_record::<4>().ok(), Some(None));
+    }
+}
@@ -0,0 +1,445 @@
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom};
+
+use flate2::read::GzDecoder;
+
+use crate::{BufReadBack, ReadBack};
+
+/// A [`BufReadBack`]er for gzip files which consist of independently compressed blocks (gzip members), like
+/// bgzip files.
+///
+/// The offsets of the blocks within the compressed file have to be known (the index). Reading back starts at
+/// the newest (last) block and only decompresses a block once all bytes of the block after it have been read
+/// back, so only the blocks which are actually needed are decompressed.
+///
+/// # Example
+/// ```
+/// use std::io::{Cursor, Write};
+/// use flate2::{write::GzEncoder, Compression};
+/// use read_collection::{BufReadBack, IndexedGzReadBacker};
+///
+/// fn compress(data: &[u8]) -> Vec<u8> {
+///     let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+///     encoder.write_all(data).unwrap();
+///     encoder.finish().unwrap()
+/// }
+///
+/// fn main() {
+///     let mut file = compress(b"first\n");
+///     let index = vec![0, file.len() as u64];
+///     file.extend(compress(b"second\n"));
+///
+///     let lines = IndexedGzReadBacker::new(Cursor::new(file), index)
+///         .unwrap()
+///         .read_back_lines()
+///         .map(|line| line.unwrap())
+///         .collect::<Vec<String>>();
+///
+///     assert_eq!(lines, ["second", "first"]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct IndexedGzReadBacker<R> {
+    inner: R,
+    /// The start offsets of the blocks which haven't been decompressed yet, followed by the end of the last one.
+    bounds: Vec<u64>,
+    /// The decompressed content of the current block.
+    block: Vec<u8>,
+    /// The index (exclusively) until which `block` hasn't been read back yet.
+    pos: usize,
+}
+
+impl<R: Read + Seek> IndexedGzReadBacker<R> {
+    /// Creates a new `IndexedGzReadBacker` from the compressed source and the start offsets of its blocks.
+    ///
+    /// # Error
+    /// Returns an [`ErrorKind::InvalidInput`] error if the offsets aren't sorted or if one of them is beyond the
+    /// end of `inner`.
+    pub fn new(mut inner: R, index: Vec<u64>) -> io::Result<Self> {
+        let len = inner.seek(SeekFrom::End(0))?;
+
+        let mut bounds = index;
+        bounds.push(len);
+        if bounds.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "block offsets have to be sorted and within the compressed source",
+            ));
+        }
+
+        Ok(Self {
+            inner,
+            bounds,
+            block: Vec::new(),
+            pos: 0,
+        })
+    }
+
+    /// Returns the amount of blocks which haven't been decompressed yet.
+    pub fn remaining_blocks(&self) -> usize {
+        self.bounds.len() - 1
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps this `IndexedGzReadBacker`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Decompresses the newest block which hasn't been decompressed yet.
+    fn decompress_prev_block(&mut self) -> io::Result<()> {
+        let end = self.bounds[self.bounds.len() - 1];
+        let start = self.bounds[self.bounds.len() - 2];
+
+        decompress_range(&mut self.inner, start, end, &mut self.block)?;
+        self.pos = self.block.len();
+        self.bounds.pop();
+
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> ReadBack for IndexedGzReadBacker<R> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.read_back_fill_buf()?.read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+}
+
+impl<R: Read + Seek> BufReadBack for IndexedGzReadBacker<R> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        // blocks might be empty, so we need to loop
+        while self.pos == 0 && self.remaining_blocks() > 0 {
+            self.decompress_prev_block()?;
+        }
+
+        Ok(&self.block[..self.pos])
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+}
+
+/// Decompresses the gzip data between the offsets `start` and `end` of `inner` into `dest`.
+fn decompress_range<R: Read + Seek>(
+    inner: &mut R,
+    start: u64,
+    end: u64,
+    dest: &mut Vec<u8>,
+) -> io::Result<()> {
+    inner.seek(SeekFrom::Start(start))?;
+    let mut decoder = GzDecoder::new(inner.take(end - start));
+
+    dest.clear();
+    decoder.read_to_end(dest)?;
+
+    Ok(())
+}
+
+/// How the length of a frame of a [`FramedGzReadBacker`] is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameLength {
+    /// The length is stored as a little endian `u32`.
+    U32Le,
+    /// The length is stored as a big endian `u32`.
+    U32Be,
+    /// The length is stored as a little endian `u64`.
+    U64Le,
+    /// The length is stored as a big endian `u64`.
+    U64Be,
+}
+
+impl FrameLength {
+    /// Returns the amount of bytes which the length takes up.
+    pub fn size(self) -> usize {
+        match self {
+            FrameLength::U32Le | FrameLength::U32Be => 4,
+            FrameLength::U64Le | FrameLength::U64Be => 8,
+        }
+    }
+
+    /// Decodes the length, `bytes` has to be [`size`](FrameLength::size) bytes long.
+    fn decode(self, bytes: &[u8]) -> u64 {
+        match self {
+            FrameLength::U32Le => u32::from_le_bytes(bytes.try_into().unwrap()) as u64,
+            FrameLength::U32Be => u32::from_be_bytes(bytes.try_into().unwrap()) as u64,
+            FrameLength::U64Le => u64::from_le_bytes(bytes.try_into().unwrap()),
+            FrameLength::U64Be => u64::from_be_bytes(bytes.try_into().unwrap()),
+        }
+    }
+}
+
+/// A [`BufReadBack`]er for append-only logs where each record is compressed on its own and is followed by
+/// its compressed length:
+///
+/// ```text
+/// [gzip record 1][length of record 1][gzip record 2][length of record 2]...
+/// ```
+///
+/// Reading back starts at the end of the source: the length in front of it tells where the newest record
+/// starts, so the records get decompressed one after another, newest first.
+///
+/// # Example
+/// ```
+/// use std::io::{Cursor, Write};
+/// use flate2::{write::GzEncoder, Compression};
+/// use read_collection::{BufReadBack, FrameLength, FramedGzReadBacker};
+///
+/// fn append_record(log: &mut Vec<u8>, record: &[u8]) {
+///     let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+///     encoder.write_all(record).unwrap();
+///     let compressed = encoder.finish().unwrap();
+///
+///     log.extend(&compressed);
+///     log.extend((compressed.len() as u32).to_le_bytes());
+/// }
+///
+/// fn main() {
+///     let mut log = Vec::new();
+///     append_record(&mut log, b"{\"id\": 1}\n");
+///     append_record(&mut log, b"{\"id\": 2}\n");
+///
+///     let mut reader = FramedGzReadBacker::new(Cursor::new(log), FrameLength::U32Le).unwrap();
+///     reader.read_back_skip_until(b'\n').unwrap();
+///
+///     let mut newest = String::new();
+///     reader.read_back_line(&mut newest).unwrap();
+///     assert_eq!(newest, "\n{\"id\": 2}");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FramedGzReadBacker<R> {
+    inner: R,
+    length: FrameLength,
+    /// The offset until which the frames haven't been decompressed yet.
+    frames_end: u64,
+    /// The decompressed content of the current record.
+    record: Vec<u8>,
+    /// The index (exclusively) until which `record` hasn't been read back yet.
+    pos: usize,
+}
+
+impl<R: Read + Seek> FramedGzReadBacker<R> {
+    /// Creates a new `FramedGzReadBacker` which reads back the frames of `inner` from its end on.
+    pub fn new(mut inner: R, length: FrameLength) -> io::Result<Self> {
+        let frames_end = inner.seek(SeekFrom::End(0))?;
+
+        Ok(Self {
+            inner,
+            length,
+            frames_end,
+            record: Vec::new(),
+            pos: 0,
+        })
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps this `FramedGzReadBacker`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Decompresses the newest record which hasn't been decompressed yet.
+    fn decompress_prev_record(&mut self) -> io::Result<()> {
+        let length_size = self.length.size() as u64;
+        let invalid_frame = || {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                "frame length points before the start of the source",
+            )
+        };
+
+        let record_end = self
+            .frames_end
+            .checked_sub(length_size)
+            .ok_or_else(invalid_frame)?;
+
+        let mut length_bytes = [0; 8];
+        let length_bytes = &mut length_bytes[..self.length.size()];
+        self.inner.seek(SeekFrom::Start(record_end))?;
+        self.inner.read_exact(length_bytes)?;
+
+        let record_start = record_end
+            .checked_sub(self.length.decode(length_bytes))
+            .ok_or_else(invalid_frame)?;
+
+        decompress_range(&mut self.inner, record_start, record_end, &mut self.record)?;
+        self.pos = self.record.len();
+        self.frames_end = record_start;
+
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> ReadBack for FramedGzReadBacker<R> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.read_back_fill_buf()?.read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+}
+
+impl<R: Read + Seek> BufReadBack for FramedGzReadBacker<R> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        // records might be empty, so we need to loop
+        while self.pos == 0 && self.frames_end > 0 {
+            self.decompress_prev_record()?;
+        }
+
+        Ok(&self.record[..self.pos])
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::{Cursor, Write};
+
+    mod indexed {
+        use super::*;
+
+        fn compress(data: &[u8]) -> Vec<u8> {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+
+        /// Compresses every block on its own and returns the concatenated blocks with their index.
+        fn indexed_gzip(blocks: &[&[u8]]) -> (Vec<u8>, Vec<u64>) {
+            let mut file = Vec::new();
+            let mut index = Vec::new();
+
+            for block in blocks {
+                index.push(file.len() as u64);
+                file.extend(compress(block));
+            }
+
+            (file, index)
+        }
+
+        #[test]
+        fn two_blocks() {
+            let (file, index) = indexed_gzip(&[b"first block\n", b"second block\n"]);
+            let mut reader = IndexedGzReadBacker::new(Cursor::new(file), index).unwrap();
+
+            let mut buffer = Vec::new();
+            assert_eq!(reader.read_back_to_end(&mut buffer).ok(), Some(25));
+            assert_eq!(&buffer, b"first block\nsecond block\n");
+        }
+
+        #[test]
+        fn only_needed_blocks_are_decompressed() {
+            let (file, index) = indexed_gzip(&[b"first block\n", b"second block\n"]);
+            let mut reader = IndexedGzReadBacker::new(Cursor::new(file), index).unwrap();
+
+            let mut buffer = [0; 6];
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"block\n");
+            assert_eq!(reader.remaining_blocks(), 1);
+
+            // a record spanning both blocks
+            let mut record = Vec::new();
+            reader.read_back_until(b'\n', &mut record).unwrap();
+            assert_eq!(&record, b"\nsecond ");
+            assert_eq!(reader.remaining_blocks(), 0);
+        }
+
+        #[test]
+        fn empty_block() {
+            let (file, index) = indexed_gzip(&[b"data", b""]);
+            let mut reader = IndexedGzReadBacker::new(Cursor::new(file), index).unwrap();
+
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"data".as_slice()));
+        }
+
+        #[test]
+        fn invalid_index() {
+            let (file, _index) = indexed_gzip(&[b"first", b"second"]);
+            let len = file.len() as u64;
+
+            let err = IndexedGzReadBacker::new(Cursor::new(file), vec![0, len + 1]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+    }
+
+    mod framed {
+        use super::*;
+
+        fn framed_log(records: &[&[u8]], length: FrameLength) -> Vec<u8> {
+            let mut log = Vec::new();
+
+            for record in records {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(record).unwrap();
+                let compressed = encoder.finish().unwrap();
+                let len = compressed.len() as u64;
+
+                log.extend(compressed);
+                match length {
+                    FrameLength::U32Le => log.extend((len as u32).to_le_bytes()),
+                    FrameLength::U32Be => log.extend((len as u32).to_be_bytes()),
+                    FrameLength::U64Le => log.extend(len.to_le_bytes()),
+                    FrameLength::U64Be => log.extend(len.to_be_bytes()),
+                }
+            }
+
+            log
+        }
+
+        #[test]
+        fn two_records() {
+            for length in [
+                FrameLength::U32Le,
+                FrameLength::U32Be,
+                FrameLength::U64Le,
+                FrameLength::U64Be,
+            ] {
+                let log = framed_log(&[b"{\"id\":1}\n", b"{\"id\":2}\n"], length);
+                let lines = FramedGzReadBacker::new(Cursor::new(log), length)
+                    .unwrap()
+                    .read_back_lines()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+
+                assert_eq!(lines, ["{\"id\":2}", "{\"id\":1}"]);
+            }
+        }
+
+        #[test]
+        fn newest_record_first() {
+            let log = framed_log(&[b"old", b"new"], FrameLength::U32Le);
+            let mut reader = FramedGzReadBacker::new(Cursor::new(log), FrameLength::U32Le).unwrap();
+
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"new".as_slice()));
+            reader.read_back_consume(3);
+            assert_eq!(reader.read_back_fill_buf().ok(), Some(b"old".as_slice()));
+        }
+
+        #[test]
+        fn length_before_start() {
+            let mut log = framed_log(&[b"record"], FrameLength::U32Le);
+            let len = log.len();
+            log[len - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+
+            let mut reader = FramedGzReadBacker::new(Cursor::new(log), FrameLength::U32Le).unwrap();
+            let err = reader.read_back_fill_buf().unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+    }
+}
@@ -0,0 +1,431 @@
+use std::cmp;
+use std::io::Cursor;
+
+use crate::read_back::footer_too_short_error;
+use crate::BufReadBack;
+use crate::ReadBack;
+
+/// Returns the bytes in front of the cursor's position.
+///
+/// A position which is past the end of the inner buffer is treated as if it were at the end.
+fn remaining_back<T: AsRef<[u8]>>(cursor: &Cursor<T>) -> &[u8] {
+    let inner = cursor.get_ref().as_ref();
+    let pos = cmp::min(cursor.position(), inner.len() as u64) as usize;
+
+    &inner[..pos]
+}
+
+/// Moves the position of the cursor `amt` bytes towards the start of the inner buffer.
+fn consume_back<T: AsRef<[u8]>>(cursor: &mut Cursor<T>, amt: usize) {
+    let pos = remaining_back(cursor).len();
+    cursor.set_position(pos.saturating_sub(amt) as u64);
+}
+
+/// Moves the position of the cursor `n` bytes towards the start without reading them.
+fn skip_footer<T: AsRef<[u8]>>(cursor: &mut Cursor<T>, n: usize) -> std::io::Result<()> {
+    if n > remaining_back(cursor).len() {
+        cursor.set_position(0);
+        return Err(footer_too_short_error());
+    }
+
+    consume_back(cursor, n);
+    Ok(())
+}
+
+/// Reads back the bytes which are in front of the [`position`] of the cursor, so a cursor which has been read
+/// forwards can be read back to where it started. Each read moves the position towards the start of the inner
+/// buffer, a fully read back cursor is at position `0`. Use [`Seek`] (or [`set_position`]) with
+/// [`SeekFrom::End`] to read back from the end of the inner buffer instead.
+///
+/// The length of the inner buffer is checked on each read, so a `Vec` can be truncated or grow (via
+/// [`get_mut`]) between two reads. If it has been truncated in front of the position, reading back goes on at the
+/// end of the buffer.
+///
+/// # Example
+/// ```
+/// use std::io::{Cursor, Seek, SeekFrom};
+/// use read_collection::ReadBack;
+///
+/// fn main() {
+///     let mut cursor = Cursor::new(vec![1, 2, 3, 4]);
+///     cursor.seek(SeekFrom::End(0)).unwrap();
+///
+///     let mut buffer = [0; 3];
+///     cursor.read_back_exact(&mut buffer).unwrap();
+///     assert_eq!(buffer, [2, 3, 4]);
+///     assert_eq!(cursor.position(), 1);
+/// }
+/// ```
+///
+/// [`position`]: std::io::Cursor::position
+/// [`set_position`]: std::io::Cursor::set_position
+/// [`get_mut`]: std::io::Cursor::get_mut
+/// [`Seek`]: std::io::Seek
+/// [`SeekFrom::End`]: std::io::SeekFrom::End
+impl<T: AsRef<[u8]>> ReadBack for Cursor<T> {
+    fn read_back(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let amount = remaining_back(self).read_back(buf)?;
+        consume_back(self, amount);
+
+        Ok(amount)
+    }
+
+    fn read_back_count_bytes(&mut self) -> std::io::Result<u64> {
+        let amount = remaining_back(self).len();
+        self.set_position(0);
+
+        Ok(amount as u64)
+    }
+
+    fn read_back_skip_footer(&mut self, n: usize) -> std::io::Result<()> {
+        skip_footer(self, n)
+    }
+}
+
+impl<T: AsRef<[u8]>> BufReadBack for Cursor<T> {
+    fn read_back_fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(remaining_back(self))
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        consume_back(self, amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Seek, SeekFrom};
+
+    mod read_back {
+        use super::*;
+
+        #[test]
+        fn position_at_start() {
+            let data: [u8; 3] = [1, 2, 3];
+            let mut cursor = Cursor::new(data.as_slice());
+            let mut buffer = [0; 3];
+
+            assert_eq!(cursor.read_back(&mut buffer).ok(), Some(0));
+            assert_eq!(cursor.position(), 0);
+        }
+
+        #[test]
+        fn position_in_between() {
+            let data: [u8; 4] = [1, 2, 3, 4];
+            let mut cursor = Cursor::new(data.as_slice());
+            cursor.set_position(3);
+
+            let mut buffer = [0; 2];
+            assert_eq!(cursor.read_back(&mut buffer).ok(), Some(2));
+            assert_eq!(buffer, [2, 3]);
+            assert_eq!(cursor.position(), 1);
+
+            assert_eq!(cursor.read_back(&mut buffer).ok(), Some(1));
+            assert_eq!(buffer, [1, 3]);
+            assert_eq!(cursor.position(), 0);
+        }
+
+        #[test]
+        fn position_past_the_end() {
+            let data: [u8; 3] = [1, 2, 3];
+            let mut cursor = Cursor::new(data.as_slice());
+            cursor.set_position(10);
+
+            let mut buffer = [0; 1];
+            assert_eq!(cursor.read_back(&mut buffer).ok(), Some(1));
+            assert_eq!(buffer, [3]);
+            assert_eq!(cursor.position(), 2);
+        }
+
+        #[test]
+        fn read_then_read_back() {
+            let data: [u8; 5] = [1, 2, 3, 4, 5];
+            let mut cursor = Cursor::new(data.as_slice());
+
+            let mut read_buffer = [0; 3];
+            cursor.read_exact(&mut read_buffer).unwrap();
+
+            let mut read_back_buffer = [0; 3];
+            cursor.read_back_exact(&mut read_back_buffer).unwrap();
+
+            assert_eq!(read_buffer, read_back_buffer);
+            assert_eq!(cursor.position(), 0);
+        }
+
+        #[test]
+        fn sub_slice_of_owned_buffer() {
+            let owned: Vec<u8> = b"header|body|footer".to_vec();
+            let mut cursor = Cursor::new(&owned[7..]);
+            cursor.seek(SeekFrom::Start(4)).unwrap();
+
+            let mut buffer = [0; 4];
+            assert!(cursor.read_back_exact(&mut buffer).is_ok());
+            assert_eq!(&buffer, b"body");
+            assert_eq!(cursor.position(), 0);
+        }
+
+        #[test]
+        fn mut_slice() {
+            let mut data: [u8; 3] = [1, 2, 3];
+            let mut cursor = Cursor::new(data.as_mut_slice());
+            cursor.set_position(2);
+
+            let mut buffer = [0; 3];
+            assert_eq!(cursor.read_back(&mut buffer).ok(), Some(2));
+            assert_eq!(buffer, [1, 2, 0]);
+            assert_eq!(cursor.position(), 0);
+        }
+
+        #[test]
+        fn count_bytes() {
+            let data: [u8; 5] = [1, 2, 3, 4, 5];
+            let mut cursor = Cursor::new(data.as_slice());
+            cursor.set_position(3);
+
+            assert_eq!(cursor.read_back_count_bytes().ok(), Some(3));
+            assert_eq!(cursor.position(), 0);
+        }
+    }
+
+    mod vec {
+        use super::*;
+
+        #[test]
+        fn reversed_output_and_position() {
+            let mut cursor = Cursor::new(b"first\nsecond".to_vec());
+            cursor.seek(SeekFrom::End(0)).unwrap();
+
+            let bytes = cursor
+                .read_back_bytes()
+                .map(|b| b.unwrap())
+                .collect::<Vec<u8>>();
+            assert_eq!(bytes, b"dnoces\ntsrif");
+
+            let mut cursor = Cursor::new(b"first\nsecond".to_vec());
+            cursor.set_position(9);
+            let mut line = String::new();
+            cursor.read_back_line(&mut line).unwrap();
+            assert_eq!(line, "\nsec");
+            assert_eq!(cursor.position(), 5);
+        }
+
+        #[test]
+        fn truncated_between_reads() {
+            let mut cursor = Cursor::new(b"first\nsecond\nthird".to_vec());
+            cursor.seek(SeekFrom::End(0)).unwrap();
+
+            let mut buffer = [0; 5];
+            cursor.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"third");
+
+            // cut off everything from "second" on
+            cursor.get_mut().truncate(6);
+
+            let mut buffer = [0; 3];
+            assert_eq!(cursor.read_back(&mut buffer).ok(), Some(3));
+            assert_eq!(&buffer, b"st\n");
+            assert_eq!(cursor.position(), 3);
+        }
+
+        #[test]
+        fn grown_between_reads() {
+            let mut cursor = Cursor::new(b"abc".to_vec());
+            cursor.set_position(3);
+
+            cursor.read_back_consume(1);
+            cursor.get_mut().extend_from_slice(b"def");
+
+            // the appended bytes are behind the position
+            assert_eq!(cursor.read_back_fill_buf().ok(), Some(b"ab".as_slice()));
+        }
+
+        #[test]
+        fn cleared() {
+            let mut cursor = Cursor::new(b"abc".to_vec());
+            cursor.set_position(3);
+            cursor.get_mut().clear();
+
+            let mut buffer = [0; 3];
+            assert_eq!(cursor.read_back(&mut buffer).ok(), Some(0));
+            assert_eq!(cursor.position(), 0);
+        }
+    }
+
+    mod other_buffers {
+        use super::*;
+
+        #[test]
+        fn array() {
+            let mut cursor = Cursor::new([1, 2, 3, 4]);
+            cursor.set_position(4);
+
+            let mut buffer = [0; 2];
+            cursor.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(buffer, [3, 4]);
+            assert_eq!(cursor.position(), 2);
+        }
+
+        #[test]
+        fn boxed_slice_and_string() {
+            let mut cursor = Cursor::new(b"abc".to_vec().into_boxed_slice());
+            cursor.set_position(3);
+            assert_eq!(cursor.read_back_count_bytes().ok(), Some(3));
+            assert_eq!(cursor.position(), 0);
+
+            let mut cursor = Cursor::new(String::from("first\nsecond"));
+            cursor.seek(SeekFrom::End(0)).unwrap();
+            let lines = cursor
+                .read_back_lines()
+                .map(|line| line.unwrap())
+                .collect::<Vec<String>>();
+            assert_eq!(lines, ["second", "first"]);
+        }
+    }
+
+    mod buf_read_back {
+        use super::*;
+
+        #[test]
+        fn fill_buf_and_consume() {
+            let data: [u8; 4] = [1, 2, 3, 4];
+            let mut cursor = Cursor::new(data.as_slice());
+            cursor.set_position(3);
+
+            assert_eq!(cursor.read_back_fill_buf().ok(), Some([1, 2, 3].as_slice()));
+            cursor.read_back_consume(2);
+            assert_eq!(cursor.position(), 1);
+            assert_eq!(cursor.read_back_fill_buf().ok(), Some([1].as_slice()));
+        }
+
+        #[test]
+        fn consume_large_amt() {
+            let mut data: [u8; 3] = [1, 2, 3];
+            let mut cursor = Cursor::new(data.as_mut_slice());
+            cursor.set_position(3);
+
+            cursor.read_back_consume(10);
+            assert_eq!(cursor.position(), 0);
+            assert!(cursor.read_back_fill_buf().unwrap().is_empty());
+        }
+
+        #[test]
+        fn read_back_until() {
+            let data = b"first line\nsecond line\nthird line";
+            let mut cursor = Cursor::new(data.as_slice());
+            cursor.set_position(22);
+
+            let mut buffer = Vec::new();
+            assert_eq!(cursor.read_back_until(b'\n', &mut buffer).ok(), Some(12));
+            assert_eq!(&buffer, b"\nsecond line");
+            assert_eq!(cursor.position(), 10);
+        }
+
+        mod read_back_range {
+            use super::*;
+
+            #[test]
+            fn middle_window() {
+                let data = b"0123456789";
+                let cursor = Cursor::new(data.as_slice());
+
+                let mut range = cursor.read_back_range(3, 7).unwrap();
+                let mut buffer = [0; 10];
+                assert_eq!(range.read_back(&mut buffer).ok(), Some(4));
+                assert_eq!(&buffer[..4], b"3456");
+                assert_eq!(range.read_back(&mut buffer).ok(), Some(0));
+
+                // nothing in front of the range has been read back
+                assert_eq!(range.get_ref().position(), 3);
+            }
+
+            #[test]
+            fn lines_of_window() {
+                let data = b"skipped\nfirst\nsecond\nskipped";
+                let cursor = Cursor::new(data.as_slice());
+
+                let lines = cursor
+                    .read_back_range(8, 21)
+                    .unwrap()
+                    .read_back_lines()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+                assert_eq!(lines, ["second", "first"]);
+            }
+
+            #[test]
+            fn empty_and_whole_range() {
+                let data = b"data";
+
+                let mut buffer = Vec::new();
+                let mut range = Cursor::new(data.as_slice()).read_back_range(2, 2).unwrap();
+                assert_eq!(range.read_back_to_end(&mut buffer).ok(), Some(0));
+
+                let mut range = Cursor::new(data.as_slice()).read_back_range(0, 4).unwrap();
+                assert_eq!(range.read_back_to_end(&mut buffer).ok(), Some(4));
+                assert_eq!(&buffer, b"data");
+            }
+
+            #[test]
+            fn invalid_ranges() {
+                let data = b"data";
+
+                let err = Cursor::new(data.as_slice())
+                    .read_back_range(3, 2)
+                    .unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+                let err = Cursor::new(data.as_slice())
+                    .read_back_range(0, 5)
+                    .unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+            }
+        }
+
+        mod read_back_lines_indexed {
+            use super::*;
+
+            #[test]
+            fn last_line_is_total_minus_one() {
+                let data = b"a\n\nc\r\nd";
+                let total = std::io::BufRead::lines(data.as_slice()).count();
+                let mut cursor = Cursor::new(data.as_slice());
+                cursor.seek(SeekFrom::End(0)).unwrap();
+
+                let lines = cursor
+                    .read_back_lines_indexed()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<(usize, String)>>();
+
+                assert_eq!(lines[0], (total - 1, "d".to_string()));
+                assert_eq!(
+                    lines,
+                    [
+                        (3, "d".to_string()),
+                        (2, "c".to_string()),
+                        (1, String::new()),
+                        (0, "a".to_string())
+                    ]
+                );
+            }
+
+            #[test]
+            fn from_the_middle() {
+                let data = b"first\nsecond\nthird\n";
+                let mut cursor = Cursor::new(data.as_slice());
+                cursor.set_position(13);
+
+                let mut lines = cursor.read_back_lines_indexed();
+                assert_eq!(lines.next().unwrap().unwrap(), (1, "second".to_string()));
+                assert_eq!(lines.next().unwrap().unwrap(), (0, "first".to_string()));
+                assert!(lines.next().is_none());
+            }
+
+            #[test]
+            fn empty() {
+                let mut lines = Cursor::new(b"".as_slice()).read_back_lines_indexed();
+                assert!(lines.next().is_none());
+            }
+        }
+    }
+}
@@ -1,32 +1,114 @@
 use std::{
     fs::File,
-    io::{Read, Seek, SeekFrom},
+    io::{ErrorKind, IoSliceMut, Read, Seek, SeekFrom},
 };
 
+use crate::read_back::{footer_too_short_error, seek_read_back_vectored, unsupported_source_error};
 use crate::ReadBack;
 
+/// Reading back starts at the current position of the file, so seek to its end with `SeekFrom::End(0)` first
+/// (or use [`BufReadBacker`], which does that on construction). Each read seeks in front of the bytes to read,
+/// reads them and seeks back in front of them again, so offsets are never limited to `usize`.
+///
+/// Files which can't seek, like pipes, return an [`ErrorKind::Unsupported`] error.
+///
+/// [`BufReadBacker`]: crate::BufReadBacker
 impl ReadBack for &File {
     fn read_back(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let curr_pos = self.stream_position()?;
+        let curr_pos = read_back_position(self)?;
 
         let buf_len = buf.len() as u64;
         let max_amount_read = std::cmp::min(curr_pos, buf_len);
+        let start_pos = curr_pos - max_amount_read;
 
-        self.seek(SeekFrom::Current(-(max_amount_read as i64)))?;
-        let (_left, right) = buf.split_at_mut((buf_len - max_amount_read) as usize);
-        match self.read(right) {
-            Ok(n) => {
-                let offset = std::cmp::min(max_amount_read, n as u64) as i64;
-                self.seek(std::io::SeekFrom::Current(-offset))?;
-                Ok(n)
+        self.seek(SeekFrom::Start(start_pos))?;
+        let (mut left, _right) = buf.split_at_mut(max_amount_read as usize);
+
+        // A single `read` is allowed to return less bytes than requested (for example around holes of
+        // sparse files), but the bytes right in front of the cursor have to be read back, so we keep
+        // reading until all of them are there.
+        while !left.is_empty() {
+            match self.read(left) {
+                Ok(0) => {
+                    self.seek(SeekFrom::Start(curr_pos))?;
+                    return Err(std::io::Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "file ended before the read back position",
+                    ));
+                }
+                Ok(n) => left = &mut left[n..],
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.seek(SeekFrom::Start(curr_pos))?;
+                    return Err(e);
+                }
+            }
+        }
+
+        self.seek(SeekFrom::Start(start_pos))?;
+        Ok(max_amount_read as usize)
+    }
+
+    fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
+        let curr_pos = read_back_position(self)?;
+
+        seek_read_back_vectored(self, curr_pos, bufs)
+    }
+
+    fn is_read_back_vectored(&self) -> bool {
+        true
+    }
+
+    fn read_back_count_bytes(&mut self) -> std::io::Result<u64> {
+        let curr_pos = read_back_position(self)?;
+
+        self.seek(SeekFrom::Start(0))?;
+        Ok(curr_pos)
+    }
+
+    fn read_back_skip_footer(&mut self, n: usize) -> std::io::Result<()> {
+        let curr_pos = read_back_position(self)?;
+
+        match curr_pos.checked_sub(n as u64) {
+            Some(new_pos) => {
+                self.seek(SeekFrom::Start(new_pos))?;
+                Ok(())
+            }
+            None => {
+                self.seek(SeekFrom::Start(0))?;
+                Err(footer_too_short_error())
             }
-            Err(err) => Err(err),
         }
     }
 }
 
+/// Returns the position of `file`, which is where reading back starts, or the error for unsupported sources if the
+/// file can't seek.
+fn read_back_position(mut file: &File) -> std::io::Result<u64> {
+    file.stream_position().map_err(|err| match err.kind() {
+        ErrorKind::NotSeekable => unsupported_source_error(),
+        _ => err,
+    })
+}
+
 impl ReadBack for File {
     fn read_back(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         (&*self).read_back(buf)
     }
+
+    fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
+        (&*self).read_back_vectored(bufs)
+    }
+
+    fn is_read_back_vectored(&self) -> bool {
+        true
+    }
+
+    fn read_back_count_bytes(&mut self) -> std::io::Result<u64> {
+        (&*self).read_back_count_bytes()
+    }
+
+    fn read_back_skip_footer(&mut self, n: usize) -> std::io::Result<()> {
+        (&*self).read_back_skip_footer(n)
+    }
 }
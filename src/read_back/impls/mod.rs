@@ -1,3 +1,5 @@
+mod cursor;
 mod empty;
 mod file;
+mod mut_ref;
 mod u8_slice;
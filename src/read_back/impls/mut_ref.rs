@@ -0,0 +1,114 @@
+use std::io::{self, IoSliceMut};
+
+use crate::{BufReadBack, ReadBack, RevBorrowedCursor, RevUntilStop};
+
+/// Forwards to the implementation of `R`, so a reader can be handed to adapters without moving it, see
+/// [`ReadBack::read_back_by_ref`].
+impl<R: ReadBack + ?Sized> ReadBack for &mut R {
+    #[inline]
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (**self).read_back(buf)
+    }
+
+    #[inline]
+    fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        (**self).read_back_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_back_vectored(&self) -> bool {
+        (**self).is_read_back_vectored()
+    }
+
+    #[inline]
+    fn read_back_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_back_to_end(buf)
+    }
+
+    #[inline]
+    fn read_back_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        (**self).read_back_to_string(buf)
+    }
+
+    #[inline]
+    fn read_back_to_string_trim_bom(&mut self, buf: &mut String) -> io::Result<usize> {
+        (**self).read_back_to_string_trim_bom(buf)
+    }
+
+    #[inline]
+    fn read_back_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        (**self).read_back_exact(buf)
+    }
+
+    #[inline]
+    fn read_back_buf(&mut self, cursor: RevBorrowedCursor<'_>) -> io::Result<()> {
+        (**self).read_back_buf(cursor)
+    }
+
+    #[inline]
+    fn read_back_buf_exact(&mut self, cursor: RevBorrowedCursor<'_>) -> io::Result<()> {
+        (**self).read_back_buf_exact(cursor)
+    }
+
+    #[inline]
+    fn read_back_count_bytes(&mut self) -> io::Result<u64> {
+        (**self).read_back_count_bytes()
+    }
+
+    #[inline]
+    fn read_back_skip_footer(&mut self, n: usize) -> io::Result<()> {
+        (**self).read_back_skip_footer(n)
+    }
+}
+
+impl<B: BufReadBack + ?Sized> BufReadBack for &mut B {
+    #[inline]
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        (**self).read_back_fill_buf()
+    }
+
+    #[inline]
+    fn read_back_consume(&mut self, amt: usize) {
+        (**self).read_back_consume(amt)
+    }
+
+    #[inline]
+    fn read_back_has_data_left(&mut self) -> io::Result<bool> {
+        (**self).read_back_has_data_left()
+    }
+
+    #[inline]
+    fn read_back_until(&mut self, delim: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_back_until(delim, buf)
+    }
+
+    #[inline]
+    fn read_back_until_any(&mut self, delims: &[u8], buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_back_until_any(delims, buf)
+    }
+
+    #[inline]
+    fn read_back_until_any_limited(
+        &mut self,
+        delims: &[u8],
+        buf: &mut Vec<u8>,
+        max: usize,
+    ) -> io::Result<RevUntilStop> {
+        (**self).read_back_until_any_limited(delims, buf, max)
+    }
+
+    #[inline]
+    fn read_back_skip_until(&mut self, delim: u8) -> io::Result<usize> {
+        (**self).read_back_skip_until(delim)
+    }
+
+    #[inline]
+    fn read_back_line(&mut self, dest: &mut String) -> io::Result<usize> {
+        (**self).read_back_line(dest)
+    }
+
+    #[inline]
+    fn read_back_line_lossy(&mut self, dest: &mut String) -> io::Result<usize> {
+        (**self).read_back_line_lossy(dest)
+    }
+}
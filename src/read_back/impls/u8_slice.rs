@@ -1,8 +1,10 @@
 use std::cmp;
 use std::io::IoSliceMut;
 
+use crate::read_back::{footer_too_short_error, read_back_bytes_to_string};
 use crate::BufReadBack;
 use crate::ReadBack;
+use crate::RevBorrowedCursor;
 
 /// As for the [`Read`] implementation of `&[u8]`, bytes get copied from the slice.
 ///
@@ -27,38 +29,47 @@ impl ReadBack for &[u8] {
 
     fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
         let mut amount_read = 0;
-        for buf in bufs {
-            amount_read += self.read_back(buf)?;
+        for buf in bufs.iter_mut().rev() {
             if self.is_empty() {
                 break;
             }
+            amount_read += self.read_back(buf)?;
         }
 
         Ok(amount_read)
     }
 
+    fn is_read_back_vectored(&self) -> bool {
+        true
+    }
+
     fn read_back_to_end(&mut self, buf: &mut Vec<u8>) -> std::io::Result<usize> {
         let len = self.len();
         buf.try_reserve(len)
             .map_err(|_| std::io::ErrorKind::OutOfMemory)?;
 
-        let mut new_vec = self.to_vec();
-        new_vec.extend_from_slice(buf);
-        *buf = new_vec;
-
+        buf.extend_from_slice(self);
         *self = &[];
 
         Ok(len)
     }
 
     fn read_back_to_string(&mut self, buf: &mut String) -> std::io::Result<usize> {
-        let mut self_string = String::from_utf8(self.to_vec())
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        let len = self.len();
+        buf.push_str(&read_back_bytes_to_string(self.to_vec())?);
+        *self = &[];
+
+        Ok(len)
+    }
+
+    fn read_back_buf(&mut self, mut cursor: RevBorrowedCursor<'_>) -> std::io::Result<()> {
+        let amount = cmp::min(cursor.capacity(), self.len());
+        let (left, right) = self.split_at(self.len() - amount);
+        cursor.append(right);
 
-        self_string.push_str(&buf);
-        *buf = self_string;
+        *self = left;
 
-        Ok(self.len())
+        Ok(())
     }
 
     fn read_back_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
@@ -83,6 +94,23 @@ impl ReadBack for &[u8] {
 
         Ok(())
     }
+
+    fn read_back_count_bytes(&mut self) -> std::io::Result<u64> {
+        let len = self.len();
+        *self = &[];
+
+        Ok(len as u64)
+    }
+
+    fn read_back_skip_footer(&mut self, n: usize) -> std::io::Result<()> {
+        if n > self.len() {
+            *self = &[];
+            return Err(footer_too_short_error());
+        }
+
+        *self = &self[..self.len() - n];
+        Ok(())
+    }
 }
 
 impl BufReadBack for &[u8] {
@@ -117,6 +145,7 @@ mod tests {
     mod read_back {
         use super::*;
 
+        #[allow(clippy::module_inception)]
         mod read_back {
             use super::*;
 
@@ -146,6 +175,103 @@ mod tests {
                 assert_eq!(values.as_slice().read_back(&mut buffer).ok(), Some(3));
                 assert_eq!(buffer, [1, 2, 3, 0]);
             }
+
+            #[test]
+            fn consecutive() {
+                let values = [1, 2, 3, 4, 5];
+                let mut reference = values.as_slice();
+                let mut buffer = [0, 0];
+
+                assert_eq!(reference.read_back(&mut buffer).ok(), Some(2));
+                assert_eq!(buffer, [4, 5]);
+                assert_eq!(reference, [1, 2, 3]);
+
+                assert_eq!(reference.read_back(&mut buffer).ok(), Some(2));
+                assert_eq!(buffer, [2, 3]);
+                assert_eq!(reference, [1]);
+
+                assert_eq!(reference.read_back(&mut buffer).ok(), Some(1));
+                assert_eq!(buffer[0], 1);
+                assert!(reference.is_empty());
+
+                assert_eq!(reference.read_back(&mut buffer).ok(), Some(0));
+            }
+        }
+
+        mod read_back_vectored {
+            use super::*;
+            use crate::RevCursor;
+
+            #[test]
+            fn same_as_read_back_to_end() {
+                let data = b"first|second|third";
+                let mut reference = data.as_slice();
+                let (mut a, mut b, mut c) = ([0; 8], [0; 7], [0; 6]);
+
+                let amount = reference
+                    .read_back_vectored(&mut [
+                        IoSliceMut::new(&mut a),
+                        IoSliceMut::new(&mut b),
+                        IoSliceMut::new(&mut c),
+                    ])
+                    .unwrap();
+                assert_eq!(amount, data.len());
+                assert!(reference.is_empty());
+
+                let mut content = Vec::new();
+                data.as_slice().read_back_to_end(&mut content).unwrap();
+                assert_eq!([&a[..5], &b[..], &c[..]].concat(), content);
+                assert_eq!(&a[5..], [0; 3]);
+            }
+
+            #[test]
+            fn only_last_slice() {
+                let mut reference = b"data".as_slice();
+                let (mut a, mut b, mut c) = ([0; 2], [0; 2], [0; 6]);
+
+                let amount = reference
+                    .read_back_vectored(&mut [
+                        IoSliceMut::new(&mut a),
+                        IoSliceMut::new(&mut b),
+                        IoSliceMut::new(&mut c),
+                    ])
+                    .unwrap();
+                assert_eq!(amount, 4);
+                assert_eq!((a, b), ([0; 2], [0; 2]));
+                assert_eq!(&c, b"data\0\0");
+            }
+
+            #[test]
+            fn consecutive() {
+                let mut reference = b"0123456789".as_slice();
+                let (mut a, mut b) = ([0; 2], [0; 3]);
+
+                let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+                assert_eq!(reference.read_back_vectored(&mut bufs).ok(), Some(5));
+                assert_eq!(reference.read_back_vectored(&mut bufs).ok(), Some(5));
+                assert_eq!(reference.read_back_vectored(&mut bufs).ok(), Some(0));
+
+                assert_eq!((&a, &b), (b"01", b"234"));
+            }
+
+            #[test]
+            fn default_fills_last_buffer() {
+                let mut cursor = RevCursor::new(b"0123456789");
+                let (mut a, mut b, mut empty) = ([0; 2], [0; 3], [0; 0]);
+
+                let amount = cursor
+                    .read_back_vectored(&mut [
+                        IoSliceMut::new(&mut a),
+                        IoSliceMut::new(&mut b),
+                        IoSliceMut::new(&mut empty),
+                    ])
+                    .unwrap();
+                assert_eq!(amount, 3);
+                assert_eq!((&a, &b), (&[0; 2], b"789"));
+
+                assert!(!cursor.is_read_back_vectored());
+                assert!(b"".as_slice().is_read_back_vectored());
+            }
         }
 
         mod read_back_to_end {
@@ -183,13 +309,102 @@ mod tests {
                     values.as_slice().read_back_to_end(&mut buffer).ok(),
                     Some(3)
                 );
-                assert_eq!(buffer.as_slice(), &[1, 2, 3, 4]);
+                assert_eq!(buffer.as_slice(), &[4, 1, 2, 3]);
+            }
+
+            #[test]
+            fn limited() {
+                let data = b"0123456789";
+
+                for capacity in 1..=data.len() {
+                    let reader = std::io::Cursor::new(data.as_slice());
+                    let mut reader = crate::BufReadBacker::with_capacity(capacity, reader).unwrap();
+                    let mut buffer = vec![b'>'];
+
+                    assert_eq!(
+                        reader.read_back_to_end_limited(&mut buffer, 4).ok(),
+                        Some(4)
+                    );
+                    assert_eq!(buffer, b">6789", "capacity: {}", capacity);
+
+                    // the rest is still there
+                    buffer.clear();
+                    assert_eq!(reader.read_back_to_end(&mut buffer).ok(), Some(6));
+                    assert_eq!(buffer, b"012345", "capacity: {}", capacity);
+                }
+            }
+
+            #[test]
+            fn limited_bigger_than_source() {
+                let mut reference = b"abc".as_slice();
+                let mut buffer = Vec::new();
+
+                assert_eq!(
+                    reference.read_back_to_end_limited(&mut buffer, 10).ok(),
+                    Some(3)
+                );
+                assert_eq!(buffer, b"abc");
+                assert_eq!(
+                    reference.read_back_to_end_limited(&mut buffer, 0).ok(),
+                    Some(0)
+                );
             }
         }
 
         mod read_back_to_string {
             use super::*;
 
+            #[test]
+            fn trim_bom_at_the_start() {
+                let data = b"\xEF\xBB\xBFfirst\nsecond";
+
+                for capacity in 1..=data.len() {
+                    let reader = std::io::Cursor::new(data.as_slice());
+                    let mut reader = crate::BufReadBacker::with_capacity(capacity, reader).unwrap();
+
+                    let mut buffer = "> ".to_string();
+                    assert_eq!(
+                        reader.read_back_to_string_trim_bom(&mut buffer).ok(),
+                        Some(data.len())
+                    );
+                    assert_eq!(buffer, "> first\nsecond", "capacity: {}", capacity);
+                }
+            }
+
+            #[test]
+            fn trim_bom_without_bom() {
+                let mut buffer = String::new();
+
+                assert_eq!(
+                    b"no bom"
+                        .as_slice()
+                        .read_back_to_string_trim_bom(&mut buffer)
+                        .ok(),
+                    Some(6)
+                );
+                assert_eq!(buffer, "no bom");
+            }
+
+            #[test]
+            fn trim_bom_keeps_bom_in_the_middle() {
+                let data = "first\u{FEFF}second";
+                let mut buffer = String::new();
+
+                assert_eq!(
+                    data.as_bytes()
+                        .read_back_to_string_trim_bom(&mut buffer)
+                        .ok(),
+                    Some(data.len())
+                );
+                assert_eq!(buffer, data);
+
+                // the plain version keeps a BOM at the start
+                buffer.clear();
+                let data = "\u{FEFF}text";
+                data.as_bytes().read_back_to_string(&mut buffer).unwrap();
+                assert_eq!(buffer, data);
+            }
+
             #[test]
             fn empty_data() {
                 let data = b"";
@@ -203,14 +418,104 @@ mod tests {
 
             #[test]
             fn general() {
-                let data = b"I use ";
+                let data = b"Arch btw.";
+                let mut reference = data.as_slice();
 
-                let mut buffer = "Arch btw.".to_string();
+                let mut buffer = "I use ".to_string();
                 assert_eq!(
-                    data.as_slice().read_back_to_string(&mut buffer).ok(),
+                    reference.read_back_to_string(&mut buffer).ok(),
                     Some(data.len())
                 );
                 assert_eq!(&buffer, "I use Arch btw.");
+                assert!(reference.is_empty());
+            }
+
+            #[test]
+            fn multibyte_across_chunks() {
+                let text = "héllo→";
+
+                // every chunk size splits one of the code points somewhere
+                for chunk in 1..text.len() {
+                    let reader = std::io::Cursor::new(text.as_bytes());
+                    let mut reader = crate::BufReadBacker::with_capacity(chunk, reader).unwrap();
+
+                    let mut buffer = "> ".to_string();
+                    assert_eq!(
+                        reader.read_back_to_string(&mut buffer).ok(),
+                        Some(text.len())
+                    );
+                    assert_eq!(buffer, "> héllo→", "chunk: {}", chunk);
+                }
+            }
+
+            #[test]
+            fn truncated_at_front() {
+                // the first byte of "é" (0xC3) has been cut off
+                let data = b"\xA9t\xC3\xA9";
+                let mut buffer = String::new();
+
+                let err = data
+                    .as_slice()
+                    .read_back_to_string(&mut buffer)
+                    .unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+                assert!(err.to_string().contains("truncated at the front"));
+                assert!(buffer.is_empty());
+            }
+
+            #[test]
+            fn invalid_byte_in_middle() {
+                let data = b"ab\xFFcd";
+                let mut buffer = String::new();
+
+                let err = data
+                    .as_slice()
+                    .read_back_to_string(&mut buffer)
+                    .unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+                assert!(err.to_string().contains("invalid UTF-8 byte at offset 2"));
+            }
+
+            #[test]
+            fn invalid_keeps_buffer() {
+                let data = b"ab\xFFcd";
+                let mut reference = data.as_slice();
+                let mut buffer = "keep".to_string();
+
+                let err = reference.read_back_to_string(&mut buffer).unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+                assert_eq!(buffer, "keep");
+                assert_eq!(reference, data.as_slice());
+            }
+
+            #[test]
+            fn same_decisions_as_std() {
+                let long_valid = "äöü€𝄞 ascii ".repeat(64);
+                let mut long_invalid = long_valid.clone().into_bytes();
+                long_invalid[500] = 0xFF;
+
+                let inputs: [&[u8]; 10] = [
+                    b"ascii only",
+                    "multi byte: é€𝄞".as_bytes(),
+                    b"\xC3",
+                    b"\xE2\x82",
+                    b"\xED\xA0\x80",
+                    b"\xF4\x90\x80\x80",
+                    b"\xC0\xAF",
+                    b"abc\xFF",
+                    long_valid.as_bytes(),
+                    &long_invalid,
+                ];
+
+                for data in inputs {
+                    let mut buffer = String::new();
+                    assert_eq!(
+                        { data }.read_back_to_string(&mut buffer).is_ok(),
+                        std::str::from_utf8(data).is_ok(),
+                        "data: {:?}",
+                        data
+                    );
+                }
             }
         }
 
@@ -248,7 +553,330 @@ mod tests {
                 let data = [1, 2, 3];
                 let mut buffer = [0; 4];
 
-                assert!(data.as_slice().read_back_exact(&mut buffer).is_err());
+                let err = data.as_slice().read_back_exact(&mut buffer).unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+            }
+
+            /// Hands out at most two bytes at once and is interrupted before every read.
+            struct Trickle<'a> {
+                data: &'a [u8],
+                interrupt: bool,
+            }
+
+            impl ReadBack for Trickle<'_> {
+                fn read_back(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                    self.interrupt = !self.interrupt;
+                    if self.interrupt {
+                        return Err(std::io::ErrorKind::Interrupted.into());
+                    }
+
+                    let max = std::cmp::min(buf.len(), 2);
+                    self.data.read_back(&mut buf[..max])
+                }
+            }
+
+            #[test]
+            fn small_chunks() {
+                let mut reader = Trickle {
+                    data: b"header|trailer",
+                    interrupt: false,
+                };
+                let mut trailer = [0; 7];
+
+                reader.read_back_exact(&mut trailer).unwrap();
+                assert_eq!(&trailer, b"trailer");
+                assert_eq!(reader.data, b"header|");
+            }
+
+            #[test]
+            fn small_chunks_exact_fit() {
+                let mut reader = Trickle {
+                    data: b"trailer",
+                    interrupt: false,
+                };
+                let mut trailer = [0; 7];
+
+                reader.read_back_exact(&mut trailer).unwrap();
+                assert_eq!(&trailer, b"trailer");
+                assert!(reader.data.is_empty());
+            }
+
+            #[test]
+            fn small_chunks_too_short() {
+                let mut reader = Trickle {
+                    data: b"short",
+                    interrupt: false,
+                };
+                let mut trailer = [0; 7];
+
+                let err = reader.read_back_exact(&mut trailer).unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+            }
+
+            mod read_back_buf_exact {
+                use super::*;
+                use crate::RevBorrowedBuf;
+                use std::mem::MaybeUninit;
+
+                #[test]
+                fn uninit_stack_buffer() {
+                    let mut data = b"header|trailer".as_slice();
+                    let mut storage = [MaybeUninit::<u8>::uninit(); 7];
+                    let mut buf = RevBorrowedBuf::uninit(&mut storage);
+
+                    data.read_back_buf_exact(buf.unfilled()).unwrap();
+                    assert_eq!(buf.filled(), b"trailer");
+                    assert_eq!(data, b"header|");
+                }
+
+                #[test]
+                fn small_chunks() {
+                    let mut reader = Trickle {
+                        data: b"header|trailer",
+                        interrupt: false,
+                    };
+                    let mut storage = [MaybeUninit::<u8>::uninit(); 7];
+                    let mut buf = RevBorrowedBuf::uninit(&mut storage);
+
+                    reader.read_back_buf_exact(buf.unfilled()).unwrap();
+                    assert_eq!(buf.filled(), b"trailer");
+                    assert_eq!(reader.data, b"header|");
+                }
+
+                #[test]
+                fn partially_filled_cursor() {
+                    let mut reader = Trickle {
+                        data: b"header|trailer",
+                        interrupt: false,
+                    };
+                    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+                    let mut buf = RevBorrowedBuf::uninit(&mut storage);
+
+                    let mut cursor = buf.unfilled();
+                    cursor.append(b"!!");
+                    reader.read_back_buf_exact(cursor.reborrow()).unwrap();
+                    assert_eq!(cursor.written(), 8);
+
+                    assert_eq!(buf.filled(), b"railer!!");
+                    assert_eq!(reader.data, b"header|t");
+                }
+
+                #[test]
+                fn too_short() {
+                    let mut reader = Trickle {
+                        data: b"short",
+                        interrupt: false,
+                    };
+                    let mut storage = [MaybeUninit::<u8>::uninit(); 7];
+                    let mut buf = RevBorrowedBuf::uninit(&mut storage);
+
+                    let err = reader.read_back_buf_exact(buf.unfilled()).unwrap_err();
+                    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+                    assert_eq!(buf.filled(), b"short");
+                }
+            }
+        }
+
+        mod read_back_drain_to_vec_reversed {
+            use super::ReadBack;
+
+            #[test]
+            fn reversed_order() {
+                let mut data = [1, 2, 3].as_slice();
+
+                assert_eq!(
+                    data.read_back_drain_to_vec_reversed().ok(),
+                    Some(vec![3, 2, 1])
+                );
+                assert!(data.is_empty());
+                assert_eq!(data.read_back_drain_to_vec_reversed().ok(), Some(vec![]));
+            }
+        }
+
+        mod read_back_exact_array {
+            use super::ReadBack;
+
+            #[test]
+            fn exact_length() {
+                let mut data = [1, 2, 3].as_slice();
+
+                assert_eq!(data.read_back_exact_array::<3>().ok(), Some([1, 2, 3]));
+                assert!(data.is_empty());
+                assert_eq!(data.read_back_exact_array::<0>().ok(), Some([]));
+            }
+
+            #[test]
+            fn too_short() {
+                let mut data = [1, 2, 3].as_slice();
+
+                let err = data.read_back_exact_array::<4>().unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+            }
+        }
+
+        mod read_back_footer {
+            use super::ReadBack;
+
+            #[test]
+            fn whole_source() {
+                let mut data = b"RCFOOTER".as_slice();
+
+                assert_eq!(data.read_back_footer::<8>().ok(), Some(*b"RCFOOTER"));
+                assert!(data.is_empty());
+            }
+
+            #[test]
+            fn source_too_short() {
+                let mut data = b"RCFT".as_slice();
+
+                let err = data.read_back_footer::<8>().unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+            }
+        }
+
+        mod read_back_skip_footer {
+            use super::{BufReadBack, ReadBack};
+
+            #[test]
+            fn skip_then_read_records() {
+                let mut data = b"first\nsecond\n".to_vec();
+                data.extend_from_slice(&[0xAB; 16]);
+                let mut reference = data.as_slice();
+
+                reference.read_back_skip_footer(16).unwrap();
+                let records = reference
+                    .read_back_lines()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+                assert_eq!(records, ["second", "first"]);
+            }
+
+            #[test]
+            fn read_footer_then_skip() {
+                let mut data = b"record\x00\x2A\xFF\xFF".as_slice();
+
+                assert_eq!(data.read_back_footer::<2>().ok(), Some([0xFF, 0xFF]));
+                data.read_back_skip_footer(2).unwrap();
+                assert_eq!(data, b"record");
+            }
+
+            #[test]
+            fn source_too_short() {
+                let mut data = b"short".as_slice();
+
+                let err = data.read_back_skip_footer(16).unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+                assert!(data.is_empty());
+            }
+
+            #[test]
+            fn default_implementation() {
+                // `ReadBackTake` doesn't know its position, so it reads the footer back
+                let data = [1, 2, 3, 4, 5];
+                let mut take = data.as_slice().read_back_take(4);
+
+                take.read_back_skip_footer(3).unwrap();
+                assert_eq!(take.limit(), 1);
+                assert_eq!(take.read_back_fill_buf().ok(), Some([2].as_slice()));
+
+                let err = take.read_back_skip_footer(2).unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+            }
+        }
+
+        mod read_back_varint_suffixed_record {
+            use super::ReadBack;
+            use std::io::ErrorKind;
+
+            /// Appends `record` followed by its length as a reversed varint.
+            fn append_record(log: &mut Vec<u8>, record: &[u8]) {
+                log.extend_from_slice(record);
+
+                let mut varint = Vec::new();
+                let mut length = record.len() as u64;
+                loop {
+                    let byte = (length & 0x7F) as u8;
+                    length >>= 7;
+                    if length == 0 {
+                        varint.push(byte);
+                        break;
+                    }
+                    varint.push(byte | 0x80);
+                }
+                log.extend(varint.into_iter().rev());
+            }
+
+            #[test]
+            fn multi_byte_lengths() {
+                let records = [vec![1; 3], vec![2; 200], vec![], vec![3; 20_000]];
+                let mut log = Vec::new();
+                for record in &records {
+                    append_record(&mut log, record);
+                }
+                // 200 needs two bytes and 20_000 three bytes
+                assert_eq!(log.len(), 3 + 1 + 200 + 2 + 1 + 20_000 + 3);
+
+                let mut data = log.as_slice();
+                for record in records.iter().rev() {
+                    assert_eq!(
+                        data.read_back_varint_suffixed_record().unwrap().as_ref(),
+                        Some(record)
+                    );
+                }
+                assert_eq!(data.read_back_varint_suffixed_record().unwrap(), None);
+            }
+
+            #[test]
+            fn truncated_record() {
+                let mut data = b"ab\x03".as_slice();
+
+                let err = data.read_back_varint_suffixed_record().unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+            }
+
+            #[test]
+            fn truncated_varint() {
+                let mut data = [0x85].as_slice();
+
+                let err = data.read_back_varint_suffixed_record().unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+            }
+
+            #[test]
+            fn too_large_varint() {
+                let mut data = [0xFF; 11].as_slice();
+
+                let err = data.read_back_varint_suffixed_record().unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidData);
+            }
+        }
+
+        mod read_back_count_bytes {
+            use super::ReadBack;
+
+            #[test]
+            fn slice() {
+                let data = [1, 2, 3];
+                let mut reference = data.as_slice();
+
+                assert_eq!(reference.read_back_count_bytes().ok(), Some(3));
+                assert!(reference.is_empty());
+                assert_eq!(reference.read_back_count_bytes().ok(), Some(0));
+            }
+
+            #[test]
+            fn drain() {
+                // `ReadBackTake` doesn't know its length, so it has to read everything back
+                let data = [0; 2 * crate::DEFAULT_BUF_SIZE + 1];
+                let mut take = data
+                    .as_slice()
+                    .read_back_take(crate::DEFAULT_BUF_SIZE as u64 + 5);
+
+                assert_eq!(
+                    take.read_back_count_bytes().ok(),
+                    Some(crate::DEFAULT_BUF_SIZE as u64 + 5)
+                );
+                assert_eq!(take.into_inner().len(), crate::DEFAULT_BUF_SIZE - 4);
             }
         }
 
@@ -287,6 +915,88 @@ mod tests {
                     assert_eq!(take.read_back(&mut buffer).ok(), Some(data.len()));
                     assert_eq!(&buffer, &[1, 2, 3, 0]);
                 }
+
+                #[test]
+                fn last_n_bytes() {
+                    let data = b"untouched|tail";
+                    let mut take = data.as_slice().read_back_take(4);
+
+                    let mut buffer = Vec::new();
+                    assert_eq!(take.read_back_to_end(&mut buffer).ok(), Some(4));
+                    assert_eq!(&buffer, b"tail");
+                    assert_eq!(take.limit(), 0);
+
+                    // the bytes in front of the limit are still in the reader
+                    assert_eq!(take.into_inner(), b"untouched|");
+                }
+
+                #[test]
+                fn set_limit() {
+                    let data: [u8; 4] = [1, 2, 3, 4];
+                    let mut buffer: [u8; 4] = [0; 4];
+                    let mut take = data.as_slice().read_back_take(1);
+
+                    assert_eq!(take.read_back(&mut buffer).ok(), Some(1));
+                    assert_eq!(take.read_back(&mut buffer).ok(), Some(0));
+
+                    take.set_limit(2);
+                    assert_eq!(take.read_back(&mut buffer).ok(), Some(2));
+                    assert_eq!(buffer[..2], [2, 3]);
+                    assert_eq!(take.get_ref(), &[1]);
+                }
+            }
+        }
+
+        mod read_back_by_ref {
+            use super::*;
+            use crate::BufReadBacker;
+            use std::io::Cursor;
+
+            #[test]
+            fn take_then_continue() {
+                let data = (0..32u8).collect::<Vec<u8>>();
+                let mut reference = data.as_slice();
+
+                let mut last = Vec::new();
+                reference
+                    .read_back_by_ref()
+                    .read_back_take(10)
+                    .read_back_to_end(&mut last)
+                    .unwrap();
+                assert_eq!(last, &data[22..]);
+
+                let mut rest = Vec::new();
+                reference.read_back_to_end(&mut rest).unwrap();
+                assert_eq!(rest, &data[..22]);
+            }
+
+            #[test]
+            fn keeps_buffered_bytes() {
+                let data = (0..32u8).collect::<Vec<u8>>();
+                let mut reader =
+                    BufReadBacker::with_capacity(8, Cursor::new(data.as_slice())).unwrap();
+
+                let mut last = [0; 10];
+                reader
+                    .read_back_by_ref()
+                    .read_back_take(10)
+                    .read_back_exact(&mut last)
+                    .unwrap();
+                assert_eq!(last, data[22..]);
+
+                let mut rest = Vec::new();
+                reader.read_back_to_end(&mut rest).unwrap();
+                assert_eq!(rest, &data[..22]);
+            }
+
+            #[test]
+            fn buf_read_back() {
+                let mut reference = b"first\nsecond\nthird".as_slice();
+
+                let mut line = String::new();
+                (&mut reference).read_back_line(&mut line).unwrap();
+                assert_eq!(line, "\nthird");
+                assert_eq!(reference, b"first\nsecond");
             }
         }
     }
@@ -294,49 +1004,311 @@ mod tests {
     mod buf_read_back {
         use super::*;
 
+        #[test]
+        fn fill_buf_and_consume() {
+            let values: [u8; 3] = [1, 2, 3];
+            let mut reference: &[u8] = &values;
+
+            assert_eq!(reference.read_back_fill_buf().ok(), Some(values.as_slice()));
+            reference.read_back_consume(1);
+            assert_eq!(reference.read_back_fill_buf().ok(), Some([1, 2].as_slice()));
+            reference.read_back_consume(2);
+            assert_eq!(reference.read_back_fill_buf().ok(), Some([].as_slice()));
+        }
+
+        #[test]
+        fn has_data_left() {
+            let values: [u8; 3] = [1, 2, 3];
+            let mut reference: &[u8] = &values;
+
+            for remaining in (1..=values.len()).rev() {
+                assert_eq!(reference.read_back_has_data_left().ok(), Some(true));
+                // checking doesn't consume anything
+                assert_eq!(reference.len(), remaining);
+                reference.read_back_consume(1);
+            }
+            assert_eq!(reference.read_back_has_data_left().ok(), Some(false));
+        }
+
         #[test]
         fn rev_consume_large_amt() {
             let values: [u8; 3] = [1, 2, 3];
             let mut reference: &[u8] = &values;
 
-            reference.read_back_consume(values.len() + 1);
-            assert!(reference.is_empty());
+            reference.read_back_consume(values.len() + 1);
+            assert!(reference.is_empty());
+        }
+
+        mod read_back_until {
+            use super::*;
+
+            #[test]
+            fn until_end() {
+                let haystack: [u8; 3] = [1, 2, 3];
+                let mut buffer = vec![];
+                let mut reference: &[u8] = &haystack;
+
+                assert_eq!(reference.read_back_until(0, &mut buffer).ok(), Some(3));
+                assert!(reference.is_empty());
+                assert_eq!(&buffer, &[1, 2, 3]);
+            }
+
+            #[test]
+            fn delim_in_between() {
+                let haystack: [u8; 3] = [1, 2, 3];
+                let mut buffer = vec![];
+                let mut reference: &[u8] = &haystack;
+
+                assert_eq!(reference.read_back_until(2, &mut buffer).ok(), Some(2));
+                assert_eq!(reference, &[1]);
+                assert_eq!(&buffer, &[2, 3]);
+            }
+
+            #[test]
+            fn delim_at_the_beginning() {
+                let haystack: [u8; 3] = [1, 2, 3];
+                let mut buffer = vec![];
+                let mut reference: &[u8] = &haystack;
+
+                assert_eq!(reference.read_back_until(3, &mut buffer).ok(), Some(1));
+                assert_eq!(reference, &[1, 2]);
+                assert_eq!(&buffer, &[3]);
+            }
+
+            #[test]
+            fn empty_source() {
+                let mut reference: &[u8] = &[];
+                let mut buffer = vec![];
+
+                assert_eq!(reference.read_back_until(b'\n', &mut buffer).ok(), Some(0));
+                assert!(buffer.is_empty());
+            }
+
+            #[test]
+            fn appends() {
+                let mut reference = b"ab\ncd".as_slice();
+                let mut buffer = b"xy".to_vec();
+
+                assert_eq!(reference.read_back_until(b'\n', &mut buffer).ok(), Some(3));
+                assert_eq!(&buffer, b"xy\ncd");
+
+                assert_eq!(reference.read_back_until(b'\n', &mut buffer).ok(), Some(2));
+                assert_eq!(&buffer, b"xy\ncdab");
+            }
+
+            #[test]
+            fn across_refills() {
+                let data = b"first\nsecond line\n";
+
+                for capacity in 1..data.len() {
+                    let mut reader = crate::BufReadBacker::with_capacity(
+                        capacity,
+                        std::io::Cursor::new(data.as_slice()),
+                    )
+                    .unwrap();
+                    let mut buffer = b"> ".to_vec();
+
+                    assert_eq!(reader.read_back_until(b'\n', &mut buffer).ok(), Some(1));
+                    assert_eq!(reader.read_back_until(b'\n', &mut buffer).ok(), Some(12));
+                    assert_eq!(&buffer, b"> \n\nsecond line", "capacity: {}", capacity);
+
+                    buffer.clear();
+                    assert_eq!(reader.read_back_until(b'\n', &mut buffer).ok(), Some(5));
+                    assert_eq!(&buffer, b"first", "capacity: {}", capacity);
+                    assert_eq!(reader.read_back_until(b'\n', &mut buffer).ok(), Some(0));
+                }
+            }
+        }
+
+        mod read_back_until_any {
+            use super::*;
+            use crate::BufReadBacker;
+            use std::io::Cursor;
+
+            #[test]
+            fn mixed_terminators() {
+                let data = b"one\rtwo\nthree\r\nfour";
+                let mut reader =
+                    BufReadBacker::with_capacity(3, Cursor::new(data.as_slice())).unwrap();
+
+                let mut records = Vec::new();
+                loop {
+                    let mut record = Vec::new();
+                    if reader.read_back_until_any(b"\r\n", &mut record).unwrap() == 0 {
+                        break;
+                    }
+                    records.push(record);
+                }
+
+                // the `\r` of the `\r\n` is a terminator on its own
+                assert_eq!(
+                    records,
+                    [b"\nfour".as_slice(), b"\r", b"\nthree", b"\rtwo", b"one"]
+                );
+            }
+
+            #[test]
+            fn empty_delims() {
+                let mut reference = b"a\nb\rc".as_slice();
+                let mut buffer = vec![b'>'];
+
+                assert_eq!(
+                    reference.read_back_until_any(b"", &mut buffer).ok(),
+                    Some(5)
+                );
+                assert_eq!(buffer, b">a\nb\rc");
+                assert!(reference.is_empty());
+            }
+
+            #[test]
+            fn many_delims() {
+                let mut reference = b"a(b)c[d]e".as_slice();
+                let mut buffer = vec![];
+
+                assert_eq!(
+                    reference.read_back_until_any(b"()[]", &mut buffer).ok(),
+                    Some(2)
+                );
+                assert_eq!(buffer, b"]e");
+                assert_eq!(reference, b"a(b)c[d");
+
+                buffer.clear();
+                assert_eq!(
+                    reference
+                        .read_back_until_any(&[0, 63, 64, 255], &mut buffer)
+                        .ok(),
+                    Some(7)
+                );
+                assert_eq!(buffer, b"a(b)c[d");
+            }
         }
 
-        mod read_back_until {
+        mod read_back_until_any_limited {
             use super::*;
+            use crate::RevUntilStop;
 
             #[test]
-            fn until_end() {
-                let haystack: [u8; 3] = [1, 2, 3];
+            fn delimiter() {
+                let mut reference = b"a,b;cd".as_slice();
                 let mut buffer = vec![];
-                let mut reference: &[u8] = &haystack;
 
-                assert_eq!(reference.read_back_until(0, &mut buffer).ok(), Some(3));
-                assert!(reference.is_empty());
-                assert_eq!(&buffer, &[1, 2, 3]);
+                assert_eq!(
+                    reference
+                        .read_back_until_any_limited(b",;", &mut buffer, 10)
+                        .ok(),
+                    Some(RevUntilStop::Delimiter(b';'))
+                );
+                assert_eq!(&buffer, b";cd");
+                assert_eq!(reference, b"a,b");
+
+                buffer.clear();
+                assert_eq!(
+                    reference
+                        .read_back_until_any_limited(b",;", &mut buffer, 10)
+                        .ok(),
+                    Some(RevUntilStop::Delimiter(b','))
+                );
+                assert_eq!(&buffer, b",b");
+                assert_eq!(reference, b"a");
             }
 
             #[test]
-            fn delim_in_between() {
-                let haystack: [u8; 3] = [1, 2, 3];
+            fn limit() {
+                let mut reference = b"abcdef".as_slice();
                 let mut buffer = vec![];
-                let mut reference: &[u8] = &haystack;
 
-                assert_eq!(reference.read_back_until(2, &mut buffer).ok(), Some(2));
-                assert_eq!(reference, &[1]);
-                assert_eq!(&buffer, &[2, 3]);
+                assert_eq!(
+                    reference
+                        .read_back_until_any_limited(b",", &mut buffer, 4)
+                        .ok(),
+                    Some(RevUntilStop::Limit)
+                );
+                assert_eq!(&buffer, b"cdef");
+                assert_eq!(reference, b"ab");
             }
 
             #[test]
-            fn delim_at_the_beginning() {
-                let haystack: [u8; 3] = [1, 2, 3];
+            fn delimiter_at_limit() {
+                let mut reference = b"ab,cd".as_slice();
                 let mut buffer = vec![];
-                let mut reference: &[u8] = &haystack;
 
-                assert_eq!(reference.read_back_until(3, &mut buffer).ok(), Some(1));
-                assert_eq!(reference, &[1, 2]);
-                assert_eq!(&buffer, &[3]);
+                assert_eq!(
+                    reference
+                        .read_back_until_any_limited(b",", &mut buffer, 3)
+                        .ok(),
+                    Some(RevUntilStop::Delimiter(b','))
+                );
+                assert_eq!(&buffer, b",cd");
+            }
+
+            #[test]
+            fn zero_limit() {
+                let mut reference = b"ab".as_slice();
+                let mut buffer = vec![];
+
+                assert_eq!(
+                    reference
+                        .read_back_until_any_limited(b",", &mut buffer, 0)
+                        .ok(),
+                    Some(RevUntilStop::Limit)
+                );
+                assert!(buffer.is_empty());
+                assert_eq!(reference, b"ab");
+            }
+
+            #[test]
+            fn front() {
+                let mut reference = b"abc".as_slice();
+                let mut buffer = b"!".to_vec();
+
+                assert_eq!(
+                    reference
+                        .read_back_until_any_limited(b",;:-", &mut buffer, 10)
+                        .ok(),
+                    Some(RevUntilStop::Front)
+                );
+                assert_eq!(&buffer, b"!abc");
+                assert!(reference.is_empty());
+            }
+
+            #[test]
+            fn across_refills() {
+                let data = b"first-second:third";
+
+                for capacity in 1..data.len() {
+                    let mut reader = crate::BufReadBacker::with_capacity(
+                        capacity,
+                        std::io::Cursor::new(data.as_slice()),
+                    )
+                    .unwrap();
+
+                    let mut buffer = vec![];
+                    assert_eq!(
+                        reader
+                            .read_back_until_any_limited(b"-:", &mut buffer, 100)
+                            .ok(),
+                        Some(RevUntilStop::Delimiter(b':'))
+                    );
+                    assert_eq!(&buffer, b":third", "capacity: {}", capacity);
+
+                    buffer.clear();
+                    assert_eq!(
+                        reader
+                            .read_back_until_any_limited(b"-:", &mut buffer, 4)
+                            .ok(),
+                        Some(RevUntilStop::Limit)
+                    );
+                    assert_eq!(&buffer, b"cond", "capacity: {}", capacity);
+
+                    buffer.clear();
+                    assert_eq!(
+                        reader
+                            .read_back_until_any_limited(b"", &mut buffer, 100)
+                            .ok(),
+                        Some(RevUntilStop::Front)
+                    );
+                    assert_eq!(&buffer, b"first-se", "capacity: {}", capacity);
+                }
             }
         }
 
@@ -369,6 +1341,27 @@ mod tests {
                 assert_eq!(reference.read_back_skip_until(3).ok(), Some(1));
                 assert_eq!(reference, &[1, 2]);
             }
+
+            #[test]
+            fn across_refills() {
+                let data = b"header\0payload\0checksum";
+
+                for capacity in 1..data.len() {
+                    let mut reader = crate::BufReadBacker::with_capacity(
+                        capacity,
+                        std::io::Cursor::new(data.as_slice()),
+                    )
+                    .unwrap();
+
+                    assert_eq!(reader.read_back_skip_until(0).ok(), Some(9));
+                    let mut payload = Vec::new();
+                    reader.read_back_until(0, &mut payload).unwrap();
+                    assert_eq!(&payload, b"\0payload", "capacity: {}", capacity);
+
+                    assert_eq!(reader.read_back_skip_until(0).ok(), Some(6));
+                    assert_eq!(reader.read_back_skip_until(0).ok(), Some(0));
+                }
+            }
         }
 
         mod read_back_line {
@@ -403,6 +1396,244 @@ mod tests {
                 assert_eq!(data.as_slice().read_back_line(&mut buffer).ok(), Some(4));
                 assert_eq!(buffer.as_bytes(), data);
             }
+
+            #[test]
+            fn repeated_calls() {
+                let data = b"first\r\nsecond\n\nlast";
+                let mut reference = data.as_slice();
+
+                let mut lines = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    if reference.read_back_line(&mut line).unwrap() == 0 {
+                        break;
+                    }
+                    lines.push(line);
+                }
+                assert_eq!(lines, ["\nlast", "\n", "\r\nsecond", "first"]);
+
+                lines.reverse();
+                assert_eq!(lines.concat().as_bytes(), data);
+            }
+
+            #[test]
+            fn appends() {
+                let mut reference = b"first\nsecond".as_slice();
+                let mut buffer = "> ".to_string();
+
+                assert_eq!(reference.read_back_line(&mut buffer).ok(), Some(7));
+                assert_eq!(reference.read_back_line(&mut buffer).ok(), Some(5));
+                assert_eq!(buffer, "> \nsecondfirst");
+            }
+
+            #[test]
+            fn lossy_replaces_invalid_bytes() {
+                let mut reference = b"first\r\nbro\xc3ken\nlast".as_slice();
+                let mut buffer = String::new();
+
+                assert_eq!(reference.read_back_line_lossy(&mut buffer).ok(), Some(5));
+                assert_eq!(buffer, "\nlast");
+
+                buffer.clear();
+                assert_eq!(reference.read_back_line_lossy(&mut buffer).ok(), Some(9));
+                assert_eq!(buffer, "\r\nbro\u{FFFD}ken");
+
+                buffer.clear();
+                assert_eq!(reference.read_back_line_lossy(&mut buffer).ok(), Some(5));
+                assert_eq!(buffer, "first");
+                assert_eq!(reference.read_back_line_lossy(&mut buffer).ok(), Some(0));
+            }
+
+            #[test]
+            fn lossy_same_as_read_back_line_for_valid_utf8() {
+                let data = "first\r\nzweite Zeile mit Umlauten: äöü\n\nlast\n";
+                let mut lossy = data.as_bytes();
+                let mut strict = data.as_bytes();
+
+                loop {
+                    let (mut lossy_line, mut strict_line) = (String::new(), String::new());
+                    let amount = lossy.read_back_line_lossy(&mut lossy_line).unwrap();
+
+                    assert_eq!(strict.read_back_line(&mut strict_line).ok(), Some(amount));
+                    assert_eq!(lossy_line, strict_line);
+                    if amount == 0 {
+                        break;
+                    }
+                }
+            }
+
+            #[test]
+            fn invalid_utf8_keeps_buffer() {
+                let mut reference = b"first\n\xFFsecond".as_slice();
+                let mut buffer = "> ".to_string();
+
+                let err = reference.read_back_line(&mut buffer).unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+                assert_eq!(buffer, "> ");
+                assert_eq!(reference, b"first");
+            }
+        }
+
+        mod read_back_last_records {
+            use super::*;
+
+            #[test]
+            fn more_records_than_k() {
+                let data = b"one\ntwo\n\nfour\nfive";
+                let mut reference: &[u8] = data;
+
+                let records = reference.read_back_last_records(3, b'\n').unwrap();
+                assert_eq!(records, [b"".to_vec(), b"four".to_vec(), b"five".to_vec()]);
+                assert_eq!(reference, b"one\ntwo");
+            }
+
+            #[test]
+            fn fewer_records_than_k() {
+                let data = b"one\ntwo\n";
+                let mut reference: &[u8] = data;
+
+                let records = reference.read_back_last_records(5, b'\n').unwrap();
+                assert_eq!(records, [b"one".to_vec(), b"two".to_vec()]);
+                assert!(reference.is_empty());
+            }
+
+            #[test]
+            fn huge_k() {
+                for k in [1 << 40, usize::MAX] {
+                    let mut reference = b"a\nb".as_slice();
+
+                    let records = reference.read_back_last_records(k, b'\n').unwrap();
+                    assert_eq!(records, [b"a".to_vec(), b"b".to_vec()]);
+                }
+            }
+
+            #[test]
+            fn k_is_zero() {
+                let data = b"one\ntwo";
+                let mut reference: &[u8] = data;
+
+                assert!(reference
+                    .read_back_last_records(0, b'\n')
+                    .unwrap()
+                    .is_empty());
+                assert_eq!(reference, data);
+            }
+
+            #[test]
+            fn empty_data() {
+                let mut reference: &[u8] = &[];
+
+                assert!(reference
+                    .read_back_last_records(2, b'\n')
+                    .unwrap()
+                    .is_empty());
+            }
+        }
+
+        mod read_back_find_with_context {
+            use super::*;
+
+            const LOG: &[u8] = b"one\ntwo\nthree\nmatch\nfour\nfive\n";
+
+            fn strings(lines: &[&str]) -> Option<Vec<String>> {
+                Some(lines.iter().map(|line| line.to_string()).collect())
+            }
+
+            #[test]
+            fn match_with_two_context_lines() {
+                let mut reader = LOG;
+
+                let context = reader
+                    .read_back_find_with_context(|line| line == "match", 2, 0)
+                    .unwrap();
+                assert_eq!(context, strings(&["two", "three", "match"]));
+                assert_eq!(reader, b"one");
+            }
+
+            #[test]
+            fn lines_after_the_match() {
+                let context = { LOG }
+                    .read_back_find_with_context(|line| line == "match", 0, 1)
+                    .unwrap();
+                assert_eq!(context, strings(&["match", "four"]));
+            }
+
+            #[test]
+            fn huge_context() {
+                let context = { LOG }
+                    .read_back_find_with_context(|line| line == "match", usize::MAX, usize::MAX)
+                    .unwrap();
+                assert_eq!(
+                    context,
+                    strings(&["one", "two", "three", "match", "four", "five"])
+                );
+            }
+
+            #[test]
+            fn start_reached_before_context() {
+                let context = { LOG }
+                    .read_back_find_with_context(|line| line == "two", 5, 10)
+                    .unwrap();
+                assert_eq!(
+                    context,
+                    strings(&["one", "two", "three", "match", "four", "five"])
+                );
+            }
+
+            #[test]
+            fn no_match() {
+                let mut reader = LOG;
+
+                assert_eq!(
+                    reader
+                        .read_back_find_with_context(|line| line == "six", 1, 1)
+                        .unwrap(),
+                    None
+                );
+                assert!(reader.is_empty());
+            }
+        }
+
+        mod read_back_index_records {
+            use super::*;
+            use std::io::BufRead;
+
+            /// Computes the index by going forward through `data`.
+            fn forward_index(data: &[u8], delim: u8) -> Vec<(u64, u64)> {
+                let mut index = Vec::new();
+                let mut start = 0;
+
+                for record in BufRead::split(data, delim).map(|record| record.unwrap()) {
+                    let end = start + record.len() as u64;
+                    index.push((start, end));
+                    start = end + 1;
+                }
+
+                index.reverse();
+                index
+            }
+
+            #[test]
+            fn same_as_forward_index() {
+                let inputs: [&[u8]; 7] = [
+                    b"",
+                    b"\n",
+                    b"a",
+                    b"a\n",
+                    b"a\n\n",
+                    b"\nab\ncd",
+                    b"ab\n\ncd\n",
+                ];
+
+                for data in inputs {
+                    assert_eq!(
+                        { data }.read_back_index_records(b'\n').unwrap(),
+                        forward_index(data, b'\n'),
+                        "data: {:?}",
+                        data
+                    );
+                }
+            }
         }
 
         mod read_back_split {
@@ -458,10 +1689,107 @@ mod tests {
 
                 assert!(split.next().is_none());
             }
+
+            #[test]
+            fn trailing_delim() {
+                let data = b"first\0second\0";
+
+                let segments = data
+                    .as_slice()
+                    .read_back_split(0)
+                    .map(|segment| segment.unwrap())
+                    .collect::<Vec<Vec<u8>>>();
+                assert_eq!(segments, [b"second".to_vec(), b"first".to_vec()]);
+            }
+
+            #[test]
+            fn same_as_split() {
+                use std::io::BufRead;
+
+                for data in [
+                    b"first\0second".as_slice(),
+                    b"first\0second\0",
+                    b"\0first\0\0",
+                    b"\0",
+                    b"",
+                ] {
+                    let mut expected = BufRead::split(data, 0)
+                        .map(|segment| segment.unwrap())
+                        .collect::<Vec<Vec<u8>>>();
+                    expected.reverse();
+
+                    let segments = data
+                        .read_back_split(0)
+                        .map(|segment| segment.unwrap())
+                        .collect::<Vec<Vec<u8>>>();
+                    assert_eq!(segments, expected, "data: {:?}", data);
+                }
+            }
         }
 
         mod read_back_lines {
             use super::*;
+            use crate::{BufReadBacker, LineEnding};
+            use std::io::Cursor;
+
+            fn lines_with(data: &[u8], capacity: usize, ending: LineEnding) -> Vec<String> {
+                BufReadBacker::with_capacity(capacity, Cursor::new(data))
+                    .unwrap()
+                    .read_back_lines()
+                    .line_ending(ending)
+                    .map(|line| line.unwrap())
+                    .collect()
+            }
+
+            #[test]
+            fn line_ending_any() {
+                let data = b"a\r\nb\rc\n\rd\r\n";
+
+                for capacity in 1..=data.len() {
+                    assert_eq!(
+                        lines_with(data, capacity, LineEnding::Any),
+                        ["d", "", "c", "b", "a"],
+                        "capacity: {}",
+                        capacity
+                    );
+                }
+            }
+
+            #[test]
+            fn line_ending_lf() {
+                let data = b"a\r\nb\rc\n\rd\r\n";
+
+                for capacity in 1..=data.len() {
+                    assert_eq!(
+                        lines_with(data, capacity, LineEnding::Lf),
+                        ["\rd\r", "b\rc", "a\r"],
+                        "capacity: {}",
+                        capacity
+                    );
+                }
+            }
+
+            #[test]
+            fn line_ending_crlf_is_the_default() {
+                let data = b"a\r\nb\rc\n\rd\r\n";
+
+                for capacity in 1..=data.len() {
+                    let default = BufReadBacker::with_capacity(capacity, Cursor::new(data))
+                        .unwrap()
+                        .read_back_lines()
+                        .map(|line| line.unwrap())
+                        .collect::<Vec<String>>();
+
+                    assert_eq!(default, lines_with(data, capacity, LineEnding::CrLf));
+                    assert_eq!(default, ["\rd", "b\rc", "a"]);
+                }
+            }
+
+            #[test]
+            fn line_ending_lone_cr_at_the_end() {
+                assert_eq!(lines_with(b"a\rb\r", 4, LineEnding::Any), ["b", "a"]);
+                assert_eq!(lines_with(b"a\rb\r", 4, LineEnding::CrLf), ["a\rb\r"]);
+            }
 
             #[test]
             fn no_new_lines() {
@@ -496,6 +1824,136 @@ mod tests {
 
                 assert!(lines.next().is_none());
             }
+
+            #[test]
+            fn empty_lines() {
+                let data = b"a\n\n\nb";
+
+                let lines = data
+                    .as_slice()
+                    .read_back_lines()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+
+                assert_eq!(lines, ["b", "", "", "a"]);
+            }
+
+            #[test]
+            fn trailing_new_line() {
+                for (data, expected) in [
+                    (b"a\nb\n".as_slice(), ["b", "a"].as_slice()),
+                    (b"a\r\nb\r\n", &["b", "a"]),
+                    (b"a\n\n", &["", "a"]),
+                    (b"\n", &[""]),
+                    (b"", &[]),
+                ] {
+                    let lines = data
+                        .read_back_lines()
+                        .map(|line| line.unwrap())
+                        .collect::<Vec<String>>();
+
+                    assert_eq!(lines, expected, "data: {:?}", data);
+                }
+            }
+
+            #[test]
+            fn crlf() {
+                let data = b"first\r\nsecond\r\n\r\nlast";
+
+                let lines = data
+                    .as_slice()
+                    .read_back_lines()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+                assert_eq!(lines, ["last", "", "second", "first"]);
+            }
+
+            #[test]
+            fn same_as_lines_across_refills() {
+                use std::io::BufRead;
+
+                for data in [
+                    b"first\r\nsecond\nlast without newline".as_slice(),
+                    b"first\r\nsecond\n\n",
+                    b"",
+                ] {
+                    let mut expected = data
+                        .lines()
+                        .map(|line| line.unwrap())
+                        .collect::<Vec<String>>();
+                    expected.reverse();
+
+                    for capacity in 1..=data.len().max(1) {
+                        let reader = crate::BufReadBacker::with_capacity(
+                            capacity,
+                            std::io::Cursor::new(data),
+                        )
+                        .unwrap();
+                        let lines = reader
+                            .read_back_lines()
+                            .map(|line| line.unwrap())
+                            .collect::<Vec<String>>();
+
+                        assert_eq!(lines, expected, "data: {:?}, capacity: {}", data, capacity);
+                    }
+                }
+            }
+
+            #[test]
+            fn skip_empty_lines() {
+                let data = b"a\n\n\nb";
+
+                let lines = data
+                    .as_slice()
+                    .read_back_lines()
+                    .skip_empty()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+
+                assert_eq!(lines, ["b", "a"]);
+            }
+
+            #[test]
+            fn logical_lines() {
+                let data = b"one\n+two\n+three\nfour\nfive\n+six\n";
+
+                let lines = data
+                    .as_slice()
+                    .read_back_lines()
+                    .logical(|line| line.starts_with('+'))
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+
+                assert_eq!(lines, ["five+six", "four", "one+two+three"]);
+            }
+
+            #[test]
+            fn logical_lines_continuation_at_the_start() {
+                let data = b"+wrapped\nline";
+
+                let lines = data
+                    .as_slice()
+                    .read_back_lines()
+                    .logical(|line| line.starts_with('+'))
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+
+                assert_eq!(lines, ["line", "+wrapped"]);
+            }
+
+            #[test]
+            fn physical_lines() {
+                let data = b"one\n+two";
+
+                let lines = data
+                    .as_slice()
+                    .read_back_lines()
+                    .logical(|_| false)
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+
+                assert_eq!(lines, ["+two", "one"]);
+            }
         }
 
         mod read_back_take {
@@ -535,6 +1993,22 @@ mod tests {
 
                     assert_eq!(take.read_back_fill_buf().ok(), Some([1, 2].as_slice()));
                 }
+
+                #[test]
+                fn consume_decrements_limit() {
+                    let data: [u8; 5] = [1, 2, 3, 4, 5];
+                    let mut take = data.as_slice().read_back_take(3);
+
+                    take.read_back_consume(2);
+                    assert_eq!(take.limit(), 1);
+                    assert_eq!(take.read_back_fill_buf().ok(), Some([3].as_slice()));
+
+                    // consuming more than the limit doesn't touch the bytes in front of it
+                    take.read_back_consume(10);
+                    assert_eq!(take.limit(), 0);
+                    assert!(take.read_back_fill_buf().unwrap().is_empty());
+                    assert_eq!(*take.get_mut(), [1, 2]);
+                }
             }
         }
 
@@ -563,6 +2037,7 @@ mod tests {
 
                 let mut chain = data1.as_slice().read_back_chain(data2.as_slice());
 
+                // the empty second reader is skipped
                 assert_eq!(chain.read_back(&mut buffer).ok(), Some(3));
                 assert_eq!(&buffer, &[1, 2, 3, 0]);
             }
@@ -587,13 +2062,91 @@ mod tests {
                 let mut buffer: [u8; 4] = [0; 4];
                 let mut chain = data1.as_slice().read_back_chain(data2.as_slice());
 
+                // read data2, the end of the chain
+                assert_eq!(chain.read_back(&mut buffer).ok(), Some(2));
+                assert_eq!(&buffer, &[3, 4, 0, 0]);
+
                 // read data1
                 assert_eq!(chain.read_back(&mut buffer).ok(), Some(2));
                 assert_eq!(&buffer, &[1, 2, 0, 0]);
+            }
+
+            #[test]
+            fn read_back_to_end() {
+                let mut chain = b"rotated\n".read_back_chain(b"current\n".as_slice());
+
+                let mut buffer = Vec::new();
+                assert_eq!(chain.read_back_to_end(&mut buffer).ok(), Some(16));
+                assert_eq!(&buffer, b"rotated\ncurrent\n");
+            }
+
+            #[test]
+            fn lines_across_readers() {
+                let chain = b"first\nsec".read_back_chain(b"ond\nthird".as_slice());
+
+                let lines = chain
+                    .read_back_lines()
+                    .map(|line| line.unwrap())
+                    .collect::<Vec<String>>();
+                assert_eq!(lines, ["third", "second", "first"]);
+            }
+
+            #[test]
+            fn read_back_until_across_readers() {
+                let mut chain = b"first\nsec".read_back_chain(b"ond".as_slice());
+                let mut buffer = b"> ".to_vec();
+
+                assert_eq!(chain.read_back_until(b'\n', &mut buffer).ok(), Some(7));
+                assert_eq!(&buffer, b"> \nsecond");
+            }
 
-                // read data2
+            #[test]
+            fn second_isnt_polled_after_eof() {
+                let mut chain = [1u8, 2]
+                    .as_slice()
+                    .read_back_take(2)
+                    .read_back_chain([3u8, 4].as_slice().read_back_take(1));
+                let mut buffer = [0; 4];
+
+                assert_eq!(chain.read_back(&mut buffer).ok(), Some(1));
                 assert_eq!(chain.read_back(&mut buffer).ok(), Some(2));
-                assert_eq!(&buffer, &[3, 4, 0, 0]);
+                assert_eq!(&buffer[..2], &[1, 2]);
+
+                // more bytes of the second reader don't show up again
+                chain.get_mut().1.set_limit(1);
+                assert_eq!(chain.read_back(&mut buffer).ok(), Some(0));
+                assert_eq!(chain.get_ref().1.limit(), 1);
+            }
+        }
+
+        mod read_back_bytes_buffered {
+            use super::*;
+
+            #[test]
+            fn empty_data() {
+                let data: [u8; 0] = [];
+
+                let mut bytes = data.as_slice().read_back_bytes_buffered();
+                assert!(bytes.next().is_none());
+            }
+
+            #[test]
+            fn same_as_read_back_bytes() {
+                let data = b"hello there";
+
+                let buffered = data
+                    .as_slice()
+                    .read_back_bytes_buffered()
+                    .map(|b| b.unwrap())
+                    .collect::<Vec<u8>>();
+                let unbuffered = data
+                    .as_slice()
+                    .read_back_bytes()
+                    .map(|b| b.unwrap())
+                    .collect::<Vec<u8>>();
+
+                assert_eq!(buffered, unbuffered);
+                assert_eq!(buffered, b"ereht olleh".to_vec());
             }
         }
 
@@ -613,13 +2166,55 @@ mod tests {
                 let data: [u8; 3] = [1, 2, 3];
 
                 let mut rev_bytes = data.as_slice().read_back_bytes();
-                for byte_value in 3..=1 {
+                for byte_value in (1..=3).rev() {
                     let next_value = rev_bytes.next();
 
                     assert!(&next_value.is_some());
                     assert!(next_value.as_ref().unwrap().is_ok());
                     assert_eq!(next_value.unwrap().unwrap(), byte_value);
                 }
+                assert!(rev_bytes.next().is_none());
+            }
+
+            /// Hands out the given bytes and errors one after another and `Ok(0)` afterwards.
+            struct Scripted(Vec<std::io::Result<u8>>);
+
+            impl ReadBack for Scripted {
+                fn read_back(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                    if self.0.is_empty() {
+                        return Ok(0);
+                    }
+
+                    let byte = self.0.remove(0)?;
+                    buf[0] = byte;
+                    Ok(1)
+                }
+            }
+
+            #[test]
+            fn interrupted_is_retried() {
+                let reader = Scripted(vec![
+                    Ok(3),
+                    Err(std::io::ErrorKind::Interrupted.into()),
+                    Ok(2),
+                ]);
+
+                let bytes = reader
+                    .read_back_bytes()
+                    .collect::<std::io::Result<Vec<u8>>>();
+                assert_eq!(bytes.unwrap(), [3, 2]);
+            }
+
+            #[test]
+            fn errors_are_returned() {
+                let reader = Scripted(vec![Ok(3), Err(std::io::Error::other("broken")), Ok(2)]);
+                let mut bytes = reader.read_back_bytes();
+
+                assert_eq!(bytes.next().unwrap().unwrap(), 3);
+                let err = bytes.next().unwrap().unwrap_err();
+                assert_eq!(err.kind(), std::io::ErrorKind::Other);
+                assert_eq!(bytes.next().unwrap().unwrap(), 2);
+                assert!(bytes.next().is_none());
             }
         }
     }
@@ -0,0 +1,141 @@
+use std::{
+    io::{self, ErrorKind, Read, Seek, SeekFrom},
+    ops::Range,
+};
+
+/// An iterator over the records of an append-only store, newest record first, which are located by an external
+/// index.
+///
+/// The index maps the ID of each record to its byte range in the source, in the order in which the records have
+/// been appended. The records are read back by going through the index in reverse, so only the ranges of the
+/// records which are actually requested are read from the source.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use read_collection::RevIndexedRecords;
+///
+/// fn main() {
+///     let store = Cursor::new(b"firstsecond".to_vec());
+///     let index = vec![("a", 0..5), ("b", 5..11)];
+///
+///     let mut records = RevIndexedRecords::new(store, index);
+///     assert_eq!(records.next().unwrap().unwrap(), ("b", b"second".to_vec()));
+///     assert_eq!(records.next().unwrap().unwrap(), ("a", b"first".to_vec()));
+///     assert!(records.next().is_none());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RevIndexedRecords<R, K> {
+    inner: R,
+    /// The records which haven't been read yet, the newest one is the last.
+    index: Vec<(K, Range<u64>)>,
+}
+
+impl<R: Read + Seek, K> RevIndexedRecords<R, K> {
+    /// Creates a new `RevIndexedRecords` which reads the records of `index` from `inner`.
+    ///
+    /// `index` has to be in insertion order, so the newest record comes last.
+    pub fn new(inner: R, index: Vec<(K, Range<u64>)>) -> Self {
+        Self { inner, index }
+    }
+
+    /// Returns the number of records which haven't been read yet.
+    pub fn remaining(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps this `RevIndexedRecords`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn read_range(&mut self, range: Range<u64>) -> io::Result<Vec<u8>> {
+        let len = range.end.checked_sub(range.start).ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                "range of a record ends before its start",
+            )
+        })?;
+
+        self.inner.seek(SeekFrom::Start(range.start))?;
+
+        // a corrupt index can't allocate more than there is in the source
+        let mut record = Vec::new();
+        (&mut self.inner).take(len).read_to_end(&mut record)?;
+        if (record.len() as u64) < len {
+            return Err(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "source ended before the end of a record",
+            ));
+        }
+
+        Ok(record)
+    }
+}
+
+impl<R: Read + Seek, K> Iterator for RevIndexedRecords<R, K> {
+    type Item = io::Result<(K, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<io::Result<(K, Vec<u8>)>> {
+        let (id, range) = self.index.pop()?;
+
+        Some(self.read_range(range).map(|record| (id, record)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.index.len(), Some(self.index.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn three_records() {
+        let store = Cursor::new(b"headerfirst|second|third".to_vec());
+        let index = vec![(1u32, 6..11), (2, 12..18), (3, 19..24)];
+
+        let records = RevIndexedRecords::new(store, index)
+            .collect::<io::Result<Vec<(u32, Vec<u8>)>>>()
+            .unwrap();
+        assert_eq!(
+            records,
+            [
+                (3, b"third".to_vec()),
+                (2, b"second".to_vec()),
+                (1, b"first".to_vec())
+            ]
+        );
+    }
+
+    #[test]
+    fn only_requested_records_are_read() {
+        let store = Cursor::new(b"firstsecond".to_vec());
+        let mut records = RevIndexedRecords::new(store, vec![("a", 0..5), ("b", 5..11)]);
+
+        assert_eq!(records.next().unwrap().unwrap().0, "b");
+        assert_eq!(records.remaining(), 1);
+        assert_eq!(records.get_ref().position(), 11);
+    }
+
+    #[test]
+    fn invalid_ranges() {
+        let store = Cursor::new(b"data".to_vec());
+        #[allow(clippy::reversed_empty_ranges)]
+        let mut records =
+            RevIndexedRecords::new(store, vec![("reversed", 3..1), ("past the end", 2..10)]);
+
+        let err = records.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        let err = records.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
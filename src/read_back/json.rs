@@ -0,0 +1,191 @@
+use std::io::{self, ErrorKind};
+
+use crate::BufReadBack;
+
+/// The state of the backward scan over a JSON element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Outside of a string.
+    Value,
+    /// Inside of a string.
+    String,
+    /// A `"` has been found inside of a string, followed (backwards) by `backslashes` backslashes. If their
+    /// amount is odd, the quote is escaped and the string goes on. Otherwise the quote starts the string.
+    Quote { backslashes: usize },
+}
+
+fn invalid_json(msg: &'static str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, msg)
+}
+
+/// Reads back all whitespace in front of the reader.
+fn skip_whitespace<R: BufReadBack + ?Sized>(r: &mut R) -> io::Result<()> {
+    loop {
+        let (done, used) = {
+            let new_read = r.read_back_fill_buf()?;
+            let whitespace = new_read
+                .iter()
+                .rev()
+                .take_while(|b| b.is_ascii_whitespace())
+                .count();
+
+            (
+                whitespace < new_read.len() || new_read.is_empty(),
+                whitespace,
+            )
+        };
+
+        r.read_back_consume(used);
+        if done {
+            return Ok(());
+        }
+    }
+}
+
+pub(crate) fn default_buf_read_back_last_json_element<R: BufReadBack + ?Sized>(
+    r: &mut R,
+) -> io::Result<Option<Vec<u8>>> {
+    // skip the whitespace after the array and its closing bracket
+    skip_whitespace(r)?;
+    match r.read_back_fill_buf()?.last() {
+        Some(b']') => r.read_back_consume(1),
+        _ => return Err(invalid_json("JSON array doesn't end with `]`")),
+    }
+
+    skip_whitespace(r)?;
+    if r.read_back_fill_buf()?.last() == Some(&b'[') {
+        return Ok(None);
+    }
+
+    let mut chunks: Vec<Vec<u8>> = Vec::new();
+    let mut state = State::Value;
+    let mut depth: usize = 0;
+
+    loop {
+        let (done, used) = {
+            let new_read = match r.read_back_fill_buf() {
+                Ok(n) => n,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if new_read.is_empty() {
+                return Err(invalid_json(
+                    "reached the start of the reader before the start of the JSON array",
+                ));
+            }
+
+            let mut end = None;
+            for (index, &byte) in new_read.iter().enumerate().rev() {
+                if let State::Quote { backslashes } = state {
+                    if byte == b'\\' {
+                        state = State::Quote {
+                            backslashes: backslashes + 1,
+                        };
+                        continue;
+                    }
+
+                    state = if backslashes % 2 == 1 {
+                        State::String
+                    } else {
+                        State::Value
+                    };
+                }
+
+                match (state, byte) {
+                    (State::String, b'"') => state = State::Quote { backslashes: 0 },
+                    (State::String, _) => {}
+                    (State::Value, b'"') => state = State::String,
+                    (State::Value, b'}' | b']') => depth += 1,
+                    (State::Value, b'{' | b'[') if depth > 0 => depth -= 1,
+                    (State::Value, b',' | b'[') if depth == 0 => {
+                        end = Some(index + 1);
+                        break;
+                    }
+                    (State::Value, b'{') => {
+                        return Err(invalid_json("unbalanced `{` in JSON array"));
+                    }
+                    (State::Value, _) => {}
+                    (State::Quote { .. }, _) => unreachable!(),
+                }
+            }
+
+            let start = end.unwrap_or(0);
+            chunks.push(new_read[start..].to_vec());
+            (end.is_some(), new_read.len() - start)
+        };
+
+        r.read_back_consume(used);
+        if done {
+            break;
+        }
+    }
+
+    let element = chunks.into_iter().rev().flatten().collect::<Vec<u8>>();
+    Ok(Some(element.trim_ascii().to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BufReadBack;
+
+    const ARRAY: &[u8] = br#"[
+  {
+    "id": 1,
+    "msg": "first"
+  },
+  {
+    "id": 2,
+    "msg": "braces } { ] [ and \"quotes\", too\\",
+    "tags": ["a", "b"]
+  }
+]
+"#;
+
+    #[test]
+    fn last_object() {
+        let mut reader = ARRAY;
+
+        let element = reader.read_back_last_json_element().unwrap().unwrap();
+        assert_eq!(
+            element,
+            br#"{
+    "id": 2,
+    "msg": "braces } { ] [ and \"quotes\", too\\",
+    "tags": ["a", "b"]
+  }"#
+        );
+
+        // the reader stops in front of the element
+        assert!(reader.ends_with(b"},"));
+    }
+
+    #[test]
+    fn scalars() {
+        let mut reader = b"[1, 2, \"th,ree\"]".as_slice();
+        assert_eq!(
+            reader.read_back_last_json_element().unwrap(),
+            Some(b"\"th,ree\"".to_vec())
+        );
+
+        let mut reader = b"[42]".as_slice();
+        assert_eq!(
+            reader.read_back_last_json_element().unwrap(),
+            Some(b"42".to_vec())
+        );
+    }
+
+    #[test]
+    fn empty_array() {
+        let mut reader = b"[\n]\n".as_slice();
+        assert_eq!(reader.read_back_last_json_element().unwrap(), None);
+    }
+
+    #[test]
+    fn no_array() {
+        let mut reader = b"{}".as_slice();
+        assert!(reader.read_back_last_json_element().is_err());
+
+        let mut reader = b"1]".as_slice();
+        assert!(reader.read_back_last_json_element().is_err());
+    }
+}
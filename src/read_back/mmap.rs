@@ -0,0 +1,260 @@
+use std::fs::File;
+use std::io::{self, ErrorKind};
+
+use memmap2::Mmap;
+
+use crate::{BufReadBack, ReadBack};
+
+/// The size of the region which is advised to be needed soon at once.
+const ADVISE_WINDOW: usize = 1024 * 1024;
+
+/// A [`BufReadBack`]er over a memory mapped file.
+///
+/// The whole mapping is handed out by [`read_back_fill_buf`](BufReadBack::read_back_fill_buf), so reading back
+/// doesn't copy anything. There's no advice for scanning a mapping backwards, so on Unix the two windows of 1 MiB in
+/// front of the position of the reader are advised with `MADV_WILLNEED` instead, which lets the kernel read them
+/// in ahead of time and reduces the amount of major page faults.
+///
+/// Accessing a part of the mapping after the file has been truncated crashes the process with `SIGBUS`. If the
+/// reader is created with [`map`](MmapReadBacker::map), [`check_truncated`](MmapReadBacker::check_truncated) can
+/// tell whether the file has shrunk in the meantime. This is only a best-effort check since the file can still
+/// shrink right after it, so it doesn't lift the safety requirements of the mapping.
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use memmap2::Mmap;
+/// use read_collection::{BufReadBack, MmapReadBacker};
+///
+/// fn main() {
+///     let file = File::open("./tests/file/test_file1.txt").unwrap();
+///     // SAFETY: the file isn't modified while it's mapped
+///     let mmap = unsafe { Mmap::map(&file) }.unwrap();
+///
+///     let mut reader = MmapReadBacker::new(mmap);
+///     let mut last_line = String::new();
+///     reader.read_back_skip_until(b'\n').unwrap();
+///     reader.read_back_line(&mut last_line).unwrap();
+///     assert!(last_line.starts_with('\n'));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MmapReadBacker {
+    mmap: Mmap,
+    /// The mapped file, if its length can be checked by `check_truncated`.
+    file: Option<File>,
+    /// The index (exclusively) until which the mapping hasn't been read back yet.
+    pos: usize,
+    /// The index from which on the mapping has already been advised.
+    advised: usize,
+}
+
+impl MmapReadBacker {
+    /// Creates a new `MmapReadBacker` which reads back the mapping from its end on.
+    pub fn new(mmap: Mmap) -> Self {
+        let len = mmap.len();
+
+        Self {
+            mmap,
+            file: None,
+            pos: len,
+            advised: len,
+        }
+    }
+
+    /// Maps `file` and creates a new `MmapReadBacker` which reads it back from its end on.
+    ///
+    /// Unlike [`MmapReadBacker::new`], the reader keeps `file`, so
+    /// [`check_truncated`](MmapReadBacker::check_truncated) can compare its length with the mapping.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Mmap::map`] apply: the file mustn't be modified while it's mapped. Shrinking
+    /// it is only detected on a best-effort basis by [`check_truncated`](MmapReadBacker::check_truncated), reading
+    /// back a part of the mapping which has been cut off still crashes the process with `SIGBUS`.
+    pub unsafe fn map(file: File) -> io::Result<Self> {
+        // SAFETY: guaranteed by the caller
+        let mmap = unsafe { Mmap::map(&file) }?;
+
+        Ok(Self {
+            file: Some(file),
+            ..Self::new(mmap)
+        })
+    }
+
+    /// Gets a reference to the underlying mapping.
+    pub fn get_ref(&self) -> &Mmap {
+        &self.mmap
+    }
+
+    /// Unwraps this `MmapReadBacker`, returning the underlying mapping.
+    pub fn into_inner(self) -> Mmap {
+        self.mmap
+    }
+
+    /// Returns an error with [`ErrorKind::UnexpectedEof`] if the file has become shorter than the part of the
+    /// mapping which hasn't been read back yet.
+    ///
+    /// This queries the length of the file, so it's up to the caller how often to check it, e.g. before each
+    /// larger batch of reads. It's only known for a reader which has been created with
+    /// [`map`](MmapReadBacker::map), any other reader always returns `Ok(())`. Since the file can still shrink
+    /// right after the check, it doesn't make reading back the mapping safe if the file is modified.
+    pub fn check_truncated(&self) -> io::Result<()> {
+        let Some(file) = &self.file else {
+            return Ok(());
+        };
+
+        if file.metadata()?.len() < self.pos as u64 {
+            return Err(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "file has been truncated after it was mapped",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Advises the two windows in front of the position as soon as less than one advised window is left in
+    /// front of it.
+    fn advise_ahead(&mut self) {
+        if self.pos.saturating_sub(ADVISE_WINDOW) >= self.advised {
+            return;
+        }
+
+        let start = self.pos.saturating_sub(2 * ADVISE_WINDOW);
+        if start < self.advised {
+            // it's only a hint, so reading back works the same if it fails
+            #[cfg(unix)]
+            let _ = self
+                .mmap
+                .advise_range(memmap2::Advice::WillNeed, start, self.advised - start);
+
+            self.advised = start;
+        }
+    }
+}
+
+impl ReadBack for MmapReadBacker {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.read_back_fill_buf()?.read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+}
+
+impl BufReadBack for MmapReadBacker {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.advise_ahead();
+        Ok(&self.mmap[..self.pos])
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_back::temp_path::TempPath;
+    use std::{fs, path::Path};
+
+    fn create_file(name: &str, content: &[u8]) -> TempPath {
+        let path = TempPath::new(name);
+        fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    fn map(path: &Path) -> Mmap {
+        let file = File::open(path).unwrap();
+        // SAFETY: the temporary file isn't modified while it's mapped
+        unsafe { Mmap::map(&file) }.unwrap()
+    }
+
+    #[test]
+    fn hints_while_reading_back() {
+        let content = (0..5 * ADVISE_WINDOW)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<u8>>();
+        let path = create_file("mmap_hints", &content);
+        let mut reader = MmapReadBacker::new(map(&path));
+
+        // the two windows in front of the end
+        reader.read_back_fill_buf().unwrap();
+        assert_eq!(reader.advised, 3 * ADVISE_WINDOW);
+
+        // nothing new to advise while there's still a whole advised window in front of the position
+        reader.read_back_consume(ADVISE_WINDOW);
+        reader.read_back_fill_buf().unwrap();
+        assert_eq!(reader.advised, 3 * ADVISE_WINDOW);
+
+        reader.read_back_consume(1);
+        reader.read_back_fill_buf().unwrap();
+        assert_eq!(reader.advised, 2 * ADVISE_WINDOW - 1);
+
+        let mut read_back = Vec::new();
+        reader.read_back_to_end(&mut read_back).unwrap();
+        assert_eq!(read_back, &content[..4 * ADVISE_WINDOW - 1]);
+        assert_eq!(reader.advised, 0);
+    }
+
+    #[test]
+    fn last_kilobyte() {
+        let content = (0..64 * 1024u32)
+            .map(|i| (i % 253) as u8)
+            .collect::<Vec<u8>>();
+        let path = create_file("mmap_last_kilobyte", &content);
+        // SAFETY: the temporary file isn't modified while it's mapped
+        let mut reader = unsafe { MmapReadBacker::map(File::open(&path).unwrap()) }.unwrap();
+
+        let mut last = [0; 1024];
+        reader.read_back_exact(&mut last).unwrap();
+        assert_eq!(last, content[content.len() - 1024..]);
+
+        // the mapping is handed out directly
+        let (ptr, len) = {
+            let rest = reader.read_back_fill_buf().unwrap();
+            (rest.as_ptr(), rest.len())
+        };
+        assert_eq!(ptr, reader.get_ref().as_ptr());
+        assert_eq!(len, content.len() - 1024);
+    }
+
+    #[test]
+    fn truncated_after_mapping() {
+        let path = create_file("mmap_truncated", &[b'a'; 8192]);
+        // SAFETY: the mapping isn't accessed after the file has been truncated
+        let mut reader = unsafe { MmapReadBacker::map(File::open(&path).unwrap()) }.unwrap();
+
+        let mut buffer = [0; 16];
+        reader.read_back_exact(&mut buffer).unwrap();
+        reader.check_truncated().unwrap();
+
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(100)
+            .unwrap();
+
+        let err = reader.check_truncated().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        // the part in front of the new length is fine again
+        reader.read_back_consume(8192 - 16 - 100);
+        reader.check_truncated().unwrap();
+    }
+
+    #[test]
+    fn small_file() {
+        let path = create_file("mmap_small", b"first\nsecond\n");
+        let mut reader = MmapReadBacker::new(map(&path));
+
+        let mut line = Vec::new();
+        reader.read_back_skip_until(b'\n').unwrap();
+        reader.read_back_until(b'\n', &mut line).unwrap();
+        assert_eq!(&line, b"\nsecond");
+        assert_eq!(reader.advised, 0);
+    }
+}
@@ -1,8 +1,70 @@
+#[cfg(feature = "tokio")]
+mod async_read_back;
+mod base32;
+mod buf_read_backer;
+mod chars;
+mod copy;
+mod counter;
+mod fixed_records;
+#[cfg(feature = "gzip")]
+mod gzip;
 mod impls;
+mod indexed;
+mod json;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod range;
+mod records;
+mod rev_cursor;
+mod rev_read_borrowed_buf;
+mod rev_to_forward;
+mod reverser;
+mod seek;
+mod sharded;
+mod shared;
+mod slurp;
+#[cfg(test)]
+mod temp_path;
+
+pub use base32::{Base32Alphabet, RevBase32Decoder};
+pub use buf_read_backer::BufReadBacker;
+#[cfg(feature = "metrics")]
+pub use buf_read_backer::RevMetrics;
+pub use chars::RevChars;
+pub use copy::read_back_copy;
+pub use counter::RevCounter;
+pub use fixed_records::RevFixedRecords;
+pub use indexed::RevIndexedRecords;
+pub use range::RevRangeReader;
+pub use records::RevRecords;
+pub use rev_cursor::RevCursor;
+pub use rev_read_borrowed_buf::{
+    RevBorrowedBuf, RevBorrowedCursor, RevBorrowedRegion, RevBorrowedSplit, RevOwnedBuf,
+    RevOwnedBufBorrow,
+};
+pub use rev_to_forward::RevToForward;
+pub use reverser::Reverser;
+pub use seek::{RevSeek, RevSeekFrom};
+pub use sharded::RevShardedReader;
+pub use shared::SharedVecReadBacker;
+pub use slurp::ReadBackSlurp;
+
+#[cfg(feature = "mmap")]
+pub use mmap::MmapReadBacker;
+
+#[cfg(feature = "gzip")]
+pub use gzip::{FrameLength, FramedGzReadBacker, IndexedGzReadBacker};
+
+#[cfg(feature = "tokio")]
+pub use async_read_back::{
+    AsyncBufReadBacker, AsyncReadBack, AsyncReadBackExt, ReadBackExactFuture, ReadBackFuture,
+    ReadBackToEndFuture,
+};
 
 use std::{
     cmp,
-    io::{self, ErrorKind, IoSliceMut, Result},
+    collections::VecDeque,
+    io::{self, ErrorKind, IoSliceMut, Read, Result, Seek, SeekFrom},
     slice,
 };
 
@@ -62,16 +124,57 @@ pub trait ReadBack {
     /// [`Read::read`]: std::io::Read::read
     fn read_back(&mut self, buf: &mut [u8]) -> Result<usize>;
 
-    /// Like [`Read::read_vectored`] but it uses `rev_read` instead of `read`.
+    /// Like [`Read::read_vectored`] but the buffers are filled from the last one to the first one.
+    ///
+    /// The last buffer gets the last bytes of the source and each buffer is filled like in
+    /// [`read_back`](ReadBack::read_back), so a buffer which isn't filled completely has the bytes at its front and
+    /// all buffers in front of it stay untouched. The filled parts of the buffers, from the first one to the last
+    /// one, are in the same order as in the source.
+    ///
+    /// The default implementation calls [`read_back`](ReadBack::read_back) with the last non-empty buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::IoSliceMut;
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"header|body".as_slice();
+    ///     let (mut header, mut body) = ([0; 7], [0; 4]);
+    ///
+    ///     let amount = data
+    ///         .read_back_vectored(&mut [IoSliceMut::new(&mut header), IoSliceMut::new(&mut body)])
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(amount, 11);
+    ///     assert_eq!(&header, b"header|");
+    ///     assert_eq!(&body, b"body");
+    /// }
+    /// ```
     ///
     /// [`Read::read_vectored`]: std::io::Read::read_vectored
     fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
         default_read_back_vectored(|b| self.read_back(b), bufs)
     }
 
-    /// Read all bytes until the start of the source, placing them into `buf`.
+    /// Returns `true` if this reader has an efficient [`read_back_vectored`] implementation.
+    ///
+    /// If a reader doesn't override [`read_back_vectored`], only one buffer is filled per call, so it can be better to
+    /// read back into a single buffer instead. The default implementation returns `false`.
+    ///
+    /// [`read_back_vectored`]: ReadBack::read_back_vectored
+    fn is_read_back_vectored(&self) -> bool {
+        false
+    }
+
+    /// Read all bytes until the start of the source, appending them to `buf`.
     ///
-    /// Can be also seen as "read back until you reach the start of the source".
+    /// Can be also seen as "read back until you reach the start of the source". The bytes are appended in the
+    /// order of the source, so `buf` ends up with the same content as after [`Read::read_to_end`] from the start
+    /// of the source. Bytes which have already been in `buf` stay in front of them.
+    ///
+    /// If you need the bytes in the order in which they've been read back, take a look at
+    /// [`read_back_drain_to_vec_reversed`].
     ///
     /// # Example
     /// ```no_run
@@ -94,31 +197,105 @@ pub trait ReadBack {
     ///     file.read_back_to_end(&mut buffer).unwrap();
     /// }
     /// ```
+    ///
+    /// [`Read::read_to_end`]: std::io::Read::read_to_end
+    /// [`read_back_drain_to_vec_reversed`]: ReadBack::read_back_drain_to_vec_reversed
     fn read_back_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
         default_read_back_to_end(self, buf)
     }
 
-    /// Read all bytes until the start of the source, **pre**pending them to `buf` (since we're reading back).
+    /// Like [`read_back_to_end`], but reads back at most `limit` bytes, for example to guard against unexpectedly
+    /// huge sources.
+    ///
+    /// The bytes in front of the last `limit` ones stay unread, so reading back can be continued afterwards. The
+    /// appended bytes are in forward order and the amount of them is returned. If it's less than `limit`, the
+    /// start of the source has been reached.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"huge footer".as_slice();
+    ///     let mut buffer = Vec::new();
+    ///
+    ///     assert_eq!(data.read_back_to_end_limited(&mut buffer, 6).ok(), Some(6));
+    ///     assert_eq!(buffer, b"footer");
+    ///     assert_eq!(data, b"huge ");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_to_end`]: ReadBack::read_back_to_end
+    fn read_back_to_end_limited(&mut self, buf: &mut Vec<u8>, limit: usize) -> Result<usize> {
+        ReadBack::read_back_take(self, limit as u64).read_back_to_end(buf)
+    }
+
+    /// Read all bytes until the start of the source and append them to `buf`, like [`read_back_to_end`] does.
+    ///
+    /// Since the bytes are read back in chunks, a chunk might start in the middle of a multibyte code point.
+    /// That's why the bytes are validated once all of them have been read back.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::InvalidData`] error if the bytes aren't valid UTF-8. `buf` is left untouched in
+    /// this case.
     ///
     /// # Example
     /// ```
     /// use read_collection::ReadBack;
     ///
     /// fn main() {
-    ///     let mut message = "Arch btw.".to_string();
-    ///     let prefix = b"I use ";
+    ///     let mut message = "I use ".to_string();
+    ///     let suffix = "Arch btw. →";
     ///
-    ///     assert_eq!(prefix.as_slice().read_back_to_string(&mut message).ok(), Some(prefix.len()));
-    ///     assert_eq!(message, "I use Arch btw.".to_string());
+    ///     assert_eq!(suffix.as_bytes().read_back_to_string(&mut message).ok(), Some(suffix.len()));
+    ///     assert_eq!(message, "I use Arch btw. →".to_string());
     /// }
     /// ```
+    ///
+    /// [`read_back_to_end`]: ReadBack::read_back_to_end
     fn read_back_to_string(&mut self, buf: &mut String) -> Result<usize> {
         default_read_back_to_string(self, buf)
     }
 
+    /// Like [`read_back_to_string`], but a UTF-8 byte order mark (`EF BB BF`) at the start of the source is
+    /// dropped.
+    ///
+    /// The start of the source is read back last, so the BOM is removed once all bytes have been read back. A BOM
+    /// somewhere else (for example in the middle of concatenated files) is kept. The returned amount still counts
+    /// the bytes of the BOM, since they have been read back.
+    ///
+    /// # Errors
+    /// The same as for [`read_back_to_string`].
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let data = b"\xEF\xBB\xBFsome text";
+    ///     let mut text = String::new();
+    ///
+    ///     assert_eq!(data.as_slice().read_back_to_string_trim_bom(&mut text).ok(), Some(12));
+    ///     assert_eq!(text, "some text");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_to_string`]: ReadBack::read_back_to_string
+    fn read_back_to_string_trim_bom(&mut self, buf: &mut String) -> Result<usize> {
+        default_read_back_to_string_trim_bom(self, buf)
+    }
+
     /// Read back the exact number of bytes required to fill `buf`.
     ///
-    /// The conditions for [`Read::read_exact`] apply here as well.
+    /// The last `buf.len()` bytes end up in forward order in `buf`, so the earliest of them is at index `0`.
+    /// This is useful for fixed-width trailer records at the end of a file.
+    ///
+    /// The conditions for [`Read::read_exact`] apply here as well: [`ErrorKind::Interrupted`] errors are ignored
+    /// and the read is retried.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::UnexpectedEof`] error if the start of the source has been reached before `buf` has
+    /// been filled. The contents of `buf` are unspecified in this case.
     ///
     /// # Example
     /// ```
@@ -138,6 +315,275 @@ pub trait ReadBack {
         default_read_back_exact(self, buf)
     }
 
+    /// Read back bytes into the unfilled part of `cursor`, which might be uninitialized.
+    ///
+    /// The read bytes are placed at the end of the unfilled part, so they're in front of the bytes which have
+    /// been filled before. The amount of read bytes can be checked with [`RevBorrowedCursor::written`].
+    ///
+    /// The default implementation initializes the whole cursor and calls [`read_back`]. Implementations which
+    /// can copy into the cursor directly should override it to avoid the initialization.
+    ///
+    /// [`read_back`]: ReadBack::read_back
+    fn read_back_buf(&mut self, cursor: RevBorrowedCursor<'_>) -> Result<()> {
+        default_read_back_buf(self, cursor)
+    }
+
+    /// Read back the exact number of bytes required to fill `cursor`.
+    ///
+    /// This is the [`RevBorrowedCursor`] version of [`read_back_exact`]: [`read_back_buf`] is called until the
+    /// cursor is full, retrying on [`ErrorKind::Interrupted`].
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::UnexpectedEof`] error if the start of the source has been reached before the cursor
+    /// has been filled. The bytes which have been read back until then stay filled.
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use read_collection::{ReadBack, RevBorrowedBuf};
+    ///
+    /// fn main() {
+    ///     let mut data = b"header|trailer".as_slice();
+    ///     let mut storage = [MaybeUninit::<u8>::uninit(); 7];
+    ///     let mut buf = RevBorrowedBuf::uninit(&mut storage);
+    ///
+    ///     data.read_back_buf_exact(buf.unfilled()).unwrap();
+    ///     assert_eq!(buf.filled(), b"trailer");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_exact`]: ReadBack::read_back_exact
+    /// [`read_back_buf`]: ReadBack::read_back_buf
+    fn read_back_buf_exact(&mut self, cursor: RevBorrowedCursor<'_>) -> Result<()> {
+        default_read_back_buf_exact(self, cursor)
+    }
+
+    /// Reads back everything until the start of the source and returns the amount of bytes which have been
+    /// read back.
+    ///
+    /// Sources which know how many bytes are in front of them (like files or slices) don't read the bytes at
+    /// all but only move to their start.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"Hello there!".as_slice();
+    ///
+    ///     assert_eq!(data.read_back_count_bytes().ok(), Some(12));
+    ///     assert!(data.is_empty());
+    /// }
+    /// ```
+    fn read_back_count_bytes(&mut self) -> Result<u64> {
+        default_read_back_count_bytes(self)
+    }
+
+    /// Reads back everything until the start of the source and returns the bytes in the order in which they
+    /// have been read back, so the last byte of the source comes first.
+    ///
+    /// This is the difference to [`read_back_to_end`], which stores the bytes in the order of the source.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = [1, 2, 3].as_slice();
+    ///     assert_eq!(data.read_back_drain_to_vec_reversed().ok(), Some(vec![3, 2, 1]));
+    ///
+    ///     let mut data = [1, 2, 3].as_slice();
+    ///     let mut buffer = Vec::new();
+    ///     data.read_back_to_end(&mut buffer).unwrap();
+    ///     assert_eq!(buffer, [1, 2, 3]);
+    /// }
+    /// ```
+    ///
+    /// [`read_back_to_end`]: ReadBack::read_back_to_end
+    fn read_back_drain_to_vec_reversed(&mut self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.read_back_to_end(&mut bytes)?;
+        bytes.reverse();
+
+        Ok(bytes)
+    }
+
+    /// Reads back the last `N` bytes and returns them in forward order, for example to parse a fixed size trailer
+    /// like a checksum or a magic number.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::UnexpectedEof`] error if there are less than `N` bytes, like
+    /// [`read_back_exact`](ReadBack::read_back_exact).
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"payload\x00\x00\x00\x2A".as_slice();
+    ///
+    ///     let checksum = u32::from_be_bytes(data.read_back_footer::<4>().unwrap());
+    ///     assert_eq!(checksum, 42);
+    ///     assert_eq!(data, b"payload");
+    /// }
+    /// ```
+    fn read_back_footer<const N: usize>(&mut self) -> Result<[u8; N]>
+    where
+        Self: Sized,
+    {
+        self.read_back_exact_array()
+    }
+
+    /// Reads back exactly `N` bytes into an array, without the need of a buffer on the heap.
+    ///
+    /// The bytes are in forward order, so this is the same as calling [`read_back_exact`] with an array of
+    /// length `N`.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::UnexpectedEof`] error if there are less than `N` bytes left.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = [1, 2, 3, 4].as_slice();
+    ///
+    ///     assert_eq!(data.read_back_exact_array::<2>().ok(), Some([3, 4]));
+    ///     assert_eq!(data.read_back_exact_array::<2>().ok(), Some([1, 2]));
+    ///     assert!(data.read_back_exact_array::<1>().is_err());
+    /// }
+    /// ```
+    ///
+    /// [`read_back_exact`]: ReadBack::read_back_exact
+    fn read_back_exact_array<const N: usize>(&mut self) -> Result<[u8; N]>
+    where
+        Self: Sized,
+    {
+        let mut array = [0; N];
+        self.read_back_exact(&mut array)?;
+
+        Ok(array)
+    }
+
+    /// Reads back the last record of `N` bytes, for formats which consist of fixed size records, like the
+    /// trailers of ZIP or TAR archives.
+    ///
+    /// The bytes are in forward order. `None` is returned if the start of the source has been reached.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::InvalidData`] error if there are only `1..N` bytes left, so the length of the source
+    /// isn't a multiple of `N`. The remaining bytes have been read back in this case.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = [1, 2, 3, 4].as_slice();
+    ///
+    ///     assert_eq!(data.read_back_record::<2>().ok(), Some(Some([3, 4])));
+    ///     assert_eq!(data.read_back_record::<2>().ok(), Some(Some([1, 2])));
+    ///     assert_eq!(data.read_back_record::<2>().ok(), Some(None));
+    /// }
+    /// ```
+    ///
+    /// See [`read_back_fixed_records`] for an iterator over records whose size is only known at runtime.
+    ///
+    /// [`read_back_fixed_records`]: ReadBack::read_back_fixed_records
+    fn read_back_record<const N: usize>(&mut self) -> Result<Option<[u8; N]>>
+    where
+        Self: Sized,
+    {
+        let mut record = [0; N];
+        Ok(read_back_fixed_record(self, &mut record)?.then_some(record))
+    }
+
+    /// Skips the last `n` bytes, for example a fixed size trailer, so that reading back continues in front of it.
+    ///
+    /// This composes with [`read_back_footer`]: read the footer first to parse it or skip it if it's not needed.
+    /// Sources which know their position, like slices, cursors and files, skip the bytes without reading them.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::UnexpectedEof`] error if there are less than `n` bytes left. The source is at its
+    /// start afterwards.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"record\x00\x01\x02\x03".as_slice();
+    ///
+    ///     data.read_back_skip_footer(4).unwrap();
+    ///     assert_eq!(data, b"record");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_footer`]: ReadBack::read_back_footer
+    fn read_back_skip_footer(&mut self, n: usize) -> Result<()> {
+        default_read_back_skip_footer(self, n)
+    }
+
+    /// Reads back the previous record of a source where each record is followed by its length, encoded as a
+    /// varint. `None` is returned at the start of the source.
+    ///
+    /// The varint is written in reverse, so the byte right behind the record holds the most significant
+    /// 7 bits and the last byte holds the least significant ones. Just like for LEB128, the highest bit of a byte
+    /// tells whether another byte (the one in front of it) belongs to the varint. This allows to read the records
+    /// starting with the newest one.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::UnexpectedEof`] error if the source starts in the middle of a record or its
+    /// length and an [`ErrorKind::InvalidData`] error if the length doesn't fit into a `u64`.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     // "first" with the length 5, "second" with the length 6
+    ///     let mut data = b"first\x05second\x06".as_slice();
+    ///
+    ///     assert_eq!(data.read_back_varint_suffixed_record().unwrap(), Some(b"second".to_vec()));
+    ///     assert_eq!(data.read_back_varint_suffixed_record().unwrap(), Some(b"first".to_vec()));
+    ///     assert_eq!(data.read_back_varint_suffixed_record().unwrap(), None);
+    /// }
+    /// ```
+    fn read_back_varint_suffixed_record(&mut self) -> Result<Option<Vec<u8>>> {
+        default_read_back_varint_suffixed_record(self)
+    }
+
+    /// Creates a "by reference" adapter for this instance of `ReadBack`.
+    ///
+    /// The returned adapter also implements `ReadBack` and will simply borrow this current reader, so adapters
+    /// like [`read_back_take`] can be used without giving up the reader.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"body|footer".as_slice();
+    ///
+    ///     let mut footer = Vec::new();
+    ///     data.read_back_by_ref().read_back_take(7).read_back_to_end(&mut footer).unwrap();
+    ///     assert_eq!(&footer, b"|footer");
+    ///
+    ///     let mut body = Vec::new();
+    ///     data.read_back_to_end(&mut body).unwrap();
+    ///     assert_eq!(&body, b"body");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_take`]: ReadBack::read_back_take
+    fn read_back_by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     /// Transforms this `ReadBack` instance to an `Iterator` over its bytes.
     /// This can be also seen as "read the bytes of the instance in reverse".
     ///
@@ -167,7 +613,67 @@ pub trait ReadBack {
         ReadBackBytes { inner: self }
     }
 
-    /// Creates an adapter which will chain this stream with another.
+    /// Transforms this `ReadBack` instance to an `Iterator` over its records of `size` bytes, starting with the
+    /// last one. See [`RevFixedRecords`] for more details.
+    ///
+    /// # Panics
+    /// Panics if `size` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let records = b"abcdef"
+    ///         .as_slice()
+    ///         .read_back_fixed_records(3)
+    ///         .map(|record| record.unwrap())
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(records, [b"def", b"abc"]);
+    /// }
+    /// ```
+    fn read_back_fixed_records(self, size: usize) -> RevFixedRecords<Self>
+    where
+        Self: Sized,
+    {
+        RevFixedRecords::new(self, size)
+    }
+
+    /// Like [`read_back_to_end`] but it also returns the position of the source which has been reached.
+    ///
+    /// The position is `0` if everything until the start of the source has been read back.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let data = b"Hello there!";
+    ///     let mut cursor = Cursor::new(data.as_slice());
+    ///     cursor.set_position(5);
+    ///
+    ///     let mut buffer = Vec::new();
+    ///     assert_eq!(cursor.read_back_to_end_tracked(&mut buffer).ok(), Some((5, 0)));
+    ///     assert_eq!(&buffer, b"Hello");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_to_end`]: ReadBack::read_back_to_end
+    fn read_back_to_end_tracked(&mut self, buf: &mut Vec<u8>) -> Result<(usize, u64)>
+    where
+        Self: Seek,
+    {
+        let amount = self.read_back_to_end(buf)?;
+        Ok((amount, self.stream_position()?))
+    }
+
+    /// Creates an adapter which will chain this stream with another, as if `next` came right after this stream.
+    ///
+    /// Since reading back starts at the end, `next` is read back first. Once it's exhausted, this stream is read
+    /// back. This is the same order as for [`Read::chain`], just from the back, so a rotated log followed by its
+    /// current segment can be read back as one stream.
     ///
     /// # Example
     /// ```
@@ -181,12 +687,14 @@ pub trait ReadBack {
     ///
     ///     let mut chain = first_data.read_back_chain(second_data.as_slice());
     ///
-    ///     assert_eq!(chain.read_back(&mut buffer).ok(), Some(first_data.len()));
     ///     assert_eq!(chain.read_back(&mut buffer[first_data.len()..]).ok(), Some(second_data.len()));
+    ///     assert_eq!(chain.read_back(&mut buffer[..first_data.len()]).ok(), Some(first_data.len()));
     ///
     ///     assert_eq!(String::from_utf8(buffer).unwrap(), "First in the chain. Second in the chain.".to_string());
     /// }
     /// ```
+    ///
+    /// [`Read::chain`]: std::io::Read::chain
     fn read_back_chain<R: ReadBack>(self, next: R) -> ReadBackChain<Self, R>
     where
         Self: Sized,
@@ -194,7 +702,7 @@ pub trait ReadBack {
         ReadBackChain {
             first: self,
             second: next,
-            done_first: false,
+            done_second: false,
         }
     }
 
@@ -224,6 +732,53 @@ pub trait ReadBack {
     {
         ReadBackTake { inner: self, limit }
     }
+
+    /// Creates an adapter which reads back the bytes of the source in `start..end`, starting at `end`.
+    ///
+    /// This seeks to `end` and limits the reader to `end - start` bytes like [`read_back_take`], so the bytes
+    /// behind `end` and in front of `start` are never read.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::InvalidInput`] error if `start > end` or if `end` is behind the end of the source.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let cursor = Cursor::new(b"head|middle|tail".as_slice());
+    ///
+    ///     let mut range = cursor.read_back_range(5, 11).unwrap();
+    ///     let mut buffer = Vec::new();
+    ///     range.read_back_to_end(&mut buffer).unwrap();
+    ///     assert_eq!(&buffer, b"middle");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_take`]: ReadBack::read_back_take
+    fn read_back_range(mut self, start: u64, end: u64) -> Result<ReadBackTake<Self>>
+    where
+        Self: Sized + Seek,
+    {
+        if start > end {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "start of the range is behind its end",
+            ));
+        }
+
+        let len = self.seek(io::SeekFrom::End(0))?;
+        if end > len {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "end of the range is behind the end of the source",
+            ));
+        }
+
+        self.seek(io::SeekFrom::Start(end))?;
+        Ok(self.read_back_take(end - start))
+    }
 }
 
 /// A `BufReadBack` is a type of [`ReadBack`]er which has an internal buffer, allowing it to perform extra ways of reading.
@@ -240,7 +795,10 @@ pub trait BufReadBack: ReadBack {
     /// When calling this method, none of the contents will be "read back" in the sense that later calling [`read_back`] may return the same contents.
     /// As such, consume must be called with the number of bytes that are consumed from this buffer to ensure that the bytes are never returned twice.
     ///
-    /// An empty buffer returned indicates that the stream has reached the beginning again.
+    /// An empty buffer returned indicates that the stream has reached the beginning again, and only that:
+    /// implementations must not return an empty buffer as long as there are bytes in front of it (for example
+    /// after a short read of the inner reader, they have to read again instead). This allows callers to use
+    /// [`is_empty`] on the returned buffer as the terminal condition.
     ///
     /// # Error
     /// This function will return an I/O error if the underlying reader was read, but returned an error.
@@ -250,6 +808,7 @@ pub trait BufReadBack: ReadBack {
     ///
     /// [`read_back_consume`]: BufReadBack::read_back_consume
     /// [`read_back`]: ReadBack::read_back
+    /// [`is_empty`]: slice::is_empty
     fn read_back_fill_buf(&mut self) -> io::Result<&[u8]>;
 
     /// Tells this buffer that `amt` bytes have been consumed from the buffer, so they should no longer be returned in calls to [`read_back`].
@@ -267,51 +826,206 @@ pub trait BufReadBack: ReadBack {
     /// so this functions returns `Result<bool>`, not `bool`.
     ///
     /// Default implementation calls [`read_back_fill_buf`] and checks that
-    /// returned slice is empty (which means that there is no data left,
-    /// since the start is reached).
+    /// returned slice isn't empty (an empty slice means that there is no data left,
+    /// since the start is reached). Nothing gets consumed.
     ///
     /// # Example
-    /// TODO
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = [1u8, 2].as_slice();
+    ///
+    ///     let mut sum = 0;
+    ///     while data.read_back_has_data_left().unwrap() {
+    ///         sum += data.read_back_fill_buf().unwrap().last().unwrap();
+    ///         data.read_back_consume(1);
+    ///     }
+    ///
+    ///     assert_eq!(sum, 3);
+    /// }
+    /// ```
     ///
     /// [`ReadBack`]: ReadBack
     /// [`read_back_fill_buf`]: BufReadBack::read_back_fill_buf
     fn read_back_has_data_left(&mut self) -> io::Result<bool> {
-        self.read_back_fill_buf().map(|buffer| buffer.is_empty())
+        self.read_back_fill_buf().map(|buffer| !buffer.is_empty())
     }
 
     /// Read all bytes into `buf` until the delimiter `byte` or the beginning of the reader is reached.
     ///
     /// This function will read bytes from the underlying stream until the delimiter or the beginning of the reader is reached.
-    /// Once found, all bytes up to, and including, the delimiter (if found) will be appended to buf.
+    /// Once found, all bytes up to, and including, the delimiter (if found) will be appended to buf in forward order,
+    /// so the delimiter is the first of the appended bytes.
     ///
-    /// If successful, this function will return the total number of bytes read.
+    /// The delimiter is searched with [`memrchr`](memchr::memrchr) in each buffer returned by
+    /// [`read_back_fill_buf`].
+    ///
+    /// If successful, this function will return the total number of bytes read. If the source doesn't contain the
+    /// delimiter, everything until its beginning is read.
     ///
     /// # Example
-    /// TODO
-    fn read_back_until(&mut self, delim: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
-        default_buf_read_back_until(self, delim, buf)
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"first;second;".as_slice();
+    ///     let mut buffer = b"> ".to_vec();
+    ///
+    ///     assert_eq!(data.read_back_until(b';', &mut buffer).ok(), Some(1));
+    ///     assert_eq!(buffer, b"> ;");
+    ///
+    ///     assert_eq!(data.read_back_until(b';', &mut buffer).ok(), Some(7));
+    ///     assert_eq!(buffer, b"> ;;second");
+    ///
+    ///     assert_eq!(data.read_back_until(b';', &mut buffer).ok(), Some(5));
+    ///     assert_eq!(buffer, b"> ;;secondfirst");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_fill_buf`]: BufReadBack::read_back_fill_buf
+    fn read_back_until(&mut self, delim: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        default_buf_read_back_until(self, delim, buf)
+    }
+
+    /// Read back bytes into `buf` until any of the bytes in `delims` or the beginning of the reader is reached.
+    ///
+    /// This is [`read_back_until`] for multiple delimiters, for example for records which end with either `\n` or
+    /// `\r`: the read bytes, including the found delimiter, are appended to `buf` in forward order. An empty
+    /// `delims` reads back until the beginning. Use [`read_back_until_any_limited`] to limit the amount of bytes
+    /// which are read back.
+    ///
+    /// On success, the amount of appended bytes is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"first\rsecond\nthird".as_slice();
+    ///     let mut buffer = Vec::new();
+    ///
+    ///     assert_eq!(data.read_back_until_any(b"\r\n", &mut buffer).ok(), Some(6));
+    ///     assert_eq!(buffer, b"\nthird");
+    ///
+    ///     buffer.clear();
+    ///     assert_eq!(data.read_back_until_any(b"\r\n", &mut buffer).ok(), Some(7));
+    ///     assert_eq!(buffer, b"\rsecond");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_until`]: BufReadBack::read_back_until
+    /// [`read_back_until_any_limited`]: BufReadBack::read_back_until_any_limited
+    fn read_back_until_any(&mut self, delims: &[u8], buf: &mut Vec<u8>) -> io::Result<usize> {
+        let start = buf.len();
+        default_buf_read_back_until_any_limited(self, delims, buf, usize::MAX)?;
+
+        Ok(buf.len() - start)
+    }
+
+    /// Read back bytes into `buf` until one of the bytes in `delims`, the beginning of the reader or the limit of
+    /// `max` bytes is reached.
+    ///
+    /// This is the most flexible way to scan backwards: like [`read_back_until`], the read bytes (including the
+    /// delimiter, if found) are appended to `buf` in forward order, but any of the bytes in `delims` stops the
+    /// scan and at most `max` bytes are read back, so a missing delimiter can't make `buf` grow unbounded.
+    ///
+    /// The returned [`RevUntilStop`] tells why the scan stopped. If `max` bytes have been read back without
+    /// finding a delimiter, [`RevUntilStop::Limit`] is returned even if the beginning has been reached with them.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::{BufReadBack, RevUntilStop};
+    ///
+    /// fn main() {
+    ///     let mut data = b"key=value;other:thing".as_slice();
+    ///     let mut buffer = Vec::new();
+    ///
+    ///     let stop = data.read_back_until_any_limited(b"=:", &mut buffer, 64).unwrap();
+    ///     assert_eq!(stop, RevUntilStop::Delimiter(b':'));
+    ///     assert_eq!(buffer, b":thing");
+    ///
+    ///     buffer.clear();
+    ///     let stop = data.read_back_until_any_limited(b"=:", &mut buffer, 3).unwrap();
+    ///     assert_eq!(stop, RevUntilStop::Limit);
+    ///     assert_eq!(buffer, b"her");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_until`]: BufReadBack::read_back_until
+    fn read_back_until_any_limited(
+        &mut self,
+        delims: &[u8],
+        buf: &mut Vec<u8>,
+        max: usize,
+    ) -> io::Result<RevUntilStop> {
+        default_buf_read_back_until_any_limited(self, delims, buf, max)
     }
 
     /// Skip all bytes until the delimiter byte or the beginning is reached.
     ///
-    /// This function will read (and discard) bytes from the underlying stream until the delimiter or EOF is found.
+    /// This function will read (and discard) bytes from the underlying stream until the delimiter or the beginning is
+    /// found. Unlike [`read_back_until`], nothing gets allocated: the delimiter is searched with
+    /// [`memrchr`](memchr::memrchr) in each buffer returned by [`read_back_fill_buf`], which is consumed afterwards.
     ///
-    /// If successful, this function will return the total number of bytes read, including the delimiter byte.
+    /// If successful, this function will return the total number of bytes read, including the delimiter byte. If the
+    /// source doesn't contain the delimiter, this is the amount of bytes until its beginning.
     ///
     /// This is useful for efficiently skipping data such as NUL-terminated strings in binary file formats without buffering.
     ///
     /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     // skip the trailing checksum block to get to the payload
+    ///     let mut data = b"payload\0c0ffee".as_slice();
+    ///
+    ///     assert_eq!(data.read_back_skip_until(b'\0').ok(), Some(7));
+    ///     assert_eq!(data, b"payload");
+    ///
+    ///     assert_eq!(data.read_back_skip_until(b'\0').ok(), Some(7));
+    ///     assert!(data.is_empty());
+    /// }
+    /// ```
+    ///
+    /// [`read_back_until`]: BufReadBack::read_back_until
+    /// [`read_back_fill_buf`]: BufReadBack::read_back_fill_buf
     fn read_back_skip_until(&mut self, delim: u8) -> io::Result<usize> {
         default_buf_read_skip_until(self, delim)
     }
 
-    /// Read all bytes until a newline (the `0xA` byte) is reached, and *prepend* them to the provided String buffer.
+    /// Read all bytes until a newline (the `0xA` byte) is reached, and append them to the provided String buffer.
     ///
     /// This function also behaves similar as [`BufRead::read_line`] except that it uses the functions of [`ReadBack`] instead
-    /// of [`Read`].
+    /// of [`Read`]: the newline which terminates the line in front of the last one is the first byte of the appended
+    /// line (together with a `\r` in front of it, if any). It has been consumed, so the next call reads the line in
+    /// front of it without that newline.
+    ///
+    /// If successful, this function returns the amount of bytes which have been read back, `0` means that the
+    /// beginning of the reader has been reached.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::InvalidData`] error if the line isn't valid UTF-8. The line is consumed, but `dest`
+    /// is left untouched.
     ///
     /// # Example
-    /// TODO
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"first\nsecond".as_slice();
+    ///     let mut buffer = String::new();
+    ///
+    ///     assert_eq!(data.read_back_line(&mut buffer).ok(), Some(7));
+    ///     assert_eq!(buffer, "\nsecond");
+    ///
+    ///     assert_eq!(data.read_back_line(&mut buffer).ok(), Some(5));
+    ///     assert_eq!(buffer, "\nsecondfirst");
+    ///
+    ///     assert_eq!(data.read_back_line(&mut buffer).ok(), Some(0));
+    /// }
+    /// ```
     ///
     /// [`BufRead::read_line`]: std::io::BufRead::read_line
     /// [`clear`]: std::string::String::clear
@@ -321,13 +1035,215 @@ pub trait BufReadBack: ReadBack {
         default_buf_read_back_line(self, dest)
     }
 
-    /// Returns an iterator over the contents of this reader split on the byte byte.
+    /// Like [`read_back_line`], but invalid UTF-8 is replaced with [`U+FFFD REPLACEMENT CHARACTER`] instead of
+    /// returning an error, like [`String::from_utf8_lossy`] does.
+    ///
+    /// The returned amount is the number of bytes which have been read back, not the length of the appended string.
+    /// This is useful to keep going past corrupted lines, for example when reading back log files.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let mut data = b"first\nsec\xffond".as_slice();
+    ///     let mut buffer = String::new();
+    ///
+    ///     assert_eq!(data.read_back_line_lossy(&mut buffer).ok(), Some(8));
+    ///     assert_eq!(buffer, "\nsec\u{FFFD}ond");
+    ///
+    ///     assert_eq!(data.read_back_line_lossy(&mut buffer).ok(), Some(5));
+    ///     assert_eq!(buffer, "\nsec\u{FFFD}ondfirst");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_line`]: BufReadBack::read_back_line
+    /// [`U+FFFD REPLACEMENT CHARACTER`]: char::REPLACEMENT_CHARACTER
+    fn read_back_line_lossy(&mut self, dest: &mut String) -> io::Result<usize> {
+        let buffer = read_back_line_bytes(self)?;
+        dest.push_str(&String::from_utf8_lossy(&buffer));
+        Ok(buffer.len())
+    }
+
+    /// Transforms this `BufReadBack` instance to an `Iterator` over its bytes, taking them directly out of the internal buffer.
+    ///
+    /// In contrast to [`read_back_bytes`], which calls [`read_back`] once for every single byte, this iterator only
+    /// refills the internal buffer if it's empty. Prefer this one if `Self` is buffered.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let data = [1, 2, 3];
+    ///
+    ///     let read_back_bytes = data
+    ///         .as_slice()
+    ///         .read_back_bytes_buffered()
+    ///         .map(|b| b.unwrap())
+    ///         .collect::<Vec<u8>>();
+    ///
+    ///     assert_eq!(read_back_bytes, [3, 2, 1].to_vec());
+    /// }
+    /// ```
+    ///
+    /// [`read_back_bytes`]: ReadBack::read_back_bytes
+    /// [`read_back`]: ReadBack::read_back
+    fn read_back_bytes_buffered(self) -> ReadBackBytesBuffered<Self>
+    where
+        Self: Sized,
+    {
+        ReadBackBytesBuffered { inner: self }
+    }
+
+    /// Reads back the last `k` records which are terminated by `delim` and returns them in forward order.
+    ///
+    /// The delimiters are not part of the returned records and a delimiter at the very end of the
+    /// reader terminates the last record instead of starting an empty one.
+    /// Reading back stops as soon as `k` records have been found, so the rest of the reader stays unread.
+    /// If the reader contains less than `k` records, all of them are returned.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let data = b"first\nsecond\nthird\n";
+    ///     let mut reader = data.as_slice();
+    ///
+    ///     let records = reader.read_back_last_records(2, b'\n').unwrap();
+    ///     assert_eq!(records, [b"second".to_vec(), b"third".to_vec()]);
+    ///
+    ///     // only the first record is left
+    ///     assert_eq!(reader, b"first");
+    /// }
+    /// ```
+    fn read_back_last_records(&mut self, k: usize, delim: u8) -> io::Result<Vec<Vec<u8>>> {
+        default_buf_read_back_last_records(self, k, delim)
+    }
+
+    /// Reads back everything until the start of the reader and returns the `(start, end)` offsets of the records
+    /// which are terminated by `delim`, newest record first.
+    ///
+    /// The offsets are relative to the start of the reader and `end` is exclusive. The delimiters are not part of
+    /// the records and a delimiter at the very end of the reader terminates the last record instead of starting an
+    /// empty one, so the records are the same as the ones of [`BufRead::split`].
+    /// This allows to build an index of the records with a single backward scan.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let data = b"first\nsecond\n";
+    ///
+    ///     let index = data.as_slice().read_back_index_records(b'\n').unwrap();
+    ///     assert_eq!(index, [(6, 12), (0, 5)]);
+    /// }
+    /// ```
+    ///
+    /// [`BufRead::split`]: std::io::BufRead::split
+    fn read_back_index_records(&mut self, delim: u8) -> io::Result<Vec<(u64, u64)>> {
+        default_buf_read_back_index_records(self, delim)
+    }
+
+    /// Reads back the lines until the newest line which matches `pred` and returns it together with its
+    /// surrounding lines, in forward order.
+    ///
+    /// The lines are split like the ones of [`read_back_lines`]. Up to `before` lines in front of the match
+    /// (older ones) are read back as well. Up to `after` lines behind the match (newer ones) are returned too:
+    /// they have already been read back while searching for the match. There might be less context lines if
+    /// the start or the end of the reader is reached first.
+    ///
+    /// Returns `None` if no line matches, then everything has been read back.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let log = b"boot\nconnecting\nERROR: timeout\nretrying\nconnected\n";
+    ///
+    ///     let context = log
+    ///         .as_slice()
+    ///         .read_back_find_with_context(|line| line.starts_with("ERROR"), 1, 1)
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(
+    ///         context,
+    ///         Some(vec![
+    ///             "connecting".to_string(),
+    ///             "ERROR: timeout".to_string(),
+    ///             "retrying".to_string(),
+    ///         ])
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`read_back_lines`]: BufReadBack::read_back_lines
+    fn read_back_find_with_context<P>(
+        &mut self,
+        pred: P,
+        before: usize,
+        after: usize,
+    ) -> io::Result<Option<Vec<String>>>
+    where
+        Self: Sized,
+        P: FnMut(&str) -> bool,
+    {
+        default_buf_read_back_find_with_context(self, pred, before, after)
+    }
+
+    /// Reads back the last element of a JSON array, for example the newest entry of a (pretty-printed) JSON log,
+    /// without parsing the whole array.
+    ///
+    /// The reader has to end with the array. Brackets and braces are balanced backwards until the start of the
+    /// last element has been found, so the element itself isn't validated. Brackets and braces within strings are
+    /// ignored. The returned element doesn't contain the surrounding whitespace, afterwards the reader is right in
+    /// front of the element.
+    ///
+    /// Returns `None` if the array is empty.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::InvalidData`] error if the reader doesn't end with a JSON array.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let log = b"[\n  {\"id\": 1},\n  {\"id\": 2, \"msg\": \"}\"}\n]\n";
+    ///
+    ///     let last = log.as_slice().read_back_last_json_element().unwrap();
+    ///     assert_eq!(last, Some(b"{\"id\": 2, \"msg\": \"}\"}".to_vec()));
+    /// }
+    /// ```
+    fn read_back_last_json_element(&mut self) -> io::Result<Option<Vec<u8>>> {
+        json::default_buf_read_back_last_json_element(self)
+    }
+
+    /// Returns an iterator over the contents of this reader split on the byte `delim`, starting with the last segment.
     ///
     /// This function also behaves similar as [`BufRead::split`] except that it uses the functions of [`ReadBack`] instead
-    /// of [`Read`].
+    /// of [`Read`]: the segments are in forward order and don't contain the delimiter, and a delimiter at the very end
+    /// of the reader terminates the last segment instead of yielding an empty one.
     ///
     /// # Example
-    /// TODO
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let index = b"first\0second\0third\0";
+    ///
+    ///     let segments = index
+    ///         .as_slice()
+    ///         .read_back_split(b'\0')
+    ///         .map(|segment| segment.unwrap())
+    ///         .collect::<Vec<Vec<u8>>>();
+    ///
+    ///     assert_eq!(segments, [b"third".to_vec(), b"second".to_vec(), b"first".to_vec()]);
+    /// }
+    /// ```
     ///
     /// [`BufRead::split`]: std::io::BufRead::split
     /// [`ReadBack`]: ReadBack
@@ -336,16 +1252,74 @@ pub trait BufReadBack: ReadBack {
     where
         Self: Sized,
     {
-        ReadBackSplit { buf: self, delim }
+        ReadBackSplit {
+            buf: self,
+            delim,
+            started: false,
+            pending: false,
+        }
+    }
+
+    /// Returns a [`RevRecords`] which reads back the records of this reader which are terminated by `delim`,
+    /// borrowing them from the buffer of the reader if possible.
+    ///
+    /// Unlike [`read_back_split`], which allocates each record, this can't be an [`Iterator`] since the records
+    /// borrow from it. See [`RevRecords`] for more details.
+    ///
+    /// [`read_back_split`]: BufReadBack::read_back_split
+    fn read_back_records(self, delim: u8) -> RevRecords<Self>
+    where
+        Self: Sized,
+    {
+        RevRecords::new(self, delim)
+    }
+
+    /// Returns an iterator over the `char`s of this UTF-8 encoded reader, starting with the last one.
+    ///
+    /// See [`RevChars`] for how invalid UTF-8 is handled.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let mut chars = "Grüße".as_bytes().read_back_chars();
+    ///
+    ///     assert_eq!(chars.next().unwrap().ok(), Some('e'));
+    ///     assert_eq!(chars.next().unwrap().ok(), Some('ß'));
+    ///     assert_eq!(chars.next().unwrap().ok(), Some('ü'));
+    ///     assert_eq!(chars.next().unwrap().ok(), Some('r'));
+    /// }
+    /// ```
+    fn read_back_chars(self) -> RevChars<Self>
+    where
+        Self: Sized,
+    {
+        RevChars::new(self)
     }
 
-    /// Returns an iterator over the lines of this reader.
+    /// Returns an iterator over the lines of this reader, starting with the last one.
     ///
     /// This function also behaves similar as [`BufRead::lines`] except that it uses the functions of [`ReadBack`] instead
-    /// of [`Read`].
+    /// of [`Read`]: the yielded lines don't contain their `\n` or `\r\n`, a last line without a newline is yielded
+    /// as well and a newline at the very end of the reader doesn't yield an empty line.
     ///
     /// # Example
-    /// TODO
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let log = b"first\r\nsecond\nthird\n";
+    ///
+    ///     let lines = log
+    ///         .as_slice()
+    ///         .read_back_lines()
+    ///         .map(|line| line.unwrap())
+    ///         .collect::<Vec<String>>();
+    ///
+    ///     assert_eq!(lines, ["third", "second", "first"]);
+    /// }
+    /// ```
     ///
     /// [`BufRead::lines`]: std::io::BufRead::lines
     /// [`ReadBack`]: ReadBack
@@ -354,10 +1328,79 @@ pub trait BufReadBack: ReadBack {
     where
         Self: Sized,
     {
-        RevLines { buf: self }
+        RevLines {
+            buf: self,
+            skip_empty: false,
+            state: LineState::default(),
+        }
+    }
+
+    /// Returns an iterator over the lines of this reader together with their 0-based line number, counted from
+    /// the start of the reader, for example to show "line 4823" while scrolling up in a log.
+    ///
+    /// The lines are split like the ones of [`read_back_lines`]. Since the line number of the last line is only
+    /// known after counting all lines in front of it, the reader gets read forward from its start up to its
+    /// current position once, right before the first line is returned. This is why the reader has to implement
+    /// [`Read`] and [`Seek`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let mut cursor = Cursor::new(b"first\nsecond\nthird\n".as_slice());
+    ///     cursor.set_position(19);
+    ///
+    ///     let lines = cursor
+    ///         .read_back_lines_indexed()
+    ///         .map(|line| line.unwrap())
+    ///         .collect::<Vec<(usize, String)>>();
+    ///
+    ///     assert_eq!(lines, [(2, "third".to_string()), (1, "second".to_string()), (0, "first".to_string())]);
+    /// }
+    /// ```
+    ///
+    /// [`read_back_lines`]: BufReadBack::read_back_lines
+    /// [`Read`]: std::io::Read
+    fn read_back_lines_indexed(self) -> RevLinesIndexed<Self>
+    where
+        Self: Sized + io::Read + Seek,
+    {
+        RevLinesIndexed {
+            lines: self.read_back_lines(),
+            next_number: None,
+        }
     }
 }
 
+/// The reason why [`read_back_until_any_limited`] stopped reading back.
+///
+/// [`read_back_until_any_limited`]: BufReadBack::read_back_until_any_limited
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RevUntilStop {
+    /// The given delimiter has been found. It's the first byte of the read back bytes.
+    Delimiter(u8),
+    /// The maximum amount of bytes has been read back without finding a delimiter.
+    Limit,
+    /// The beginning of the reader has been reached without finding a delimiter.
+    Front,
+}
+
+/// The terminators which end a line, see [`RevLines::line_ending`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEnding {
+    /// Only `\n` ends a line, a `\r` in front of it stays part of the line.
+    Lf,
+    /// `\n` and `\r\n` end a line, like for [`BufRead::lines`].
+    ///
+    /// [`BufRead::lines`]: std::io::BufRead::lines
+    #[default]
+    CrLf,
+    /// `\n`, `\r\n` and a lone `\r` end a line.
+    Any,
+}
+
 /// An iterator over `u8` values of a read-back reader.
 ///
 /// This struct is generally created by calling [`read_back_bytes`] on a [`ReadBack`] reader.
@@ -380,7 +1423,6 @@ impl<R: ReadBack> Iterator for ReadBackBytes<R> {
         loop {
             return match self.inner.read_back(slice::from_mut(&mut byte)) {
                 Ok(0) => None,
-                Err(e) if e.kind() == ErrorKind::Other => None,
                 Ok(..) => Some(Ok(byte)),
                 Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
                 Err(e) => Some(Err(e)),
@@ -394,6 +1436,35 @@ impl<R: ReadBack> Iterator for ReadBackBytes<R> {
     }
 }
 
+/// An iterator over `u8` values of a buffered read-back reader.
+///
+/// This struct is generally created by calling [`read_back_bytes_buffered`] on a [`BufReadBack`] reader.
+/// Please see the documentation of [`read_back_bytes_buffered`] for more details.
+///
+/// [`read_back_bytes_buffered`]: BufReadBack::read_back_bytes_buffered
+/// [`BufReadBack`]: BufReadBack
+#[derive(Debug)]
+pub struct ReadBackBytesBuffered<B> {
+    inner: B,
+}
+
+impl<B: BufReadBack> Iterator for ReadBackBytesBuffered<B> {
+    type Item = Result<u8>;
+
+    fn next(&mut self) -> Option<Result<u8>> {
+        loop {
+            let byte = match self.inner.read_back_fill_buf() {
+                Ok(buf) => *buf.last()?,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e)),
+            };
+
+            self.inner.read_back_consume(1);
+            return Some(Ok(byte));
+        }
+    }
+}
+
 /// Adapter to chain together two [`ReadBack`]s.
 ///
 /// This struct is generally created by calling [`read_back_chain`] on a reader.
@@ -405,7 +1476,8 @@ impl<R: ReadBack> Iterator for ReadBackBytes<R> {
 pub struct ReadBackChain<T, U> {
     first: T,
     second: U,
-    done_first: bool,
+    /// Whether `second` has been read back completely, so it isn't polled again.
+    done_second: bool,
 }
 
 impl<T, U> ReadBackChain<T, U> {
@@ -487,68 +1559,81 @@ impl<T, U> ReadBackChain<T, U> {
 
 impl<T: ReadBack, U: ReadBack> ReadBack for ReadBackChain<T, U> {
     fn read_back(&mut self, buf: &mut [u8]) -> Result<usize> {
-        if !self.done_first {
-            match self.first.read_back(buf)? {
-                0 if !buf.is_empty() => self.done_first = true,
+        if !self.done_second {
+            match self.second.read_back(buf)? {
+                0 if !buf.is_empty() => self.done_second = true,
                 n => return Ok(n),
             }
         }
-        self.second.read_back(buf)
+        self.first.read_back(buf)
     }
 
     fn read_back_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
-        if !self.done_first {
-            match self.first.read_back_vectored(bufs)? {
-                0 if bufs.iter().any(|b| !b.is_empty()) => self.done_first = true,
+        if !self.done_second {
+            match self.second.read_back_vectored(bufs)? {
+                0 if bufs.iter().any(|b| !b.is_empty()) => self.done_second = true,
                 n => return Ok(n),
             }
         }
-        self.second.read_back_vectored(bufs)
+        self.first.read_back_vectored(bufs)
+    }
+
+    fn is_read_back_vectored(&self) -> bool {
+        self.first.is_read_back_vectored() || self.second.is_read_back_vectored()
     }
 
     fn read_back_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
         let mut read = 0;
-        if !self.done_first {
-            read += self.first.read_back_to_end(buf)?;
-            self.done_first = true;
+        let mut second = Vec::new();
+        if !self.done_second {
+            read += self.second.read_back_to_end(&mut second)?;
+            self.done_second = true;
         }
-        read += self.second.read_back_to_end(buf)?;
+        read += self.first.read_back_to_end(buf)?;
+        buf.append(&mut second);
         Ok(read)
     }
 }
 
 impl<T: BufReadBack, U: BufReadBack> BufReadBack for ReadBackChain<T, U> {
     fn read_back_fill_buf(&mut self) -> Result<&[u8]> {
-        if !self.done_first {
-            match self.first.read_back_fill_buf()? {
-                [] => self.done_first = true,
+        if !self.done_second {
+            match self.second.read_back_fill_buf()? {
+                [] => self.done_second = true,
                 buf => return Ok(buf),
             }
         }
-        self.second.read_back_fill_buf()
+        self.first.read_back_fill_buf()
     }
 
     fn read_back_consume(&mut self, amt: usize) {
-        if !self.done_first {
-            self.first.read_back_consume(amt)
-        } else {
+        if !self.done_second {
             self.second.read_back_consume(amt)
+        } else {
+            self.first.read_back_consume(amt)
         }
     }
 
     fn read_back_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> Result<usize> {
-        let mut read = 0;
-        if !self.done_first {
-            let n = self.first.read_back_until(byte, buf)?;
-            read += n;
+        if self.done_second {
+            return self.first.read_back_until(byte, buf);
+        }
 
-            match buf.last() {
-                Some(b) if *b == byte && n != 0 => return Ok(read),
-                _ => self.done_first = true,
-            }
+        let start = buf.len();
+        let n = self.second.read_back_until(byte, buf)?;
+
+        // the delimiter is the first of the appended bytes
+        if n != 0 && buf[start] == byte {
+            return Ok(n);
         }
-        read += self.second.read_back_until(byte, buf)?;
-        Ok(read)
+        self.done_second = true;
+
+        // the bytes of `first` are in front of the ones of `second`
+        let mut front = Vec::new();
+        let m = self.first.read_back_until(byte, &mut front)?;
+        buf.splice(start..start, front);
+
+        Ok(n + m)
     }
 }
 
@@ -564,18 +1649,38 @@ impl<T: BufReadBack, U: BufReadBack> BufReadBack for ReadBackChain<T, U> {
 pub struct ReadBackSplit<B> {
     buf: B,
     delim: u8,
+    /// Whether the delimiter at the end of the reader has been checked already.
+    started: bool,
+    /// Whether a delimiter has been read back, so there's one more segment in front of it (which might be empty).
+    pending: bool,
 }
 
 impl<B: BufReadBack> Iterator for ReadBackSplit<B> {
     type Item = Result<Vec<u8>>;
 
     fn next(&mut self) -> Option<Result<Vec<u8>>> {
+        if !self.started {
+            self.started = true;
+
+            // like for `BufRead::split`, a delimiter at the end terminates the last segment
+            match self.buf.read_back_fill_buf() {
+                Ok(buf) if buf.last() == Some(&self.delim) => {
+                    self.buf.read_back_consume(1);
+                    self.pending = true;
+                }
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
         let mut buf = Vec::new();
         match self.buf.read_back_until(self.delim, &mut buf) {
-            Ok(0) => None,
+            Ok(0) if !self.pending => None,
             Ok(_n) => {
-                if buf[0] == self.delim {
-                    buf.drain(..1);
+                // only a segment which starts with a delimiter has another segment in front of it
+                self.pending = buf.first() == Some(&self.delim);
+                if self.pending {
+                    buf.remove(0);
                 }
                 Some(Ok(buf))
             }
@@ -584,29 +1689,281 @@ impl<B: BufReadBack> Iterator for ReadBackSplit<B> {
     }
 }
 
-/// An iterator over the lines of an instance of `RevBufRead`.
+/// An iterator over the lines of an instance of [`BufReadBack`].
 ///
-/// This struct is generally created by calling [`rev_lines`] on a `RevBufRead`.
-/// Please see the documentation of [`rev_lines`] for more details.
+/// This struct is generally created by calling [`read_back_lines`] on a [`BufReadBack`].
+/// Please see the documentation of [`read_back_lines`] for more details.
 ///
-/// [`rev_lines`]: RevBufRead::rev_lines
+/// [`BufReadBack`]: BufReadBack
+/// [`read_back_lines`]: BufReadBack::read_back_lines
 #[derive(Debug)]
 pub struct RevLines<B> {
     buf: B,
+    skip_empty: bool,
+    state: LineState,
 }
 
-impl<B: BufReadBack> Iterator for RevLines<B> {
-    type Item = Result<String>;
-
-    fn next(&mut self) -> Option<Result<String>> {
-        let mut buf = String::new();
-        match self.buf.read_back_line(&mut buf) {
-            Ok(0) => None,
-            Ok(_n) => {
-                if buf.starts_with('\n') {
-                    buf = buf.drain(1..).collect();
-                } else if buf.starts_with("\r\n") {
-                    buf = buf.drain(2..).collect();
+impl<B> RevLines<B> {
+    /// Skip empty lines instead of yielding them as empty strings.
+    ///
+    /// By default, empty lines are yielded, just like [`BufRead::lines`] does.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     let data = b"first\n\nsecond";
+    ///
+    ///     let lines = data
+    ///         .as_slice()
+    ///         .read_back_lines()
+    ///         .skip_empty()
+    ///         .map(|line| line.unwrap())
+    ///         .collect::<Vec<String>>();
+    ///
+    ///     assert_eq!(lines, ["second", "first"]);
+    /// }
+    /// ```
+    ///
+    /// [`BufRead::lines`]: std::io::BufRead::lines
+    pub fn skip_empty(mut self) -> Self {
+        self.skip_empty = true;
+        self
+    }
+
+    /// Sets the terminators which end a line, [`LineEnding::CrLf`] by default.
+    ///
+    /// A `\r\n` is always a single terminator for [`LineEnding::CrLf`] and [`LineEnding::Any`], so the lines never
+    /// keep a trailing `\r` with them. The terminator at the very end of the reader doesn't start an empty line.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::{BufReadBack, LineEnding};
+    ///
+    /// fn main() {
+    ///     let data = b"first\rsecond\r\nthird\n";
+    ///
+    ///     let lines = data
+    ///         .as_slice()
+    ///         .read_back_lines()
+    ///         .line_ending(LineEnding::Any)
+    ///         .map(|line| line.unwrap())
+    ///         .collect::<Vec<String>>();
+    ///     assert_eq!(lines, ["third", "second", "first"]);
+    ///
+    ///     let lines = data
+    ///         .as_slice()
+    ///         .read_back_lines()
+    ///         .line_ending(LineEnding::Lf)
+    ///         .map(|line| line.unwrap())
+    ///         .collect::<Vec<String>>();
+    ///     assert_eq!(lines, ["third", "first\rsecond\r"]);
+    /// }
+    /// ```
+    pub fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.state.ending = ending;
+        self
+    }
+
+    /// Joins the lines which have been wrapped for display back into the logical line they belong to.
+    ///
+    /// `is_continuation` gets each physical line and decides whether it continues the line in front of it. A
+    /// continuation is appended to the line in front of it without a separator, so the returned iterator yields
+    /// the logical lines, newest first.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::BufReadBack;
+    ///
+    /// fn main() {
+    ///     // lines which have been wrapped start with a space
+    ///     let data = b"first line\nsecond\n line,\n wrapped";
+    ///
+    ///     let lines = data
+    ///         .as_slice()
+    ///         .read_back_lines()
+    ///         .logical(|line| line.starts_with(' '))
+    ///         .map(|line| line.unwrap())
+    ///         .collect::<Vec<String>>();
+    ///
+    ///     assert_eq!(lines, ["second line, wrapped", "first line"]);
+    /// }
+    /// ```
+    pub fn logical<F: FnMut(&str) -> bool>(self, is_continuation: F) -> RevLogicalLines<B, F> {
+        RevLogicalLines {
+            lines: self,
+            is_continuation,
+        }
+    }
+}
+
+impl<B: BufReadBack> Iterator for RevLines<B> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Result<String>> {
+        loop {
+            match self.state.next_line(&mut self.buf)? {
+                Ok(line) if self.skip_empty && line.is_empty() => continue,
+                line => return Some(line),
+            }
+        }
+    }
+}
+
+/// An iterator over the lines of an instance of [`BufReadBack`] together with their line number, counted from the
+/// start of the reader.
+///
+/// This struct is generally created by calling [`read_back_lines_indexed`] on a [`BufReadBack`].
+/// Please see the documentation of [`read_back_lines_indexed`] for more details.
+///
+/// [`read_back_lines_indexed`]: BufReadBack::read_back_lines_indexed
+#[derive(Debug)]
+pub struct RevLinesIndexed<B> {
+    lines: RevLines<B>,
+    /// The line number of the next line, `None` until the lines in front of the reader have been counted.
+    next_number: Option<usize>,
+}
+
+impl<B: BufReadBack + io::Read + Seek> RevLinesIndexed<B> {
+    /// Counts the lines in front of the current position of the reader, the same way as [`BufRead::lines`]
+    /// would split them.
+    ///
+    /// [`BufRead::lines`]: std::io::BufRead::lines
+    fn count_lines(&mut self) -> Result<usize> {
+        let reader = &mut self.lines.buf;
+        let end = reader.stream_position()?;
+        reader.seek(io::SeekFrom::Start(0))?;
+
+        let mut chunk = [0; DEFAULT_BUF_SIZE];
+        let mut newlines = 0;
+        let mut last_byte = None;
+        let mut remaining = end;
+        while remaining > 0 {
+            let max = cmp::min(remaining, chunk.len() as u64) as usize;
+            let amount = match reader.read(&mut chunk[..max]) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    reader.seek(io::SeekFrom::Start(end))?;
+                    return Err(e);
+                }
+            };
+
+            newlines += memchr::memchr_iter(b'\n', &chunk[..amount]).count();
+            last_byte = Some(chunk[amount - 1]);
+            remaining -= amount as u64;
+        }
+        reader.seek(io::SeekFrom::Start(end))?;
+
+        // a line without a newline at the end still counts
+        Ok(match last_byte {
+            Some(b'\n') | None => newlines,
+            Some(_) => newlines + 1,
+        })
+    }
+}
+
+impl<B: BufReadBack + io::Read + Seek> Iterator for RevLinesIndexed<B> {
+    type Item = Result<(usize, String)>;
+
+    fn next(&mut self) -> Option<Result<(usize, String)>> {
+        let next_number = match self.next_number {
+            Some(number) => number,
+            None => match self.count_lines() {
+                Ok(count) => *self.next_number.insert(count),
+                Err(e) => return Some(Err(e)),
+            },
+        };
+
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let number = next_number.saturating_sub(1);
+        self.next_number = Some(number);
+        Some(Ok((number, line)))
+    }
+}
+
+/// An iterator over the logical lines of an instance of [`BufReadBack`], which joins the lines which have been
+/// wrapped for display.
+///
+/// This struct is generally created by calling [`logical`] on a [`RevLines`].
+/// Please see the documentation of [`logical`] for more details.
+///
+/// [`logical`]: RevLines::logical
+#[derive(Debug)]
+pub struct RevLogicalLines<B, F> {
+    lines: RevLines<B>,
+    is_continuation: F,
+}
+
+impl<B: BufReadBack, F: FnMut(&str) -> bool> Iterator for RevLogicalLines<B, F> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Result<String>> {
+        let mut line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e)),
+        };
+
+        // the length of the physical line at the start of `line`
+        let mut physical_len = line.len();
+        while (self.is_continuation)(&line[..physical_len]) {
+            match self.lines.next() {
+                Some(Ok(previous)) => {
+                    physical_len = previous.len();
+                    line.insert_str(0, &previous);
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => break,
+            }
+        }
+
+        Some(Ok(line))
+    }
+}
+
+/// Splits a reader into lines the same way as [`BufRead::lines`] does, just from the back. Other terminators can
+/// be used with `ending`.
+///
+/// [`BufRead::lines`]: std::io::BufRead::lines
+#[derive(Debug, Default)]
+struct LineState {
+    ending: LineEnding,
+    /// Whether the newline at the end of the reader has been checked already.
+    started: bool,
+    /// Whether a newline has been read back, so there's one more line in front of it (which might be empty).
+    pending: bool,
+}
+
+impl LineState {
+    /// Reads back the previous line without its newline, `None` is returned at the start of the reader.
+    fn next_line<B: BufReadBack + ?Sized>(&mut self, r: &mut B) -> Option<Result<String>> {
+        if !self.started {
+            self.started = true;
+            if let Err(e) = self.skip_trailing_newline(r) {
+                return Some(Err(e));
+            }
+        }
+
+        if self.ending != LineEnding::CrLf {
+            return self.next_line_until(r);
+        }
+
+        let mut buf = String::new();
+        match r.read_back_line(&mut buf) {
+            Ok(0) if !self.pending => None,
+            Ok(_n) => {
+                // only a line which has been terminated by a newline has another line in front of it
+                self.pending = buf.starts_with('\n') || buf.starts_with("\r\n");
+                if buf.starts_with('\n') {
+                    buf = buf.drain(1..).collect();
+                } else if buf.starts_with("\r\n") {
+                    buf = buf.drain(2..).collect();
                 }
 
                 Some(Ok(buf))
@@ -614,14 +1971,69 @@ impl<B: BufReadBack> Iterator for RevLines<B> {
             Err(e) => Some(Err(e)),
         }
     }
+
+    /// Reads back the previous line for the line endings other than [`LineEnding::CrLf`].
+    fn next_line_until<B: BufReadBack + ?Sized>(&mut self, r: &mut B) -> Option<Result<String>> {
+        let delims: &[u8] = match self.ending {
+            LineEnding::Any => b"\r\n",
+            LineEnding::Lf | LineEnding::CrLf => b"\n",
+        };
+
+        let mut buf = Vec::new();
+        match r.read_back_until_any(delims, &mut buf) {
+            Ok(0) if !self.pending => None,
+            Ok(_n) => {
+                // only a line which has been terminated has another line in front of it
+                self.pending = buf.first().is_some_and(|byte| delims.contains(byte));
+                if self.pending {
+                    let terminator = buf.remove(0);
+                    if let Err(e) = self.skip_cr(r, terminator) {
+                        return Some(Err(e));
+                    }
+                }
+
+                Some(read_back_bytes_to_string(buf))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// Reads back the `\r` of a `\r\n` if `terminator` is its `\n` and the line ending treats it as one terminator.
+    fn skip_cr<B: BufReadBack + ?Sized>(&self, r: &mut B, terminator: u8) -> Result<()> {
+        if terminator == b'\n'
+            && self.ending != LineEnding::Lf
+            && r.read_back_fill_buf()?.last() == Some(&b'\r')
+        {
+            r.read_back_consume(1);
+        }
+
+        Ok(())
+    }
+
+    /// Reads back the line terminator at the very end of the reader. Like for [`BufRead::lines`], it terminates
+    /// the last line instead of starting an empty one.
+    ///
+    /// [`BufRead::lines`]: std::io::BufRead::lines
+    fn skip_trailing_newline<B: BufReadBack + ?Sized>(&mut self, r: &mut B) -> Result<()> {
+        let terminator = match r.read_back_fill_buf()?.last() {
+            Some(&b'\n') => b'\n',
+            Some(&b'\r') if self.ending == LineEnding::Any => b'\r',
+            _ => return Ok(()),
+        };
+
+        r.read_back_consume(1);
+        self.pending = true;
+        self.skip_cr(r, terminator)
+    }
 }
 
-/// Reader adapter which limits the bytes read from an underlying reader.
+/// Reader adapter which limits the bytes read back from an underlying reader, so it never reads further back than
+/// `limit` bytes from where it started.
 ///
-/// This struct is generally created by calling [`take`] on a reader.
-/// Please see the documentation of [`take`] for more details.
+/// This struct is generally created by calling [`read_back_take`] on a reader.
+/// Please see the documentation of [`read_back_take`] for more details.
 ///
-/// [`take`]: Read::take
+/// [`read_back_take`]: ReadBack::read_back_take
 #[derive(Debug)]
 pub struct ReadBackTake<T> {
     inner: T,
@@ -629,27 +2041,64 @@ pub struct ReadBackTake<T> {
 }
 
 impl<T> ReadBackTake<T> {
+    /// Returns the number of bytes which can still be read back before this instance returns EOF.
+    ///
+    /// This might be more than the bytes which are left in the underlying reader.
     pub fn limit(&self) -> u64 {
         self.limit
     }
 
+    /// Sets the number of bytes which can be read back before this instance returns EOF. This is the same as
+    /// creating a new `ReadBackTake` with this limit.
     pub fn set_limit(&mut self, limit: u64) {
         self.limit = limit;
     }
 
+    /// Consumes the `ReadBackTake`, returning the wrapped reader.
     pub fn into_inner(self) -> T {
         self.inner
     }
 
+    /// Gets a reference to the underlying reader.
     pub fn get_ref(&self) -> &T {
         &self.inner
     }
 
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the underlying reader as doing so may
+    /// corrupt the internal limit of this `ReadBackTake`.
     pub fn get_mut(&mut self) -> &mut T {
         &mut self.inner
     }
 }
 
+impl<T: ReadBack + Seek> ReadBackTake<T> {
+    /// Like [`ReadBack::read_back_to_end_tracked`] but it returns the position of the underlying reader which has
+    /// been reached, which is where the limit stopped reading back.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_collection::ReadBack;
+    ///
+    /// fn main() {
+    ///     let data = b"Hello there!";
+    ///     let mut cursor = Cursor::new(data.as_slice());
+    ///     cursor.set_position(data.len() as u64);
+    ///
+    ///     let mut take = cursor.read_back_take(6);
+    ///     let mut buffer = Vec::new();
+    ///     assert_eq!(take.read_back_to_end_tracked(&mut buffer).ok(), Some((6, 6)));
+    ///     assert_eq!(&buffer, b"there!");
+    /// }
+    /// ```
+    pub fn read_back_to_end_tracked(&mut self, buf: &mut Vec<u8>) -> Result<(usize, u64)> {
+        let amount = self.read_back_to_end(buf)?;
+        Ok((amount, self.inner.stream_position()?))
+    }
+}
+
 impl<T: ReadBack> ReadBack for ReadBackTake<T> {
     fn read_back(&mut self, buf: &mut [u8]) -> Result<usize> {
         // Don't call into inner reader at all at EOF because it may still block
@@ -694,6 +2143,7 @@ pub fn default_read_back_vectored<F: FnOnce(&mut [u8]) -> Result<usize>>(
 ) -> Result<usize> {
     let buf = bufs
         .iter_mut()
+        .rev()
         .find(|b| !b.is_empty())
         .map_or(&mut [][..], |b| &mut **b);
 
@@ -712,25 +2162,19 @@ pub fn default_read_back_to_end<R: ReadBack + ?Sized>(
     loop {
         match reader.read_back(curr_buffer.as_mut_slice()) {
             Ok(amount) => {
-                println!("{}", amount);
                 if amount == 0 {
-                    let mut final_buf = Vec::with_capacity(amount_read + dest_buf.len());
-
+                    // the chunks have been read back from the end, so the last one is the first of the source
+                    dest_buf
+                        .try_reserve(amount_read)
+                        .map_err(|_| ErrorKind::OutOfMemory)?;
                     for buffer in buffers.into_iter().rev() {
-                        final_buf.extend_from_slice(&buffer);
+                        dest_buf.extend_from_slice(&buffer);
                     }
-                    final_buf.extend_from_slice(dest_buf);
-                    *dest_buf = final_buf;
 
                     return Ok(amount_read);
                 }
-                curr_buffer = {
-                    let curr_buffer_len = curr_buffer.len();
-                    curr_buffer[curr_buffer_len - amount..].to_vec()
-                };
                 amount_read += amount;
-                buffers.push(curr_buffer);
-                curr_buffer = Vec::new();
+                buffers.push(curr_buffer[..amount].to_vec());
             }
             Err(e) if e.kind() == ErrorKind::Interrupted => continue,
             Err(e) => return Err(e),
@@ -742,25 +2186,240 @@ fn default_read_back_to_string<R: ReadBack + ?Sized>(r: &mut R, buf: &mut String
     let mut bytes_buf = Vec::new();
     let amount_bytes = default_read_back_to_end(r, &mut bytes_buf)?;
 
-    let mut read_back_string = String::from_utf8(bytes_buf).map_err(|e| {
-        std::io::Error::new(
+    buf.push_str(&read_back_bytes_to_string(bytes_buf)?);
+
+    Ok(amount_bytes)
+}
+
+/// Reads back the last `record.len()` bytes into `record` in forward order. Returns `false` if there weren't any
+/// bytes left and an [`ErrorKind::InvalidData`] error if there were less than `record.len()` bytes left.
+pub(crate) fn read_back_fixed_record<R: ReadBack + ?Sized>(
+    r: &mut R,
+    record: &mut [u8],
+) -> Result<bool> {
+    // the bytes are read back in front of the ones which have been read back already
+    let mut remaining = record.len();
+    while remaining > 0 {
+        match r.read_back(&mut record[..remaining]) {
+            Ok(0) => break,
+            Ok(amount) => {
+                record.copy_within(..amount, remaining - amount);
+                remaining -= amount;
+            }
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    match remaining {
+        0 => Ok(true),
+        _ if remaining == record.len() => Ok(false),
+        _ => Err(io::Error::new(
             ErrorKind::InvalidData,
-            format!("Couldn't convert the rev-reader to a string: {}", e),
-        )
-    })?;
+            format!(
+                "source ends with a partial record of {} bytes instead of {}",
+                record.len() - remaining,
+                record.len()
+            ),
+        )),
+    }
+}
+
+fn default_read_back_to_string_trim_bom<R: ReadBack + ?Sized>(
+    r: &mut R,
+    buf: &mut String,
+) -> Result<usize> {
+    let mut bytes_buf = Vec::new();
+    let amount_bytes = r.read_back_to_end(&mut bytes_buf)?;
 
-    read_back_string.push_str(buf);
-    *buf = read_back_string;
+    if bytes_buf.starts_with(UTF8_BOM) {
+        bytes_buf.drain(..UTF8_BOM.len());
+    }
+    buf.push_str(&read_back_bytes_to_string(bytes_buf)?);
 
     Ok(amount_bytes)
 }
 
+/// The byte order mark of UTF-8.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// The error for sources which can't be read back directly, because they can't seek (like pipes).
+pub(crate) fn unsupported_source_error() -> io::Error {
+    io::Error::new(
+        ErrorKind::Unsupported,
+        "source can't be read back since it isn't seekable, \
+         use `ReadBackSlurp::from_reader_buffered` to read it back from memory instead",
+    )
+}
+
+/// The error if there are less bytes in front of the reader than the footer which should be skipped.
+pub(crate) fn footer_too_short_error() -> io::Error {
+    io::Error::new(
+        ErrorKind::UnexpectedEof,
+        "source is shorter than the footer which should be skipped",
+    )
+}
+
+/// Converts the bytes which have been read back until the start of the source into a string.
+///
+/// Since the start of the source is the only place where reading back can begin in the middle of a
+/// code point (for example a sliced file), this case gets its own error message.
+pub(crate) fn read_back_bytes_to_string(bytes: Vec<u8>) -> Result<String> {
+    // The SIMD validation only tells whether the bytes are valid, so invalid bytes still go through the std
+    // validation to get the details for the error message.
+    #[cfg(feature = "simdutf8")]
+    if simdutf8::basic::from_utf8(&bytes).is_ok() {
+        // SAFETY: the bytes have just been validated
+        return Ok(unsafe { String::from_utf8_unchecked(bytes) });
+    }
+
+    String::from_utf8(bytes).map_err(|err| {
+        let utf8_err = err.utf8_error();
+        let bytes = err.as_bytes();
+        let starts_with_continuation_byte = bytes.first().is_some_and(|&b| (b & 0xC0) == 0x80);
+
+        let msg = if utf8_err.valid_up_to() == 0 && starts_with_continuation_byte {
+            "stream is truncated at the front: it starts in the middle of a UTF-8 sequence"
+                .to_string()
+        } else {
+            format!(
+                "stream contains an invalid UTF-8 byte at offset {}",
+                utf8_err.valid_up_to()
+            )
+        };
+
+        io::Error::new(ErrorKind::InvalidData, msg)
+    })
+}
+
+fn default_read_back_count_bytes<R: ReadBack + ?Sized>(r: &mut R) -> Result<u64> {
+    let mut buffer = [0; DEFAULT_BUF_SIZE];
+    let mut amount_read: u64 = 0;
+
+    loop {
+        match r.read_back(&mut buffer) {
+            Ok(0) => return Ok(amount_read),
+            Ok(n) => amount_read += n as u64,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn default_read_back_skip_footer<R: ReadBack + ?Sized>(r: &mut R, mut n: usize) -> Result<()> {
+    let mut buffer = [0; DEFAULT_BUF_SIZE];
+
+    while n > 0 {
+        let max = cmp::min(n, buffer.len());
+        match r.read_back(&mut buffer[..max]) {
+            Ok(0) => return Err(footer_too_short_error()),
+            Ok(amount) => n -= amount,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+fn default_read_back_varint_suffixed_record<R: ReadBack + ?Sized>(
+    r: &mut R,
+) -> Result<Option<Vec<u8>>> {
+    let mut length: u64 = 0;
+    let mut shift = 0;
+
+    loop {
+        let mut byte = [0];
+        match r.read_back(&mut byte) {
+            Ok(0) if shift == 0 => return Ok(None),
+            Ok(0) => {
+                return Err(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "source starts in the middle of a varint",
+                ))
+            }
+            Ok(_) => {}
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+
+        let value = u64::from(byte[0] & 0x7F);
+        if shift >= u64::BITS || (value << shift) >> shift != value {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "varint is too large for a u64",
+            ));
+        }
+        length |= value << shift;
+        shift += 7;
+
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+    }
+
+    // read in chunks, so a corrupt length can't allocate more than the source contains
+    let mut chunks = Vec::new();
+    let mut remaining = length;
+    while remaining > 0 {
+        let chunk_len = cmp::min(remaining, DEFAULT_BUF_SIZE as u64) as usize;
+        let mut chunk = vec![0; chunk_len];
+        r.read_back_exact(&mut chunk)?;
+
+        chunks.push(chunk);
+        remaining -= chunk_len as u64;
+    }
+
+    Ok(Some(chunks.into_iter().rev().flatten().collect()))
+}
+
+fn default_read_back_buf<R: ReadBack + ?Sized>(
+    r: &mut R,
+    mut cursor: RevBorrowedCursor<'_>,
+) -> Result<()> {
+    // SAFETY: the cursor has just been initialized completely and nothing gets uninitialized
+    let buf = unsafe { cursor.ensure_init().as_mut().assume_init_mut() };
+    let n = r.read_back(buf)?;
+
+    // `read_back` fills the front of `buf`, but the filled part of the cursor grows from the end
+    let buf_len = buf.len();
+    buf.copy_within(..n, buf_len - n);
+    cursor.advance(n);
+
+    Ok(())
+}
+
+fn default_read_back_buf_exact<R: ReadBack + ?Sized>(
+    r: &mut R,
+    mut cursor: RevBorrowedCursor<'_>,
+) -> Result<()> {
+    while cursor.capacity() > 0 {
+        let prev_written = cursor.written();
+        match r.read_back_buf(cursor.reborrow()) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+
+        if cursor.written() == prev_written {
+            return Err(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn default_read_back_exact<R: ReadBack + ?Sized>(r: &mut R, mut buf: &mut [u8]) -> Result<()> {
     while !buf.is_empty() {
         match r.read_back(buf) {
             Ok(0) => break,
             Ok(n) => {
+                // the read bytes are the last ones of `buf`
                 let buf_len = buf.len();
+                buf.copy_within(..n, buf_len - n);
                 buf = &mut buf[..buf_len - n];
             }
             Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
@@ -771,19 +2430,21 @@ fn default_read_back_exact<R: ReadBack + ?Sized>(r: &mut R, mut buf: &mut [u8])
     if !buf.is_empty() {
         Err(std::io::Error::new(
             ErrorKind::UnexpectedEof,
-            "Failed to fill whole buffer.",
+            "failed to fill whole buffer",
         ))
     } else {
         Ok(())
     }
 }
 
-fn default_buf_read_back_until<R: BufReadBack + ?Sized>(
+pub(crate) fn default_buf_read_back_until<R: BufReadBack + ?Sized>(
     r: &mut R,
     delim: u8,
     buf: &mut Vec<u8>,
 ) -> io::Result<usize> {
-    let mut amount_read = 0;
+    let start = buf.len();
+    // whether the chunks have been collected in reversed order and need to be turned around at the end
+    let mut reversed = false;
 
     loop {
         let (done, used) = {
@@ -792,36 +2453,158 @@ fn default_buf_read_back_until<R: BufReadBack + ?Sized>(
                 Err(err) if err.kind() == ErrorKind::Interrupted => continue,
                 Err(err) => return Err(err),
             };
-            match memchr::memrchr(delim, new_read) {
-                Some(index) => {
-                    let used = new_read.len() - index;
-
-                    let mut new_buf = Vec::with_capacity(buf.len() + used);
-                    new_buf.extend_from_slice(&new_read[index..]);
-                    new_buf.extend_from_slice(buf);
-                    *buf = new_buf;
-
-                    (true, used)
-                }
-                None => {
-                    let mut new_buf = Vec::with_capacity(buf.len() + new_read.len());
-                    new_buf.extend_from_slice(new_read);
-                    new_buf.extend_from_slice(buf);
-                    *buf = new_buf;
+            let (done, chunk) = match memchr::memrchr(delim, new_read) {
+                Some(index) => (true, &new_read[index..]),
+                None => (false, new_read),
+            };
 
-                    (false, new_read.len())
-                }
+            if done && !reversed {
+                // the whole record is in one buffer, so it can be copied as it is
+                buf.extend_from_slice(chunk);
+            } else {
+                // the chunks are read from back to front
+                buf.extend(chunk.iter().rev());
+                reversed = true;
             }
+
+            (done, chunk.len())
         };
 
         r.read_back_consume(used);
-        amount_read += used;
         if done || used == 0 {
-            return Ok(amount_read);
+            if reversed {
+                buf[start..].reverse();
+            }
+            return Ok(buf.len() - start);
         }
     }
 }
 
+/// Reads back the bytes in front of `end` into `bufs` with a single [`Read::read_vectored`] per attempt, for
+/// sources which read back by seeking.
+///
+/// The buffers are filled as described in [`ReadBack::read_back_vectored`]. Afterwards `reader` is positioned in
+/// front of the bytes which have been read, or at `end` again if an error occurred.
+///
+/// [`Read::read_vectored`]: std::io::Read::read_vectored
+pub(crate) fn seek_read_back_vectored<R: Read + Seek + ?Sized>(
+    reader: &mut R,
+    end: u64,
+    bufs: &mut [IoSliceMut<'_>],
+) -> Result<usize> {
+    let total = bufs.iter().map(|b| b.len() as u64).sum::<u64>();
+    let amount = cmp::min(end, total) as usize;
+    let start = end - amount as u64;
+
+    // The parts of the buffers which get the bytes, in the order of the source.
+    let mut remaining = amount;
+    let mut targets = Vec::new();
+    for buf in bufs.iter_mut().rev() {
+        if remaining == 0 {
+            break;
+        }
+
+        let len = cmp::min(buf.len(), remaining);
+        remaining -= len;
+        if len > 0 {
+            targets.push(IoSliceMut::new(&mut buf[..len]));
+        }
+    }
+    targets.reverse();
+
+    reader.seek(SeekFrom::Start(start))?;
+    let mut left = &mut targets[..];
+    while !left.is_empty() {
+        match reader.read_vectored(left) {
+            Ok(0) => {
+                reader.seek(SeekFrom::Start(end))?;
+                return Err(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "source ended before the read back position",
+                ));
+            }
+            Ok(n) => IoSliceMut::advance_slices(&mut left, n),
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => {
+                reader.seek(SeekFrom::Start(end))?;
+                return Err(e);
+            }
+        }
+    }
+
+    reader.seek(SeekFrom::Start(start))?;
+    Ok(amount)
+}
+
+/// Returns the index of the last byte in `haystack` which is one of `delims`.
+fn memrchr_any(delims: &[u8], haystack: &[u8]) -> Option<usize> {
+    match *delims {
+        [] => None,
+        [a] => memchr::memrchr(a, haystack),
+        [a, b] => memchr::memrchr2(a, b, haystack),
+        [a, b, c] => memchr::memrchr3(a, b, c, haystack),
+        _ => {
+            let mut set = [0u64; 4];
+            for &delim in delims {
+                set[usize::from(delim >> 6)] |= 1 << (delim & 63);
+            }
+
+            haystack
+                .iter()
+                .rposition(|&byte| set[usize::from(byte >> 6)] & (1 << (byte & 63)) != 0)
+        }
+    }
+}
+
+fn default_buf_read_back_until_any_limited<R: BufReadBack + ?Sized>(
+    r: &mut R,
+    delims: &[u8],
+    buf: &mut Vec<u8>,
+    max: usize,
+) -> io::Result<RevUntilStop> {
+    let start = buf.len();
+
+    let stop = loop {
+        if buf.len() - start == max {
+            break RevUntilStop::Limit;
+        }
+
+        let (stop, used) = {
+            let available = match r.read_back_fill_buf() {
+                Ok(n) => n,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if available.is_empty() {
+                break RevUntilStop::Front;
+            }
+
+            let window = &available
+                [available.len() - cmp::min(available.len(), max - (buf.len() - start))..];
+            let (stop, chunk) = match memrchr_any(delims, window) {
+                Some(index) => (
+                    Some(RevUntilStop::Delimiter(window[index])),
+                    &window[index..],
+                ),
+                None => (None, window),
+            };
+            // the chunks are read from back to front, so they're collected reversed and turned around at the end
+            buf.extend(chunk.iter().rev());
+
+            (stop, chunk.len())
+        };
+
+        r.read_back_consume(used);
+        if let Some(stop) = stop {
+            break stop;
+        }
+    };
+
+    buf[start..].reverse();
+
+    Ok(stop)
+}
+
 fn default_buf_read_skip_until<R: BufReadBack + ?Sized>(r: &mut R, delim: u8) -> Result<usize> {
     let mut amount_read: usize = 0;
 
@@ -847,33 +2630,154 @@ fn default_buf_read_skip_until<R: BufReadBack + ?Sized>(r: &mut R, delim: u8) ->
     }
 }
 
-fn default_buf_read_back_line<R: BufReadBack + ?Sized>(
+fn default_buf_read_back_last_records<R: BufReadBack + ?Sized>(
     r: &mut R,
-    dest: &mut String,
-) -> io::Result<usize> {
-    let mut buffer = Vec::with_capacity(crate::DEFAULT_BUF_SIZE);
+    k: usize,
+    delim: u8,
+) -> io::Result<Vec<Vec<u8>>> {
+    // `k` is only an upper bound, so the records aren't preallocated
+    let mut records = Vec::new();
+    if k == 0 {
+        return Ok(records);
+    }
 
-    let mut amount_read = r.read_back_until(b'\n', &mut buffer)?;
-    if r.read_back_fill_buf()?
-        .last()
-        .map(|&c| c == b'\r')
-        .unwrap_or(false)
-    {
-        let mut new_buf = Vec::with_capacity(buffer.len() + 1);
-        new_buf.push(b'\r');
-        new_buf.extend_from_slice(&buffer);
-        buffer = new_buf;
-        amount_read += 1;
+    // the delimiter at the very end belongs to the last record
+    if r.read_back_fill_buf()?.last() == Some(&delim) {
         r.read_back_consume(1);
     }
 
-    match String::from_utf8(buffer) {
-        Ok(mut line) => {
-            line.push_str(dest);
-            *dest = line;
+    while records.len() < k {
+        let mut record = Vec::new();
+        if r.read_back_until(delim, &mut record)? == 0 {
+            break;
+        }
 
-            Ok(amount_read)
+        if record.first() == Some(&delim) {
+            record.remove(0);
         }
-        Err(err) => Err(io::Error::new(ErrorKind::InvalidData, err)),
+        records.push(record);
     }
+
+    records.reverse();
+    Ok(records)
+}
+
+fn default_buf_read_back_index_records<R: BufReadBack + ?Sized>(
+    r: &mut R,
+    delim: u8,
+) -> io::Result<Vec<(u64, u64)>> {
+    // The offsets are counted from the end of the reader first since its length isn't known until the start
+    // of it has been reached.
+    let mut records = Vec::new();
+    let mut scanned: u64 = 0;
+
+    // the delimiter at the very end belongs to the last record
+    if r.read_back_fill_buf()?.last() == Some(&delim) {
+        r.read_back_consume(1);
+        scanned = 1;
+    }
+    let mut record_end = scanned;
+
+    loop {
+        let used = {
+            let new_read = match r.read_back_fill_buf() {
+                Ok(n) => n,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+
+            for index in memchr::memrchr_iter(delim, new_read) {
+                let delim_end = scanned + (new_read.len() - index) as u64;
+
+                records.push((delim_end - 1, record_end));
+                record_end = delim_end;
+            }
+
+            new_read.len()
+        };
+
+        if used == 0 {
+            break;
+        }
+        r.read_back_consume(used);
+        scanned += used as u64;
+    }
+
+    if scanned > 0 {
+        records.push((scanned, record_end));
+    }
+
+    Ok(records
+        .into_iter()
+        .map(|(start, end)| (scanned - start, scanned - end))
+        .collect())
+}
+
+fn default_buf_read_back_find_with_context<R, P>(
+    r: &mut R,
+    mut pred: P,
+    before: usize,
+    after: usize,
+) -> io::Result<Option<Vec<String>>>
+where
+    R: BufReadBack + ?Sized,
+    P: FnMut(&str) -> bool,
+{
+    let mut lines = LineState::default();
+    // the last `after` lines which have been read back, the one which has been read back last is at the front.
+    // `before` and `after` are only upper bounds, so nothing is preallocated from them.
+    let mut newer_lines: VecDeque<String> = VecDeque::new();
+
+    loop {
+        let line = match lines.next_line(r) {
+            Some(line) => line?,
+            None => return Ok(None),
+        };
+
+        if pred(&line) {
+            let mut context = Vec::new();
+            for _ in 0..before {
+                match lines.next_line(r) {
+                    Some(line) => context.push(line?),
+                    None => break,
+                }
+            }
+            context.reverse();
+            context.push(line);
+            context.extend(newer_lines);
+
+            return Ok(Some(context));
+        }
+
+        if after > 0 {
+            if newer_lines.len() == after {
+                newer_lines.pop_back();
+            }
+            newer_lines.push_front(line);
+        }
+    }
+}
+
+/// Reads back the bytes of the last line for [`BufReadBack::read_back_line`], including the `\r` of a leading
+/// `\r\n`.
+fn read_back_line_bytes<R: BufReadBack + ?Sized>(r: &mut R) -> io::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+
+    r.read_back_until(b'\n', &mut buffer)?;
+    if buffer.first() == Some(&b'\n') && r.read_back_fill_buf()?.last() == Some(&b'\r') {
+        buffer.insert(0, b'\r');
+        r.read_back_consume(1);
+    }
+
+    Ok(buffer)
+}
+
+fn default_buf_read_back_line<R: BufReadBack + ?Sized>(
+    r: &mut R,
+    dest: &mut String,
+) -> io::Result<usize> {
+    let buffer = read_back_line_bytes(r)?;
+    let amount_read = buffer.len();
+    dest.push_str(&read_back_bytes_to_string(buffer)?);
+    Ok(amount_read)
 }
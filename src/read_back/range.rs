@@ -0,0 +1,208 @@
+use std::{cmp, fmt, io, ops::Range};
+
+use crate::{BufReadBack, ReadBack, DEFAULT_BUF_SIZE};
+
+/// A [`BufReadBack`]er for remote resources which can be fetched in byte ranges, like files behind HTTP
+/// `Range` requests.
+///
+/// Each time the fetched bytes have been read back, the range in front of them is requested. `fetch` may
+/// return fewer bytes than requested (like a partial response): those are the first bytes of the range, so the
+/// remaining end of the range gets requested again until the whole range has been fetched.
+///
+/// # Example
+/// ```
+/// use read_collection::{BufReadBack, RevRangeReader};
+///
+/// fn main() {
+///     let remote = b"first\nsecond\nthird";
+///
+///     let mut reader = RevRangeReader::new(remote.len() as u64, |range| {
+///         Ok(remote[range.start as usize..range.end as usize].to_vec())
+///     });
+///
+///     let mut line = String::new();
+///     reader.read_back_line(&mut line).unwrap();
+///     assert_eq!(line, "\nthird");
+/// }
+/// ```
+pub struct RevRangeReader<F> {
+    fetch: F,
+    chunk_size: u64,
+    /// The offset of the resource until which it hasn't been fetched yet.
+    pos: u64,
+    /// The last fetched range.
+    buf: Vec<u8>,
+    /// The index (exclusively) until which `buf` hasn't been read back yet.
+    buf_pos: usize,
+}
+
+impl<F: FnMut(Range<u64>) -> io::Result<Vec<u8>>> RevRangeReader<F> {
+    /// Creates a new `RevRangeReader` for a resource with `len` bytes (for example the `Content-Length`) which
+    /// requests 8 KiB at once.
+    pub fn new(len: u64, fetch: F) -> Self {
+        Self::with_chunk_size(DEFAULT_BUF_SIZE as u64, len, fetch)
+    }
+
+    /// Creates a new `RevRangeReader` for a resource with `len` bytes which requests `chunk_size` bytes at once.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is `0`.
+    pub fn with_chunk_size(chunk_size: u64, len: u64, fetch: F) -> Self {
+        assert!(chunk_size > 0, "chunk size has to be greater than 0");
+
+        Self {
+            fetch,
+            chunk_size,
+            pos: len,
+            buf: Vec::new(),
+            buf_pos: 0,
+        }
+    }
+
+    /// Returns the offset of the resource until which it has been read back.
+    pub fn position(&self) -> u64 {
+        self.pos + self.buf_pos as u64
+    }
+
+    /// Fetches the range in front of the already fetched bytes.
+    fn fetch_prev_range(&mut self) -> io::Result<()> {
+        let start = self.pos - cmp::min(self.pos, self.chunk_size);
+        let end = self.pos;
+
+        self.buf.clear();
+        while start + (self.buf.len() as u64) < end {
+            let missing = start + self.buf.len() as u64..end;
+            let missing_len = missing.end - missing.start;
+
+            let fetched = (self.fetch)(missing)?;
+            if fetched.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "fetching a range returned no bytes",
+                ));
+            } else if fetched.len() as u64 > missing_len {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "fetching a range returned more bytes than requested",
+                ));
+            }
+
+            self.buf.extend_from_slice(&fetched);
+        }
+
+        self.buf_pos = self.buf.len();
+        self.pos = start;
+
+        Ok(())
+    }
+}
+
+impl<F> fmt::Debug for RevRangeReader<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevRangeReader")
+            .field("chunk_size", &self.chunk_size)
+            .field("pos", &self.pos)
+            .field("buf_pos", &self.buf_pos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: FnMut(Range<u64>) -> io::Result<Vec<u8>>> ReadBack for RevRangeReader<F> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.read_back_fill_buf()?.read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+}
+
+impl<F: FnMut(Range<u64>) -> io::Result<Vec<u8>>> BufReadBack for RevRangeReader<F> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.buf_pos == 0 && self.pos > 0 {
+            self.fetch_prev_range()?;
+        }
+
+        Ok(&self.buf[..self.buf_pos])
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.buf_pos = self.buf_pos.saturating_sub(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    const BLOB: &[u8] = b"0123456789";
+
+    /// The `(start, end)` pairs of the requested ranges.
+    type Requests = Rc<RefCell<Vec<(u64, u64)>>>;
+
+    /// Returns a `fetch` function over `BLOB` which records the requested ranges and returns at most
+    /// `max_response` bytes per request.
+    fn mock_fetch(
+        max_response: usize,
+    ) -> (impl FnMut(Range<u64>) -> io::Result<Vec<u8>>, Requests) {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+
+        let fetch = {
+            let requests = Rc::clone(&requests);
+            move |range: Range<u64>| {
+                requests.borrow_mut().push((range.start, range.end));
+
+                let end = cmp::min(
+                    range.end as usize,
+                    (range.start as usize).saturating_add(max_response),
+                );
+                Ok(BLOB[range.start as usize..end].to_vec())
+            }
+        };
+
+        (fetch, requests)
+    }
+
+    #[test]
+    fn requested_ranges() {
+        let (fetch, requests) = mock_fetch(usize::MAX);
+        let mut reader = RevRangeReader::with_chunk_size(4, BLOB.len() as u64, fetch);
+
+        let mut content = Vec::new();
+        assert_eq!(reader.read_back_to_end(&mut content).ok(), Some(10));
+        assert_eq!(content, BLOB);
+        assert_eq!(*requests.borrow(), [(6, 10), (2, 6), (0, 2)]);
+    }
+
+    #[test]
+    fn contiguous_reads_are_served_from_cache() {
+        let (fetch, requests) = mock_fetch(usize::MAX);
+        let mut reader = RevRangeReader::with_chunk_size(4, BLOB.len() as u64, fetch);
+
+        let mut buffer = [0; 1];
+        for expected in *b"9876" {
+            reader.read_back_exact(&mut buffer).unwrap();
+            assert_eq!(buffer, [expected]);
+        }
+
+        assert_eq!(*requests.borrow(), [(6, 10)]);
+        assert_eq!(reader.position(), 6);
+    }
+
+    #[test]
+    fn partial_responses() {
+        let (fetch, requests) = mock_fetch(3);
+        let mut reader = RevRangeReader::with_chunk_size(5, BLOB.len() as u64, fetch);
+
+        assert_eq!(reader.read_back_fill_buf().ok(), Some(b"56789".as_slice()));
+        assert_eq!(*requests.borrow(), [(5, 10), (8, 10)]);
+    }
+
+    #[test]
+    fn empty_response() {
+        let mut reader = RevRangeReader::new(BLOB.len() as u64, |_| Ok(Vec::new()));
+
+        let err = reader.read_back_fill_buf().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,271 @@
+use std::{borrow::Cow, io};
+
+use crate::BufReadBack;
+
+/// Reads back the records of an instance of [`BufReadBack`] which are terminated by a delimiter, newest record
+/// first, without copying them if possible.
+///
+/// The records are split like the ones of [`BufRead::split`]: the delimiters aren't part of the records and a
+/// delimiter at the very end terminates the last record instead of starting an empty one.
+///
+/// A record which is within the buffer of the reader is borrowed from it. This isn't possible for a record
+/// which spans over multiple refills of the buffer, it's copied instead. Since the start of the oldest record is
+/// only known once the start of the reader has been reached, it's always copied as well.
+///
+/// This struct is generally created by calling [`read_back_records`] on a [`BufReadBack`].
+///
+/// # Example
+/// ```
+/// use std::borrow::Cow;
+/// use read_collection::BufReadBack;
+///
+/// fn main() {
+///     let mut records = b"first,second,third,".as_slice().read_back_records(b',');
+///
+///     assert_eq!(records.next_record().unwrap(), Some(Cow::Borrowed(b"third".as_slice())));
+///     assert_eq!(records.next_record().unwrap(), Some(Cow::Borrowed(b"second".as_slice())));
+///     assert_eq!(records.next_record().unwrap(), Some(Cow::Owned(b"first".to_vec())));
+///     assert_eq!(records.next_record().unwrap(), None);
+/// }
+/// ```
+///
+/// [`BufRead::split`]: std::io::BufRead::split
+/// [`read_back_records`]: BufReadBack::read_back_records
+#[derive(Debug)]
+pub struct RevRecords<B> {
+    buf: B,
+    delim: u8,
+    /// The amount of bytes of the last borrowed record (and its delimiter) which still have to be consumed.
+    unconsumed: usize,
+    /// Whether the delimiter at the end of the reader has been checked already.
+    started: bool,
+    /// Whether a delimiter has been read back, so there's one more record in front of it (which might be empty).
+    pending: bool,
+}
+
+impl<B: BufReadBack> RevRecords<B> {
+    pub(crate) fn new(buf: B, delim: u8) -> Self {
+        Self {
+            buf,
+            delim,
+            unconsumed: 0,
+            started: false,
+            pending: false,
+        }
+    }
+
+    /// Reads back the previous record, `None` is returned at the start of the reader.
+    pub fn next_record(&mut self) -> io::Result<Option<Cow<'_, [u8]>>> {
+        self.consume_last_record();
+
+        if !self.started {
+            let ends_with_delim = loop {
+                match self.buf.read_back_fill_buf() {
+                    Ok(last) => break last.last() == Some(&self.delim),
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(err) => return Err(err),
+                }
+            };
+            self.started = true;
+            if ends_with_delim {
+                self.buf.read_back_consume(1);
+                self.pending = true;
+            }
+        }
+
+        // the parts of the record which have spanned over previous refills, the oldest one is at the end
+        let mut chunks: Option<Vec<Vec<u8>>> = None;
+        loop {
+            let new_read = match self.buf.read_back_fill_buf() {
+                Ok(n) => n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+
+            if new_read.is_empty() {
+                // the start of the reader has been reached, so this is the oldest record
+                let pending = std::mem::replace(&mut self.pending, false);
+                return Ok(match chunks {
+                    Some(chunks) => Some(Cow::Owned(concat_chunks(chunks))),
+                    None if pending => Some(Cow::Owned(Vec::new())),
+                    None => None,
+                });
+            }
+
+            let (used, found) = match memchr::memrchr(self.delim, new_read) {
+                Some(index) => (new_read.len() - index, true),
+                None => (new_read.len(), false),
+            };
+
+            match chunks.as_mut() {
+                // the buffer is still filled, so this doesn't refill it
+                None if found => {
+                    self.pending = true;
+                    self.unconsumed = used;
+
+                    let new_read = self.buf.read_back_fill_buf()?;
+                    return Ok(Some(Cow::Borrowed(&new_read[new_read.len() - used + 1..])));
+                }
+                _ => {
+                    let skip = if found { 1 } else { 0 };
+                    let new_read = &new_read[new_read.len() - used + skip..];
+                    chunks.get_or_insert_with(Vec::new).push(new_read.to_vec());
+
+                    self.buf.read_back_consume(used);
+                    if found {
+                        self.pending = true;
+                        return Ok(chunks.map(|chunks| Cow::Owned(concat_chunks(chunks))));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Unwraps this `RevRecords`, returning the underlying reader.
+    ///
+    /// The reader is right in front of the last returned record.
+    pub fn into_inner(mut self) -> B {
+        self.consume_last_record();
+        self.buf
+    }
+
+    /// Consumes the last borrowed record, which couldn't be consumed while it was borrowed.
+    fn consume_last_record(&mut self) {
+        if self.unconsumed > 0 {
+            self.buf.read_back_consume(self.unconsumed);
+            self.unconsumed = 0;
+        }
+    }
+}
+
+/// Concatenates the parts of a record which have been read back from the newest to the oldest one.
+fn concat_chunks(chunks: Vec<Vec<u8>>) -> Vec<u8> {
+    chunks.into_iter().rev().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReadBackSlurp;
+    use std::io::BufRead;
+
+    /// A reader which hands out at most `chunk` bytes per refill and is interrupted on the first `interrupts`
+    /// refills.
+    struct Chunked<'a> {
+        data: &'a [u8],
+        chunk: usize,
+        interrupts: usize,
+    }
+
+    impl crate::ReadBack for Chunked<'_> {
+        fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let amount = self.read_back_fill_buf()?.read_back(buf)?;
+            self.read_back_consume(amount);
+            Ok(amount)
+        }
+    }
+
+    impl BufReadBack for Chunked<'_> {
+        fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+            if self.interrupts > 0 {
+                self.interrupts -= 1;
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+
+            let start = self.data.len().saturating_sub(self.chunk);
+            Ok(&self.data[start..])
+        }
+
+        fn read_back_consume(&mut self, amt: usize) {
+            self.data.read_back_consume(amt);
+        }
+    }
+
+    #[test]
+    fn borrowed() {
+        let mut records = ReadBackSlurp::from(b"a\nbb\nccc\n".to_vec()).read_back_records(b'\n');
+
+        let record = records.next_record().unwrap().unwrap();
+        assert!(matches!(record, Cow::Borrowed(b"ccc")));
+        let record = records.next_record().unwrap().unwrap();
+        assert!(matches!(record, Cow::Borrowed(b"bb")));
+
+        assert_eq!(records.into_inner().get_ref(), b"a");
+    }
+
+    #[test]
+    fn owned_when_spanning_refills() {
+        let data = b"first\nsecond-record\n";
+        let mut records = Chunked {
+            data,
+            chunk: 4,
+            interrupts: 0,
+        }
+        .read_back_records(b'\n');
+
+        let record = records.next_record().unwrap().unwrap();
+        assert!(matches!(record, Cow::Owned(_)));
+        assert_eq!(&*record, b"second-record");
+
+        let record = records.next_record().unwrap().unwrap();
+        assert!(matches!(record, Cow::Owned(_)));
+        assert_eq!(&*record, b"first");
+    }
+
+    #[test]
+    fn interrupted_first_refill() {
+        let mut records = Chunked {
+            data: b"a\nbb\n",
+            chunk: 2,
+            interrupts: 1,
+        }
+        .read_back_records(b'\n');
+
+        assert_eq!(
+            records.next_record().unwrap().as_deref(),
+            Some(b"bb".as_slice())
+        );
+        assert_eq!(
+            records.next_record().unwrap().as_deref(),
+            Some(b"a".as_slice())
+        );
+        assert_eq!(records.next_record().unwrap(), None);
+    }
+
+    #[test]
+    fn terminal() {
+        let mut records = b"".as_slice().read_back_records(b'\n');
+        assert_eq!(records.next_record().unwrap(), None);
+
+        let mut records = b"\n".as_slice().read_back_records(b'\n');
+        assert_eq!(records.next_record().unwrap(), Some(Cow::Owned(Vec::new())));
+        assert_eq!(records.next_record().unwrap(), None);
+        assert_eq!(records.next_record().unwrap(), None);
+    }
+
+    #[test]
+    fn same_as_split() {
+        let inputs: [&[u8]; 6] = [b"a", b"a,", b",a", b"a,,b", b",,", b"ab,cd,ef,"];
+
+        for data in inputs {
+            for chunk in 1..4 {
+                let mut records = Chunked {
+                    data,
+                    chunk,
+                    interrupts: 0,
+                }
+                .read_back_records(b',');
+                let mut back = Vec::new();
+                while let Some(record) = records.next_record().unwrap() {
+                    back.push(record.into_owned());
+                }
+                back.reverse();
+
+                let forward = BufRead::split(data, b',')
+                    .collect::<io::Result<Vec<Vec<u8>>>>()
+                    .unwrap();
+                assert_eq!(back, forward, "data: {:?}, chunk: {}", data, chunk);
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::{cmp, io};
+
+use crate::{read_back::footer_too_short_error, BufReadBack, ReadBack};
+
+/// A [`ReadBack`]er over any byte-like container, like a [`String`], a [`Vec<u8>`] or a `[u8; N]`.
+///
+/// Unlike [`Cursor`], which starts at the beginning of its buffer, a `RevCursor` starts at the end of it and each
+/// read back moves it towards the start. Its [`position`] is counted from the end as well: it's the amount of
+/// bytes which have been read back.
+///
+/// # Example
+/// ```
+/// use read_collection::{ReadBack, RevCursor};
+///
+/// fn main() {
+///     let mut cursor = RevCursor::new(String::from("Hello there"));
+///     let mut buffer = [0; 5];
+///
+///     cursor.read_back_exact(&mut buffer).unwrap();
+///     assert_eq!(&buffer, b"there");
+///     assert_eq!(cursor.position(), 5);
+///     assert_eq!(cursor.remaining_back(), b"Hello ");
+/// }
+/// ```
+///
+/// [`Cursor`]: std::io::Cursor
+/// [`position`]: RevCursor::position
+#[derive(Debug, Clone)]
+pub struct RevCursor<T> {
+    inner: T,
+    /// The amount of bytes at the end of `inner` which have been read back.
+    pos: usize,
+}
+
+impl<T: AsRef<[u8]>> RevCursor<T> {
+    /// Creates a new `RevCursor` which reads back the bytes of `inner`, starting at its end.
+    pub fn new(inner: T) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Creates a new `RevCursor` from any byte-like container.
+    ///
+    /// This is the same as [`RevCursor::new`].
+    pub fn from_ref(inner: T) -> Self {
+        Self::new(inner)
+    }
+
+    /// Returns the amount of bytes which have been read back, counted from the end of the inner buffer.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Sets the amount of bytes, counted from the end of the inner buffer, which have been read back.
+    ///
+    /// A position which is greater than the length of the inner buffer is treated as if it were at its start.
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Returns the bytes which haven't been read back yet.
+    pub fn remaining_back(&self) -> &[u8] {
+        let inner = self.inner.as_ref();
+        &inner[..inner.len() - cmp::min(self.pos, inner.len())]
+    }
+
+    /// Gets a reference to the inner buffer.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner buffer.
+    ///
+    /// Note that the position is counted from the end, so changing the length of the inner buffer changes which
+    /// bytes are read back next.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwraps this `RevCursor`, returning the inner buffer.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: AsRef<[u8]>> ReadBack for RevCursor<T> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.remaining_back().read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+
+    fn read_back_count_bytes(&mut self) -> io::Result<u64> {
+        let amount = self.remaining_back().len();
+        self.pos = self.inner.as_ref().len();
+
+        Ok(amount as u64)
+    }
+
+    fn read_back_skip_footer(&mut self, n: usize) -> io::Result<()> {
+        if n > self.remaining_back().len() {
+            self.pos = self.inner.as_ref().len();
+            return Err(footer_too_short_error());
+        }
+
+        self.read_back_consume(n);
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]>> BufReadBack for RevCursor<T> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_back())
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        let len = self.inner.as_ref().len();
+        self.pos = len - self.remaining_back().len().saturating_sub(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_string() {
+        let mut cursor = RevCursor::from_ref(String::from("first\nsecond"));
+
+        let mut line = String::new();
+        cursor.read_back_line(&mut line).unwrap();
+        assert_eq!(line, "\nsecond");
+        assert_eq!(cursor.remaining_back(), b"first");
+    }
+
+    #[test]
+    fn from_array() {
+        let mut cursor = RevCursor::from_ref([1u8, 2, 3]);
+        let mut buffer = [0; 2];
+
+        assert_eq!(cursor.read_back(&mut buffer).ok(), Some(2));
+        assert_eq!(buffer, [2, 3]);
+        assert_eq!(cursor.read_back(&mut buffer).ok(), Some(1));
+        assert_eq!(buffer[..1], [1]);
+        assert_eq!(cursor.read_back(&mut buffer).ok(), Some(0));
+
+        assert_eq!(cursor.into_inner(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn from_vec() {
+        let mut cursor = RevCursor::from_ref(vec![1u8, 2, 3, 4]);
+
+        let mut content = Vec::new();
+        cursor.read_back_to_end(&mut content).unwrap();
+        assert_eq!(content, [1, 2, 3, 4]);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn from_slice() {
+        let data = b"PK\x05\x06 end of central directory";
+        let mut cursor = RevCursor::new(data.as_slice());
+
+        assert_eq!(cursor.read_back_skip_until(b'P').ok(), Some(data.len()));
+        assert_eq!(cursor.position(), data.len());
+        assert!(cursor.read_back_fill_buf().unwrap().is_empty());
+    }
+
+    #[test]
+    fn set_position() {
+        let mut cursor = RevCursor::new(b"data");
+
+        cursor.set_position(1);
+        assert_eq!(cursor.read_back_fill_buf().ok(), Some(b"dat".as_slice()));
+        cursor.read_back_consume(1);
+        assert_eq!(cursor.position(), 2);
+
+        cursor.set_position(0);
+        assert_eq!(cursor.remaining_back(), b"data");
+    }
+
+    #[test]
+    fn position_past_the_start() {
+        let mut cursor = RevCursor::new(b"data");
+        cursor.set_position(10);
+
+        assert_eq!(cursor.read_back_fill_buf().ok(), Some(b"".as_slice()));
+        cursor.read_back_consume(1);
+        assert_eq!(cursor.position(), 4);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut cursor = RevCursor::new(b"first".to_vec());
+        let mut buffer = [0; 2];
+
+        cursor.read_back_exact(&mut buffer).unwrap();
+        cursor.get_mut()[0] = b'F';
+
+        assert_eq!(cursor.remaining_back(), b"Fir");
+    }
+}
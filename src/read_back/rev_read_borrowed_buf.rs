@@ -1,6 +1,10 @@
+use std::io::{self, Write};
 use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
 use std::{cmp, ptr};
 
+use crate::ReadBack;
+
 /// A borrowed byte buffer which is incrementally filled and initialized. This is basically just the reversed version of
 /// [`std::io::BorrowedBuf`].
 ///
@@ -8,7 +12,7 @@ use std::{cmp, ptr};
 /// - a region at the beginning of the buffer that is fully uninitialized
 /// - a region that has been initialized at some point but not yet logically filled, and
 /// - a region at the end that is fully initilized. The filled region is guaranteed to be a
-/// subset of the initialized region.
+///   subset of the initialized region.
 ///
 /// In summary, the contents of the buffer can be visualized as:
 /// ```not_rust
@@ -42,7 +46,7 @@ impl<'data> From<&'data mut [u8]> for RevBorrowedBuf<'data> {
 
         RevBorrowedBuf {
             // SAFETY: initialized data never becoming uninitialized is an invariant of BorrowedBuf
-            buf: unsafe { (slice as *mut [u8]).as_uninit_slice_mut().unwrap() },
+            buf: unsafe { &mut *(slice as *mut [u8] as *mut [MaybeUninit<u8>]) },
             filled: len,
             init: 0,
         }
@@ -65,22 +69,56 @@ impl<'data> From<&'data mut [MaybeUninit<u8>]> for RevBorrowedBuf<'data> {
 }
 
 impl<'data> RevBorrowedBuf<'data> {
+    /// Create a new `RevBorrowedBuf` from an uninitialized buffer.
+    ///
+    /// This is the same as the `From<&mut [MaybeUninit<u8>]>` implementation.
+    #[inline]
+    pub fn uninit(buf: &'data mut [MaybeUninit<u8>]) -> Self {
+        Self::from(buf)
+    }
+
+    /// Create a new `RevBorrowedBuf` without any capacity, for example as a placeholder until the real
+    /// buffer is known.
+    #[inline]
+    pub fn empty() -> Self {
+        Self::uninit(&mut [])
+    }
+
     /// Returns the total capacity of the buffer.
     #[inline]
     pub fn capacity(&self) -> usize {
         self.buf.len()
     }
 
+    /// Checks (in debug builds) that the initialized region still covers the filled one, so
+    /// `init <= filled <= capacity` has to hold.
+    #[inline]
+    fn debug_assert_invariants(&self) {
+        debug_assert!(
+            self.init <= self.filled && self.filled <= self.capacity(),
+            "invalid RevBorrowedBuf state: init = {}, filled = {}, capacity = {}",
+            self.init,
+            self.filled,
+            self.capacity()
+        );
+    }
+
     /// Returns the amount of bytes which are filled.
     #[inline]
     pub fn len(&self) -> usize {
         self.capacity() - self.filled
     }
 
-    /// Returns `true` if the buf is empty, otherwise `false`.
+    /// Returns `true` if no bytes are filled, otherwise `false`.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.buf.len() == 0
+        self.len() == 0
+    }
+
+    /// Returns `true` if the buffer can't hold any bytes, otherwise `false`.
+    #[inline]
+    pub fn capacity_is_zero(&self) -> bool {
+        self.capacity() == 0
     }
 
     /// Returns the amount of bytes of the initialized part of the buffer.
@@ -89,18 +127,37 @@ impl<'data> RevBorrowedBuf<'data> {
         self.capacity() - self.init
     }
 
+    /// Returns the amount of bytes of the unfilled part of the buffer, which is in front of the filled part.
+    #[inline]
+    pub fn unfilled_len(&self) -> usize {
+        self.filled
+    }
+
+    /// Returns a mutable reference to the uninitialized part at the front of the buffer, for example to hand it as
+    /// a pointer and a length to FFI code.
+    ///
+    /// Bytes which are written into it aren't filled yet: mark them as initialized with [`set_init`] and
+    /// [`advance`] a cursor over them afterwards. It is safe to uninitialize any of these bytes.
+    ///
+    /// [`set_init`]: RevBorrowedBuf::set_init
+    /// [`advance`]: RevBorrowedCursor::advance
+    #[inline]
+    pub fn uninit_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        &mut self.buf[..self.init]
+    }
+
     /// Returns a shared reference to the filled portion of the buffer.
     #[inline]
     pub fn filled(&self) -> &[u8] {
         // SAFETY: We only slice the filled part of the buffer, which is always valid
-        unsafe { MaybeUninit::slice_assume_init_ref(&self.buf[self.filled..]) }
+        unsafe { self.buf[self.filled..].assume_init_ref() }
     }
 
     /// Returns a mutable reference to the filled portion of the buffer.
     #[inline]
     pub fn filled_mut(&mut self) -> &mut [u8] {
         // SAFETY: We only slice the filled part of the buffer, which is always valid
-        unsafe { MaybeUninit::slice_assume_init_mut(&mut self.buf[self.filled..]) }
+        unsafe { self.buf[self.filled..].assume_init_mut() }
     }
 
     /// Returns a cursor over the unfilled part of the buffer.
@@ -124,9 +181,84 @@ impl<'data> RevBorrowedBuf<'data> {
     #[inline]
     pub fn clear(&mut self) -> &mut Self {
         self.filled = self.capacity();
+        self.debug_assert_invariants();
         self
     }
 
+    /// Appends the filled portion of the buffer to `out` and [`clear`]s the buffer afterwards.
+    ///
+    /// The filled bytes are appended in the order they're stored in (forward order), so each drain appends
+    /// one chunk after the chunk of the previous drain.
+    ///
+    /// [`clear`]: RevBorrowedBuf::clear
+    #[inline]
+    pub fn drain_filled_into(&mut self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.filled());
+        self.clear();
+    }
+
+    /// Renders the buffer as a hex dump with an ASCII column, for example to debug parsers which read back.
+    ///
+    /// Each row shows 16 bytes, starting with the offset of its first byte in the buffer. Bytes of the unfilled
+    /// region are shown as `__` (and as a space in the ASCII column), so the boundary to the filled region is
+    /// visible. Rows which don't contain any filled byte are left out.
+    ///
+    /// # Example
+    /// ```
+    /// use read_collection::RevBorrowedBuf;
+    ///
+    /// fn main() {
+    ///     let mut data = [0; 8];
+    ///     let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+    ///     buf.unfilled().append(b"Hi!\n");
+    ///
+    ///     assert_eq!(
+    ///         buf.hex_dump(),
+    ///         "00000000  __ __ __ __ 48 69 21 0a                          |    Hi!.|\n"
+    ///     );
+    /// }
+    /// ```
+    pub fn hex_dump(&self) -> String {
+        const ROW_LEN: usize = 16;
+
+        let mut dump = String::new();
+        if self.is_empty() {
+            return dump;
+        }
+
+        let first_row = self.filled / ROW_LEN * ROW_LEN;
+        for row_start in (first_row..self.capacity()).step_by(ROW_LEN) {
+            let row_end = cmp::min(row_start + ROW_LEN, self.capacity());
+            let mut hex = String::with_capacity(3 * ROW_LEN);
+            let mut ascii = String::with_capacity(ROW_LEN);
+
+            for index in row_start..row_end {
+                if index < self.filled {
+                    hex.push_str("__ ");
+                    ascii.push(' ');
+                } else {
+                    let byte = self.filled()[index - self.filled];
+                    hex.push_str(&format!("{:02x} ", byte));
+                    ascii.push(if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    });
+                }
+            }
+
+            dump.push_str(&format!(
+                "{:08x}  {:<width$} |{}|\n",
+                row_start,
+                hex,
+                ascii,
+                width = 3 * ROW_LEN
+            ));
+        }
+
+        dump
+    }
+
     /// Asserts that all bytes on the left (inclusive) to index `n` are initialised.
     ///
     /// `RevBorrowedBuf` assumes that bytes are never de-initialized, so this method does nothing when called with fewer
@@ -138,6 +270,7 @@ impl<'data> RevBorrowedBuf<'data> {
     #[inline]
     pub unsafe fn set_init(&mut self, n: usize) -> &mut Self {
         self.init = cmp::min(self.init, n);
+        self.debug_assert_invariants();
         self
     }
 }
@@ -209,7 +342,7 @@ impl<'a> RevBorrowedCursor<'a> {
         debug_assert!(self.buf.init <= self.buf.filled);
 
         // SAFETY: We only slice the initialized part of the buffer, which is always valid
-        unsafe { MaybeUninit::slice_assume_init_ref(&self.buf.buf[self.buf.init..]) }
+        unsafe { self.buf.buf[self.buf.init..].assume_init_ref() }
     }
 
     /// Returns a mutable reference to the initialized portion of the cursor.
@@ -218,7 +351,7 @@ impl<'a> RevBorrowedCursor<'a> {
         debug_assert!(self.buf.init <= self.buf.filled);
 
         // SAFETY: We only slice the initialized part of the buffer, which is always valid
-        unsafe { MaybeUninit::slice_assume_init_mut(&mut self.buf.buf[self.buf.init..]) }
+        unsafe { self.buf.buf[self.buf.init..].assume_init_mut() }
     }
 
     /// Returns a mutable reference to the uninitialized part of the cursor.
@@ -253,6 +386,7 @@ impl<'a> RevBorrowedCursor<'a> {
     pub fn advance(&mut self, n: usize) -> &mut Self {
         self.buf.filled -= n;
         self.buf.init = cmp::min(self.buf.init, self.buf.filled);
+        self.buf.debug_assert_invariants();
         self
     }
 
@@ -266,6 +400,7 @@ impl<'a> RevBorrowedCursor<'a> {
             ptr::write_bytes(uninit.as_mut_ptr(), 0, uninit.len());
         }
         self.buf.init = 0;
+        self.buf.debug_assert_invariants();
 
         self
     }
@@ -281,38 +416,419 @@ impl<'a> RevBorrowedCursor<'a> {
     #[inline]
     pub unsafe fn set_init(&mut self, n: usize) -> &mut Self {
         self.buf.init = cmp::min(self.buf.init, self.buf.filled.saturating_sub(n));
+        self.buf.debug_assert_invariants();
         self
     }
 
     /// Appends data to the cursor, advancing position within its buffer.
     ///
+    /// `buf` can't point into the underlying buffer of the cursor, since the cursor borrows it mutably. Creating
+    /// such a slice anyway (with `unsafe` code) is undefined behaviour, debug builds panic if they detect it.
+    ///
     /// # Panics
     ///
     /// Panics if `self.capacity()` is less than `buf.len()`.
     #[inline]
     pub fn append(&mut self, buf: &[u8]) {
         assert!(self.capacity() >= buf.len());
+        debug_assert!(
+            !overlaps(self.buf.buf, buf),
+            "the appended slice points into the buffer of the cursor"
+        );
 
         // SAFETY: we do not de-initialize any of the elements of the slice
         let mut_init_slice = unsafe { self.as_mut() };
         let mut_init_slice_len = mut_init_slice.len();
-        MaybeUninit::copy_from_slice(
-            &mut mut_init_slice[mut_init_slice_len.saturating_sub(buf.len())..],
-            buf,
-        );
+        mut_init_slice[mut_init_slice_len.saturating_sub(buf.len())..].write_copy_of_slice(buf);
 
         // SAFETY: We just added the entire contents of buf to the filled section.
         unsafe {
             self.set_init(buf.len());
         }
         self.buf.filled -= buf.len();
+        self.buf.debug_assert_invariants();
+    }
+
+    /// Writes `n` copies of `byte` to the cursor, advancing position within its buffer.
+    ///
+    /// This is like [`append`](RevBorrowedCursor::append) with a slice which contains `n` times `byte`,
+    /// without having to create the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.capacity()` is less than `n`.
+    #[inline]
+    pub fn fill_bytes(&mut self, byte: u8, n: usize) {
+        assert!(self.capacity() >= n);
+
+        // SAFETY: we do not de-initialize any of the elements of the slice
+        let mut_init_slice = unsafe { self.as_mut() };
+        let mut_init_slice_len = mut_init_slice.len();
+        mut_init_slice[mut_init_slice_len - n..].fill(MaybeUninit::new(byte));
+
+        // SAFETY: We just initialized the last `n` bytes of the cursor.
+        unsafe {
+            self.set_init(n);
+        }
+        self.buf.filled -= n;
+        self.buf.debug_assert_invariants();
+    }
+
+    /// Reads back bytes from `reader` directly into the cursor, advancing it by the amount of read bytes.
+    ///
+    /// This is a single [`read_back_buf`] call, so it doesn't need an intermediate buffer and at most
+    /// [`capacity`] bytes are appended. If the cursor is full, `reader` isn't touched at all.
+    ///
+    /// On success, the amount of appended bytes is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use read_collection::RevBorrowedBuf;
+    ///
+    /// fn main() {
+    ///     let mut data = [MaybeUninit::uninit(); 4];
+    ///     let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+    ///     let mut source = b"Hello there".as_slice();
+    ///
+    ///     assert_eq!(buf.unfilled().append_from(&mut source).ok(), Some(4));
+    ///     assert_eq!(buf.filled(), b"here");
+    ///     assert_eq!(source, b"Hello t");
+    /// }
+    /// ```
+    ///
+    /// [`read_back_buf`]: ReadBack::read_back_buf
+    /// [`capacity`]: RevBorrowedCursor::capacity
+    pub fn append_from<R: ReadBack + ?Sized>(&mut self, reader: &mut R) -> io::Result<usize> {
+        if self.capacity() == 0 {
+            return Ok(0);
+        }
+
+        let written = self.written();
+        reader.read_back_buf(self.reborrow())?;
+
+        Ok(self.written() - written)
+    }
+
+    /// Splits the unfilled part of the cursor into two non-overlapping regions at index `n`.
+    ///
+    /// The front region covers the first `n` bytes of the cursor and the back region the rest, so the back region
+    /// is the one which is adjacent to the filled part of the buffer. Both regions can be filled independently
+    /// (for example from two threads) and are handed back to the buffer with [`RevBorrowedSplit::commit`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than `self.capacity()`.
+    #[inline]
+    pub fn split_at(&mut self, n: usize) -> RevBorrowedSplit<'_> {
+        assert!(n <= self.capacity());
+
+        let RevBorrowedBuf { buf, filled, init } = &mut *self.buf;
+        let (front, back) = buf[..*filled].split_at_mut(n);
+
+        RevBorrowedSplit {
+            front: RevBorrowedRegion::new(front),
+            back: RevBorrowedRegion::new(back),
+            filled,
+            init,
+        }
     }
 }
 
+/// Appends the written bytes to the cursor, like [`RevBorrowedCursor::append`] does.
+///
+/// Since the cursor is filled from the back to the front, each write is placed *in front of* the bytes of the
+/// previous writes. Writing `"world"` and then `"hello "` leads to `"hello world"` in the filled part.
+///
+/// If the input doesn't fit into the cursor, only its first [`capacity`] bytes are written, so the cursor is full
+/// afterwards and further writes return `Ok(0)`.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+/// use read_collection::RevBorrowedBuf;
+///
+/// fn main() {
+///     let mut data = [0; 11];
+///     let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+///
+///     let mut cursor = buf.unfilled();
+///     write!(cursor, "world").unwrap();
+///     write!(cursor, "hello ").unwrap();
+///
+///     assert_eq!(buf.filled(), b"hello world");
+/// }
+/// ```
+///
+/// [`capacity`]: RevBorrowedCursor::capacity
+impl Write for RevBorrowedCursor<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let amount = cmp::min(self.capacity(), buf.len());
+        self.append(&buf[..amount]);
+        Ok(amount)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The two regions of a [`RevBorrowedCursor`] which has been split with [`RevBorrowedCursor::split_at`].
+///
+/// Nothing which is written into the regions becomes part of the filled portion of the underlying
+/// `RevBorrowedBuf` until [`commit`](RevBorrowedSplit::commit) is called.
+#[derive(Debug)]
+pub struct RevBorrowedSplit<'a> {
+    front: RevBorrowedRegion<'a>,
+    back: RevBorrowedRegion<'a>,
+    filled: &'a mut usize,
+    init: &'a mut usize,
+}
+
+impl<'a> RevBorrowedSplit<'a> {
+    /// Returns the front and the back region.
+    #[inline]
+    pub fn regions(&mut self) -> (&mut RevBorrowedRegion<'a>, &mut RevBorrowedRegion<'a>) {
+        (&mut self.front, &mut self.back)
+    }
+
+    /// Adds the written bytes of both regions to the filled portion of the buffer and returns how many
+    /// bytes have been added.
+    ///
+    /// Since the filled portion has to stay contiguous, the bytes of the front region are only added if the
+    /// back region has been written completely. Otherwise they are discarded.
+    #[inline]
+    pub fn commit(self) -> usize {
+        let mut committed = self.back.written;
+        if self.back.capacity() == 0 {
+            committed += self.front.written;
+        }
+
+        *self.filled -= committed;
+        *self.init = cmp::min(*self.init, *self.filled);
+        debug_assert!(*self.init <= *self.filled);
+        committed
+    }
+}
+
+/// One of the two regions of a [`RevBorrowedSplit`].
+///
+/// Like [`RevBorrowedCursor`], a region is filled from its end towards its start.
+#[derive(Debug)]
+pub struct RevBorrowedRegion<'a> {
+    buf: &'a mut [MaybeUninit<u8>],
+    /// The amount of bytes which have been written at the end of `buf`.
+    written: usize,
+}
+
+impl<'a> RevBorrowedRegion<'a> {
+    fn new(buf: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self { buf, written: 0 }
+    }
+
+    /// Returns the available space in the region.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len() - self.written
+    }
+
+    /// Returns the number of bytes written to this region.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Returns a shared reference to the written part of the region.
+    #[inline]
+    pub fn filled(&self) -> &[u8] {
+        let start = self.buf.len() - self.written;
+
+        // SAFETY: We only slice the written part of the region, which has been initialized by `append`
+        unsafe { self.buf[start..].assume_init_ref() }
+    }
+
+    /// Appends data to the region in front of the already written bytes.
+    ///
+    /// Like for [`RevBorrowedCursor::append`], `buf` mustn't point into the region.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.capacity()` is less than `buf.len()`.
+    #[inline]
+    pub fn append(&mut self, buf: &[u8]) {
+        assert!(self.capacity() >= buf.len());
+        debug_assert!(
+            !overlaps(self.buf, buf),
+            "the appended slice points into the region"
+        );
+
+        let end = self.capacity();
+        self.buf[end - buf.len()..end].write_copy_of_slice(buf);
+        self.written += buf.len();
+    }
+}
+
+/// An owned version of [`RevBorrowedBuf`], so one allocation can be reused for many reads.
+///
+/// It has a fixed capacity and keeps track of its filled and initialized regions the same way as
+/// [`RevBorrowedBuf`] does. To write into it, [`borrow`] it as a [`RevBorrowedBuf`].
+///
+/// # Example
+/// ```
+/// use read_collection::{ReadBack, RevOwnedBuf};
+///
+/// fn main() {
+///     let mut data = b"first|second".as_slice();
+///     let mut buf = RevOwnedBuf::with_capacity(6);
+///
+///     data.read_back_buf_exact(buf.borrow().unfilled()).unwrap();
+///     assert_eq!(buf.filled(), b"second");
+///
+///     buf.clear();
+///     data.read_back_buf_exact(buf.borrow().unfilled()).unwrap();
+///     assert_eq!(buf.filled(), b"first|");
+/// }
+/// ```
+///
+/// [`borrow`]: RevOwnedBuf::borrow
+#[derive(Debug)]
+pub struct RevOwnedBuf {
+    buf: Box<[MaybeUninit<u8>]>,
+    /// The starting index (inclusively) where the values are filled
+    filled: usize,
+    /// The starting index (inclusively) where the values are initialized
+    init: usize,
+}
+
+impl RevOwnedBuf {
+    /// Creates a new, uninitialized `RevOwnedBuf` which can hold `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Box::new_uninit_slice(capacity),
+            filled: capacity,
+            init: capacity,
+        }
+    }
+
+    /// Returns the total capacity of the buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the amount of bytes which are filled.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.capacity() - self.filled
+    }
+
+    /// Returns `true` if no bytes are filled.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a shared reference to the filled portion of the buffer.
+    #[inline]
+    pub fn filled(&self) -> &[u8] {
+        // SAFETY: We only slice the filled part of the buffer, which is always initialized
+        unsafe { self.buf[self.filled..].assume_init_ref() }
+    }
+
+    /// Clears the buffer, resetting the filled region to empty.
+    ///
+    /// The number of initialized bytes is not changed, and the contents of the buffer are not modified.
+    #[inline]
+    pub fn clear(&mut self) -> &mut Self {
+        self.filled = self.capacity();
+        self
+    }
+
+    /// Borrows the buffer as a [`RevBorrowedBuf`], which starts with the filled and initialized regions of this
+    /// buffer. Once the returned value is dropped, the changes to these regions are taken over.
+    #[inline]
+    pub fn borrow(&mut self) -> RevOwnedBufBorrow<'_> {
+        let RevOwnedBuf { buf, filled, init } = self;
+        let data: *const [MaybeUninit<u8>] = &**buf;
+
+        RevOwnedBufBorrow {
+            buf: RevBorrowedBuf {
+                buf,
+                filled: *filled,
+                init: *init,
+            },
+            data,
+            filled,
+            init,
+        }
+    }
+}
+
+/// A [`RevOwnedBuf`] which is borrowed as a [`RevBorrowedBuf`].
+///
+/// This struct is created by [`RevOwnedBuf::borrow`] and dereferences to a [`RevBorrowedBuf`]. Dropping it hands
+/// the filled and initialized regions back to the [`RevOwnedBuf`].
+#[derive(Debug)]
+pub struct RevOwnedBufBorrow<'a> {
+    buf: RevBorrowedBuf<'a>,
+    /// The memory of the owned buffer, to check that `buf` still points to all of it.
+    data: *const [MaybeUninit<u8>],
+    filled: &'a mut usize,
+    init: &'a mut usize,
+}
+
+impl<'a> Deref for RevOwnedBufBorrow<'a> {
+    type Target = RevBorrowedBuf<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buf
+    }
+}
+
+impl DerefMut for RevOwnedBufBorrow<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buf
+    }
+}
+
+impl Drop for RevOwnedBufBorrow<'_> {
+    fn drop(&mut self) {
+        // `buf` might have been swapped with another one, its regions don't say anything about our memory then.
+        // Comparing the lengths as well catches a shortened slice of our memory, whose indices are shifted.
+        if ptr::eq(&*self.buf.buf, self.data) {
+            *self.filled = self.buf.filled;
+            *self.init = self.buf.init;
+        }
+    }
+}
+
+/// Returns `true` if the memory of `src` overlaps the memory of `dst`.
+#[inline]
+fn overlaps(dst: &[MaybeUninit<u8>], src: &[u8]) -> bool {
+    let dst = dst.as_ptr_range();
+    let src = src.as_ptr_range();
+
+    (dst.start as usize) < (src.end as usize) && (src.start as usize) < (dst.end as usize)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn overlaps() {
+        let data = [0u8; 8];
+        let dst = [MaybeUninit::<u8>::uninit(); 8];
+        // SAFETY: `data` is initialized, the slice is only used to compare addresses
+        let data_as_dst = unsafe { &*(data.as_slice() as *const [u8] as *const [MaybeUninit<u8>]) };
+
+        assert!(!super::overlaps(&dst, &data));
+        assert!(super::overlaps(data_as_dst, &data[2..4]));
+        assert!(super::overlaps(&data_as_dst[2..4], &data));
+        assert!(!super::overlaps(&data_as_dst[..4], &data[4..]));
+        assert!(!super::overlaps(data_as_dst, &data[8..]));
+    }
+
     mod rev_borrowed_buf {
         use super::*;
 
@@ -329,11 +845,254 @@ mod tests {
             buf.filled -= 1;
             assert_eq!(buf.filled(), [2, 3]);
         }
+
+        #[test]
+        fn is_empty() {
+            let mut data = [MaybeUninit::uninit(); 8];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+            assert!(buf.is_empty());
+            assert!(!buf.capacity_is_zero());
+
+            buf.unfilled().append(&[1]);
+            assert!(!buf.is_empty());
+
+            assert!(RevBorrowedBuf::empty().capacity_is_zero());
+        }
+
+        #[test]
+        fn drain_filled_into() {
+            let mut data = [0; 3];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+            let mut out = vec![0];
+
+            buf.unfilled().append(&[4, 5]);
+            buf.drain_filled_into(&mut out);
+            assert_eq!(out, [0, 4, 5]);
+            assert!(buf.filled().is_empty());
+
+            buf.unfilled().append(&[1, 2, 3]);
+            buf.drain_filled_into(&mut out);
+            assert_eq!(out, [0, 4, 5, 1, 2, 3]);
+            assert!(buf.filled().is_empty());
+
+            // nothing filled => nothing to drain
+            buf.drain_filled_into(&mut out);
+            assert_eq!(out, [0, 4, 5, 1, 2, 3]);
+        }
+
+        #[test]
+        fn uninit() {
+            let mut data = [MaybeUninit::uninit(); 2];
+            let mut buf = RevBorrowedBuf::uninit(data.as_mut_slice());
+
+            assert_eq!(buf.capacity(), 2);
+            assert_eq!(buf.init_len(), 0);
+            buf.unfilled().append(&[1, 2]);
+            assert_eq!(buf.filled(), [1, 2]);
+        }
+
+        #[test]
+        fn uninit_mut() {
+            let mut data = [MaybeUninit::uninit(); 6];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+            buf.unfilled().append(&[5, 6]);
+            assert_eq!(buf.unfilled_len(), 4);
+
+            let uninit = buf.uninit_mut();
+            assert_eq!(uninit.len(), 4);
+            // SAFETY: the pointer is valid for the last two bytes of the uninitialized part
+            unsafe {
+                let ptr = uninit.as_mut_ptr().cast::<u8>();
+                ptr.add(2).write(3);
+                ptr.add(3).write(4);
+            }
+            // writing doesn't fill anything
+            assert_eq!(buf.filled(), [5, 6]);
+
+            // SAFETY: the bytes from index 2 on have been initialized above
+            unsafe {
+                buf.set_init(2);
+            }
+            buf.unfilled().advance(2);
+
+            assert_eq!(buf.filled(), [3, 4, 5, 6]);
+            assert_eq!(buf.unfilled_len(), 2);
+            assert_eq!(buf.uninit_mut().len(), 2);
+        }
+
+        #[test]
+        fn empty() {
+            let mut buf = RevBorrowedBuf::empty();
+            assert_eq!(buf.capacity(), 0);
+
+            let mut cursor = buf.unfilled();
+            assert_eq!(cursor.capacity(), 0);
+            cursor.append(&[]);
+            cursor.fill_bytes(0, 0);
+            assert_eq!(cursor.written(), 0);
+
+            assert!(buf.filled().is_empty());
+        }
+
+        #[test]
+        fn invariants_hold_for_valid_sequence() {
+            let mut data = [MaybeUninit::uninit(); 4];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+
+            buf.unfilled().append(&[1]);
+            buf.unfilled().ensure_init().advance(1);
+            unsafe {
+                buf.unfilled().set_init(2);
+                buf.set_init(0);
+            }
+            buf.clear();
+            buf.unfilled().append(&[1, 2, 3, 4]);
+
+            assert_eq!(buf.init_len(), 4);
+            assert_eq!(buf.len(), 4);
+        }
+
+        #[test]
+        fn hex_dump() {
+            let mut data = [0; 20];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+            assert_eq!(buf.hex_dump(), "");
+
+            buf.unfilled().append(b"\x00abc\xFF");
+            assert_eq!(
+                buf.hex_dump(),
+                concat!(
+                    "00000000  __ __ __ __ __ __ __ __ __ __ __ __ __ __ __ 00  |               .|\n",
+                    "00000010  61 62 63 ff                                      |abc.|\n",
+                )
+            );
+
+            buf.unfilled().append(b"12345678901234");
+            assert_eq!(
+                buf.hex_dump(),
+                concat!(
+                    "00000000  __ 31 32 33 34 35 36 37 38 39 30 31 32 33 34 00  | 12345678901234.|\n",
+                    "00000010  61 62 63 ff                                      |abc.|\n",
+                )
+            );
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "invalid RevBorrowedBuf state")]
+        fn broken_invariant() {
+            let mut data = [1, 2, 3];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+
+            // the filled region isn't initialized anymore
+            buf.filled = 1;
+            buf.init = 2;
+            unsafe {
+                buf.set_init(3);
+            }
+        }
     }
 
     mod rev_borrowed_cursor {
         use super::*;
 
+        mod append_from {
+            use super::*;
+
+            /// Panics if it's read back from.
+            struct Untouchable;
+
+            impl ReadBack for Untouchable {
+                fn read_back(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                    panic!("the reader mustn't be touched");
+                }
+            }
+
+            #[test]
+            fn less_than_capacity() {
+                let mut buffer = [MaybeUninit::uninit(); 8];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+                let mut source = b"abc".as_slice();
+
+                let mut cursor = buf.unfilled();
+                cursor.append(b"!");
+                assert_eq!(cursor.append_from(&mut source).ok(), Some(3));
+                assert_eq!(cursor.written(), 4);
+                assert_eq!(cursor.capacity(), 4);
+                assert_eq!(cursor.append_from(&mut source).ok(), Some(0));
+
+                assert_eq!(buf.filled(), b"abc!");
+                assert!(source.is_empty());
+            }
+
+            #[test]
+            fn capped_at_capacity() {
+                let mut buffer = [0; 3];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+                let mut source = b"abcde".as_slice();
+
+                assert_eq!(buf.unfilled().append_from(&mut source).ok(), Some(3));
+                assert_eq!(buf.filled(), b"cde");
+                assert_eq!(source, b"ab");
+            }
+
+            #[test]
+            fn zero_capacity() {
+                let mut buf = RevBorrowedBuf::empty();
+
+                assert_eq!(buf.unfilled().append_from(&mut Untouchable).ok(), Some(0));
+            }
+        }
+
+        mod write {
+            use super::*;
+
+            #[test]
+            fn later_writes_are_in_front() {
+                let mut buffer = [MaybeUninit::uninit(); 9];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+
+                let mut cursor = buf.unfilled();
+                assert_eq!(cursor.write(b"third").ok(), Some(5));
+                assert_eq!(cursor.write(b"2nd").ok(), Some(3));
+                assert_eq!(cursor.write(b"1").ok(), Some(1));
+                assert_eq!(cursor.written(), 9);
+
+                assert_eq!(buf.filled(), b"12ndthird");
+            }
+
+            #[test]
+            fn short_write() {
+                let mut buffer = [0; 3];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+
+                let mut cursor = buf.unfilled();
+                assert_eq!(cursor.write(b"abcd").ok(), Some(3));
+                assert_eq!(cursor.write(b"e").ok(), Some(0));
+                assert_eq!(
+                    cursor.write_all(b"f").map_err(|err| err.kind()),
+                    Err(io::ErrorKind::WriteZero)
+                );
+
+                assert_eq!(buf.filled(), b"abc");
+            }
+
+            #[test]
+            fn copy() {
+                let mut buffer = [0; 8];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+
+                let mut cursor = buf.unfilled();
+                cursor.fill_bytes(b'!', 2);
+                assert_eq!(
+                    io::copy(&mut b"hello".as_slice(), &mut cursor).ok(),
+                    Some(5)
+                );
+
+                assert_eq!(buf.filled(), b"hello!!");
+            }
+        }
+
         #[test]
         fn capacity() {
             let mut data = [1, 2, 3];
@@ -361,6 +1120,44 @@ mod tests {
             assert_eq!(cursor.capacity(), 1);
         }
 
+        #[test]
+        fn fill_bytes() {
+            let mut data = [MaybeUninit::uninit(); 5];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+
+            buf.unfilled().fill_bytes(0, 2);
+            assert_eq!(buf.filled(), [0, 0]);
+
+            let mut cursor = buf.unfilled();
+            cursor.fill_bytes(b'x', 3);
+            assert_eq!(cursor.written(), 3);
+            assert_eq!(cursor.capacity(), 0);
+
+            assert_eq!(buf.filled(), b"xxx\0\0");
+            assert_eq!(buf.init_len(), 5);
+        }
+
+        #[test]
+        #[should_panic]
+        fn fill_bytes_panic() {
+            let mut data = [0; 2];
+            let mut buf = RevBorrowedBuf::from(data.as_mut_slice());
+
+            buf.unfilled().fill_bytes(0, 3);
+        }
+
+        /// The common case of appending a separate slice, also meant to be checked with Miri.
+        #[test]
+        fn append_from_separate_buffer() {
+            let mut data = [MaybeUninit::uninit(); 6];
+            let mut buf = RevBorrowedBuf::uninit(data.as_mut_slice());
+            let source = [1, 2, 3, 4, 5, 6];
+
+            buf.unfilled().append(&source[4..]);
+            buf.unfilled().append(&source[..4]);
+            assert_eq!(buf.filled(), [1, 2, 3, 4, 5, 6]);
+        }
+
         #[test]
         #[should_panic]
         fn append_panic() {
@@ -373,5 +1170,152 @@ mod tests {
             // capacity < data.len()!!!! => Panic
             cursor.append(&data);
         }
+
+        mod split_at {
+            use super::*;
+
+            #[test]
+            fn both_regions() {
+                let mut buffer = [MaybeUninit::uninit(); 6];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+                buf.unfilled().append(&[6]);
+
+                let mut cursor = buf.unfilled();
+                let mut split = cursor.split_at(2);
+                {
+                    let (front, back) = split.regions();
+                    assert_eq!(front.capacity(), 2);
+                    assert_eq!(back.capacity(), 3);
+
+                    std::thread::scope(|scope| {
+                        scope.spawn(|| front.append(&[1, 2]));
+                        scope.spawn(|| {
+                            back.append(&[4, 5]);
+                            back.append(&[3]);
+                        });
+                    });
+
+                    assert_eq!(front.filled(), [1, 2]);
+                    assert_eq!(back.filled(), [3, 4, 5]);
+                }
+                assert_eq!(split.commit(), 5);
+                assert_eq!(cursor.written(), 5);
+                assert_eq!(cursor.capacity(), 0);
+
+                assert_eq!(buf.filled(), [1, 2, 3, 4, 5, 6]);
+            }
+
+            #[test]
+            fn back_region_not_full() {
+                let mut buffer = [MaybeUninit::uninit(); 4];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+
+                let mut cursor = buf.unfilled();
+                let mut split = cursor.split_at(2);
+                {
+                    let (front, back) = split.regions();
+                    front.append(&[1, 2]);
+                    back.append(&[4]);
+                }
+                // the bytes of the front region aren't adjacent to the filled part
+                assert_eq!(split.commit(), 1);
+                assert_eq!(cursor.written(), 1);
+
+                assert_eq!(buf.filled(), [4]);
+            }
+
+            #[test]
+            fn nothing_written() {
+                let mut buffer = [0; 3];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+
+                let mut cursor = buf.unfilled();
+                assert_eq!(cursor.split_at(1).commit(), 0);
+                assert_eq!(cursor.written(), 0);
+                assert!(buf.filled().is_empty());
+            }
+
+            #[test]
+            #[should_panic]
+            fn out_of_bounds() {
+                let mut buffer = [0; 3];
+                let mut buf = RevBorrowedBuf::from(buffer.as_mut_slice());
+
+                buf.unfilled().split_at(4);
+            }
+        }
+    }
+
+    mod rev_owned_buf {
+        use super::*;
+
+        #[test]
+        fn keeps_state_between_borrows() {
+            let mut buf = RevOwnedBuf::with_capacity(4);
+
+            buf.borrow().unfilled().append(&[3, 4]);
+            buf.borrow().unfilled().append(&[1, 2]);
+
+            assert_eq!(buf.filled(), [1, 2, 3, 4]);
+            assert_eq!(buf.borrow().init_len(), 4);
+        }
+
+        #[test]
+        fn borrow_and_clear() {
+            let mut buf = RevOwnedBuf::with_capacity(3);
+            buf.borrow().unfilled().append(&[1, 2]);
+
+            buf.borrow().clear();
+            assert!(buf.is_empty());
+
+            let borrowed = buf.borrow();
+            assert_eq!(borrowed.len(), 0);
+            assert_eq!(borrowed.init_len(), 2);
+        }
+
+        #[test]
+        fn capacity_does_not_grow() {
+            let mut buf = RevOwnedBuf::with_capacity(2);
+            buf.borrow().unfilled().append(&[1, 2]);
+
+            assert_eq!(buf.capacity(), 2);
+            assert_eq!(buf.borrow().unfilled().capacity(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn append_past_capacity() {
+            let mut buf = RevOwnedBuf::with_capacity(2);
+            buf.borrow().unfilled().append(&[1, 2, 3]);
+        }
+
+        #[test]
+        fn swapped_borrow() {
+            let mut buf = RevOwnedBuf::with_capacity(2);
+            let mut other = [0; 1];
+
+            {
+                let mut borrowed = buf.borrow();
+                *borrowed = RevBorrowedBuf::from(other.as_mut_slice());
+                borrowed.unfilled().append(&[1]);
+            }
+
+            assert!(buf.is_empty());
+            assert_eq!(buf.borrow().init_len(), 0);
+        }
+
+        #[test]
+        fn shortened_borrow() {
+            let mut buf = RevOwnedBuf::with_capacity(4);
+
+            let mut borrowed = buf.borrow();
+            let mut taken = mem::replace(&mut *borrowed, RevBorrowedBuf::uninit(&mut []));
+            *borrowed = RevBorrowedBuf::uninit(&mut taken.uninit_mut()[..1]);
+            borrowed.unfilled().append(&[1]);
+            drop(borrowed);
+
+            assert!(buf.is_empty());
+            assert_eq!(buf.borrow().init_len(), 0);
+        }
     }
 }
@@ -0,0 +1,100 @@
+use std::io::{self, Read};
+
+use crate::ReadBack;
+
+/// An adapter which turns a [`ReadBack`]er into a [`Read`]er which yields the bytes of the source in reversed
+/// order, starting with its last byte.
+///
+/// `RevToForward` doesn't buffer anything itself: each [`read`](Read::read) reads back into the given buffer
+/// and reverses the read bytes in place. Wrap it in a [`BufReader`](std::io::BufReader) if the source needs
+/// bigger reads.
+///
+/// # Example
+/// ```
+/// use std::io::Read;
+/// use read_collection::RevToForward;
+///
+/// fn main() {
+///     let data = b"!olleH";
+///
+///     let mut reversed = String::new();
+///     RevToForward::new(data.as_slice())
+///         .read_to_string(&mut reversed)
+///         .unwrap();
+///
+///     assert_eq!(reversed, "Hello!");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RevToForward<R> {
+    inner: R,
+}
+
+impl<R> RevToForward<R> {
+    /// Creates a new `RevToForward` which reads back `inner`.
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this `RevToForward`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: ReadBack> Read for RevToForward<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.inner.read_back(buf)?;
+        buf[..amount].reverse();
+
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn read_to_end() {
+        let data = [1, 2, 3, 4, 5];
+        let mut reversed = Vec::new();
+
+        let mut reader = RevToForward::new(data.as_slice());
+        assert_eq!(reader.read_to_end(&mut reversed).ok(), Some(5));
+        assert_eq!(reversed, [5, 4, 3, 2, 1]);
+        assert!(reader.into_inner().is_empty());
+    }
+
+    #[test]
+    fn small_reads() {
+        let data = b"abcdefg";
+        let mut reader = BufReader::with_capacity(3, RevToForward::new(data.as_slice()));
+
+        let mut reversed = Vec::new();
+        reader.read_to_end(&mut reversed).unwrap();
+        assert_eq!(&reversed, b"gfedcba");
+    }
+
+    #[test]
+    fn read_exact() {
+        let data = b"abcdefg";
+        let mut reader = RevToForward::new(data.as_slice());
+
+        let mut buffer = [0; 2];
+        reader.read_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer, b"gf");
+        assert_eq!(reader.get_ref(), b"abcde");
+    }
+}
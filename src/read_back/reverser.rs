@@ -0,0 +1,107 @@
+use std::io::{self, Read, Seek};
+
+use crate::{BufReadBacker, RevToForward, DEFAULT_BUF_SIZE};
+
+/// A [`Read`]er which yields the bytes of a seekable source in reversed order, starting with its last byte.
+///
+/// This lets code which only knows [`Read`] run over a reversed stream unchanged. It's a [`RevToForward`] over a
+/// [`BufReadBacker`], so the source is read block by block from its end and each `read` hands out the buffered
+/// bytes in reversed order.
+///
+/// # Example
+/// ```
+/// use std::io::{Cursor, Read};
+/// use read_collection::Reverser;
+///
+/// fn main() {
+///     let mut reverser = Reverser::new(Cursor::new(b"!olleH")).unwrap();
+///
+///     let mut reversed = String::new();
+///     reverser.read_to_string(&mut reversed).unwrap();
+///     assert_eq!(reversed, "Hello!");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Reverser<R> {
+    inner: RevToForward<BufReadBacker<R>>,
+}
+
+impl<R: Read + Seek> Reverser<R> {
+    /// Creates a new `Reverser` with a default buffer capacity (currently 8 KiB) which starts at the end of
+    /// `inner`.
+    pub fn new(inner: R) -> io::Result<Self> {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `Reverser` with the given buffer capacity which starts at the end of `inner`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    pub fn with_capacity(capacity: usize, inner: R) -> io::Result<Self> {
+        Ok(Self {
+            inner: RevToForward::new(BufReadBacker::with_capacity(capacity, inner)?),
+        })
+    }
+}
+
+impl<R> Reverser<R> {
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Unwraps this `Reverser`, returning the underlying reader.
+    ///
+    /// Like for [`BufReadBacker::into_inner`], the bytes which are still buffered are lost.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner().into_inner()
+    }
+}
+
+impl<R: Read + Seek> Read for Reverser<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_to_end() {
+        let data = (0..100u8).collect::<Vec<u8>>();
+        let mut reverser = Reverser::with_capacity(7, Cursor::new(data.as_slice())).unwrap();
+
+        let mut reversed = Vec::new();
+        assert_eq!(reverser.read_to_end(&mut reversed).ok(), Some(100));
+
+        let mut expected = data.clone();
+        expected.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[test]
+    fn small_reads() {
+        let mut reverser = Reverser::with_capacity(4, Cursor::new(b"abcdefg")).unwrap();
+        let mut buffer = [0; 3];
+
+        assert_eq!(reverser.read(&mut buffer).ok(), Some(3));
+        assert_eq!(&buffer, b"gfe");
+        assert_eq!(reverser.read(&mut buffer).ok(), Some(1));
+        assert_eq!(&buffer[..1], b"d");
+        assert_eq!(reverser.read(&mut buffer).ok(), Some(3));
+        assert_eq!(&buffer, b"cba");
+        assert_eq!(reverser.read(&mut buffer).ok(), Some(0));
+    }
+
+    #[test]
+    fn empty_source() {
+        let mut reverser = Reverser::new(Cursor::new(Vec::new())).unwrap();
+
+        let mut reversed = Vec::new();
+        assert_eq!(reverser.read_to_end(&mut reversed).ok(), Some(0));
+        assert!(reverser.into_inner().into_inner().is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+use std::io;
+
+/// Enumeration of the possible methods to seek within a [`RevSeek`]er.
+///
+/// It's the read-back counterpart of [`SeekFrom`]: the offsets are measured in the direction of reading back, so
+/// [`Back(0)`] is right behind the last byte, which is the first one to be read back.
+///
+/// [`SeekFrom`]: std::io::SeekFrom
+/// [`Back(0)`]: RevSeekFrom::Back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RevSeekFrom {
+    /// Sets the position to the given amount of bytes from the start, so exactly these bytes are left to be read
+    /// back.
+    Front(u64),
+    /// Sets the position to the given amount of bytes in front of the end, so these bytes are skipped.
+    Back(u64),
+    /// Moves the position by the given amount of bytes. Positive values move it further towards the front (like
+    /// reading back does), negative values back towards the end.
+    Current(i64),
+}
+
+/// A reader which can be repositioned while reading back.
+///
+/// It's the read-back counterpart of [`Seek`].
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use read_collection::{BufReadBack, BufReadBacker, ReadBack, RevSeek, RevSeekFrom};
+///
+/// fn main() {
+///     let mut reader = BufReadBacker::new(Cursor::new(b"header|body|footer")).unwrap();
+///
+///     // skip the footer
+///     assert_eq!(reader.rev_seek(RevSeekFrom::Back(7)).ok(), Some(7));
+///
+///     let mut body = Vec::new();
+///     reader.read_back_until(b'|', &mut body).unwrap();
+///     assert_eq!(body, b"|body");
+/// }
+/// ```
+///
+/// [`Seek`]: std::io::Seek
+pub trait RevSeek {
+    /// Seeks to the given position and returns the new position, measured as the amount of bytes in front of the
+    /// end of the source.
+    ///
+    /// # Errors
+    /// Seeking in front of the start or behind the end of the source returns an [`ErrorKind::InvalidInput`] error,
+    /// the position isn't changed in this case.
+    ///
+    /// [`ErrorKind::InvalidInput`]: std::io::ErrorKind::InvalidInput
+    fn rev_seek(&mut self, pos: RevSeekFrom) -> io::Result<u64>;
+}
+
+pub(crate) fn invalid_rev_seek_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "invalid seek to a position outside of the source",
+    )
+}
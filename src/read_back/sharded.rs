@@ -0,0 +1,149 @@
+use std::{
+    fs::{self, File},
+    io::{self, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use crate::ReadBack;
+
+/// A [`ReadBack`]er over a directory of shard files of an append-only store, like rotated logs.
+///
+/// The shards are ordered by their modification time, so reading back starts at the end of the newest shard and
+/// goes on with the end of the next older shard once the start of a shard has been reached. This is the same as
+/// reading back the concatenation of all shards, from the oldest to the newest one.
+///
+/// The shards are discovered when the reader is created, shards which are added or removed afterwards aren't
+/// noticed.
+///
+/// # Example
+/// ```no_run
+/// use read_collection::{ReadBack, RevShardedReader};
+///
+/// fn main() {
+///     let mut reader =
+///         RevShardedReader::open("/var/log/app", |name| name.starts_with("app.log")).unwrap();
+///
+///     let mut newest = [0; 64];
+///     reader.read_back_exact(&mut newest).unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RevShardedReader {
+    /// The shards which haven't been read back completely yet, the newest one is the last one.
+    shards: Vec<(PathBuf, File)>,
+}
+
+impl RevShardedReader {
+    /// Opens all files in `dir` whose name is accepted by `filter` and orders them by their modification time.
+    /// Shards with the same modification time are ordered by their name.
+    pub fn open<P, F>(dir: P, filter: F) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+        F: Fn(&str) -> bool,
+    {
+        let mut shards = Vec::new();
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let accepted = entry.file_name().to_str().is_some_and(&filter);
+            if !accepted || !entry.file_type()?.is_file() {
+                continue;
+            }
+
+            let modified = entry.metadata()?.modified()?;
+            shards.push((modified, entry.path()));
+        }
+        shards.sort();
+
+        let shards = shards
+            .into_iter()
+            .map(|(_modified, path)| {
+                let mut file = File::open(&path)?;
+                file.seek(SeekFrom::End(0))?;
+                Ok((path, file))
+            })
+            .collect::<io::Result<Vec<(PathBuf, File)>>>()?;
+
+        Ok(Self { shards })
+    }
+
+    /// Returns the paths of the shards which haven't been read back completely yet, newest shard first.
+    pub fn shards(&self) -> impl Iterator<Item = &Path> {
+        self.shards.iter().rev().map(|(path, _file)| path.as_path())
+    }
+}
+
+impl ReadBack for RevShardedReader {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while let Some((_path, shard)) = self.shards.last_mut() {
+            match shard.read_back(buf)? {
+                0 if !buf.is_empty() => {
+                    self.shards.pop();
+                }
+                amount => return Ok(amount),
+            }
+        }
+
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_back::temp_path::TempPath;
+    use std::time::{Duration, SystemTime};
+
+    /// Creates a directory with the shards which have been modified in the given order.
+    fn create_shards(name: &str, shards: &[(&str, &[u8])]) -> TempPath {
+        let dir = TempPath::new(name);
+        fs::create_dir_all(&dir).unwrap();
+
+        let start = SystemTime::now() - Duration::from_secs(3600);
+        for (i, (name, content)) in shards.iter().enumerate() {
+            let path = dir.join(name);
+            fs::write(&path, content).unwrap();
+
+            let file = File::options().write(true).open(&path).unwrap();
+            file.set_modified(start + Duration::from_secs(60 * i as u64))
+                .unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn newest_shard_first() {
+        // the names are ordered the other way around to make sure the modification time is used
+        let dir = create_shards(
+            "sharded_mtime",
+            &[
+                ("c.log", b"first\n"),
+                ("b.log", b"second\n"),
+                ("a.log", b"third\n"),
+            ],
+        );
+        fs::write(dir.join("ignored.txt"), b"ignored\n").unwrap();
+
+        let mut reader = RevShardedReader::open(&dir, |name| name.ends_with(".log")).unwrap();
+        assert_eq!(
+            reader.shards().collect::<Vec<&Path>>(),
+            [dir.join("a.log"), dir.join("b.log"), dir.join("c.log")]
+        );
+
+        let mut content = Vec::new();
+        reader.read_back_to_end(&mut content).unwrap();
+        assert_eq!(&content, b"first\nsecond\nthird\n");
+        assert_eq!(reader.shards().count(), 0);
+    }
+
+    #[test]
+    fn read_back_across_shards() {
+        let dir = create_shards("sharded_across", &[("0", b"abc"), ("1", b""), ("2", b"de")]);
+        let mut reader = RevShardedReader::open(&dir, |_| true).unwrap();
+
+        let mut buffer = [0; 4];
+        reader.read_back_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer, b"bcde");
+    }
+}
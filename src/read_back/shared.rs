@@ -0,0 +1,198 @@
+use std::{
+    cmp, io,
+    sync::{Arc, RwLock},
+};
+
+use crate::{BufReadBack, ReadBack, DEFAULT_BUF_SIZE};
+
+/// A [`BufReadBack`]er over a buffer which is shared with other threads, like an in-memory log which a writer
+/// thread keeps appending to (or truncating).
+///
+/// The reader starts at the end of the buffer at the time of its creation. The lock is only held while a chunk
+/// of the buffer is copied into the internal buffer of the reader, so writers can change the shared buffer
+/// between two reads. Bytes which are appended after the reader has been created aren't read back.
+///
+/// If the shared buffer gets truncated below the position of the reader, the reader is at its end and returns
+/// no more bytes.
+///
+/// # Example
+/// ```
+/// use std::sync::{Arc, RwLock};
+/// use read_collection::{ReadBack, SharedVecReadBacker};
+///
+/// fn main() {
+///     let log = Arc::new(RwLock::new(b"first\nsecond\n".to_vec()));
+///     let mut reader = SharedVecReadBacker::new(Arc::clone(&log)).unwrap();
+///
+///     // the writer goes on while we're reading back
+///     log.write().unwrap().extend_from_slice(b"third\n");
+///
+///     let mut content = Vec::new();
+///     reader.read_back_to_end(&mut content).unwrap();
+///     assert_eq!(&content, b"first\nsecond\n");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SharedVecReadBacker {
+    inner: Arc<RwLock<Vec<u8>>>,
+    /// The index (exclusively) of the shared buffer until which it hasn't been copied yet.
+    pos: usize,
+    /// The chunk which has been copied out of the shared buffer.
+    buf: Box<[u8]>,
+    /// The index (exclusively) until which `buf` hasn't been read back yet.
+    buf_pos: usize,
+}
+
+impl SharedVecReadBacker {
+    /// Creates a new `SharedVecReadBacker` which copies at most 8 KiB per lock.
+    pub fn new(inner: Arc<RwLock<Vec<u8>>>) -> io::Result<Self> {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `SharedVecReadBacker` which copies at most `capacity` bytes per lock.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    pub fn with_capacity(capacity: usize, inner: Arc<RwLock<Vec<u8>>>) -> io::Result<Self> {
+        assert!(capacity > 0, "capacity has to be greater than 0");
+        let pos = inner.read().map_err(|_| poisoned_error())?.len();
+
+        Ok(Self {
+            inner,
+            pos,
+            buf: vec![0; capacity].into_boxed_slice(),
+            buf_pos: 0,
+        })
+    }
+
+    /// Returns the index of the shared buffer until which it has been read back.
+    pub fn position(&self) -> usize {
+        self.pos + self.buf_pos
+    }
+
+    /// Gets a reference to the shared buffer.
+    pub fn get_ref(&self) -> &Arc<RwLock<Vec<u8>>> {
+        &self.inner
+    }
+
+    /// Unwraps this `SharedVecReadBacker`, returning the shared buffer.
+    pub fn into_inner(self) -> Arc<RwLock<Vec<u8>>> {
+        self.inner
+    }
+}
+
+fn poisoned_error() -> io::Error {
+    io::Error::other("the lock of the shared buffer is poisoned")
+}
+
+impl ReadBack for SharedVecReadBacker {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.read_back_fill_buf()?.read_back(buf)?;
+        self.read_back_consume(amount);
+
+        Ok(amount)
+    }
+}
+
+impl BufReadBack for SharedVecReadBacker {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.buf_pos == 0 && self.pos > 0 {
+            let shared = self.inner.read().map_err(|_| poisoned_error())?;
+
+            if shared.len() < self.pos {
+                // the buffer has been truncated in front of us
+                self.pos = 0;
+            } else {
+                let amount = cmp::min(self.pos, self.buf.len());
+                let start = self.pos - amount;
+
+                self.buf[..amount].copy_from_slice(&shared[start..self.pos]);
+                self.buf_pos = amount;
+                self.pos = start;
+            }
+        }
+
+        Ok(&self.buf[..self.buf_pos])
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.buf_pos = self.buf_pos.saturating_sub(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn shared(data: &[u8]) -> Arc<RwLock<Vec<u8>>> {
+        Arc::new(RwLock::new(data.to_vec()))
+    }
+
+    #[test]
+    fn read_back_in_chunks() {
+        let mut reader = SharedVecReadBacker::with_capacity(2, shared(b"12345")).unwrap();
+
+        assert_eq!(reader.read_back_fill_buf().ok(), Some(b"45".as_slice()));
+        reader.read_back_consume(2);
+        assert_eq!(reader.position(), 3);
+
+        let mut content = Vec::new();
+        assert_eq!(reader.read_back_to_end(&mut content).ok(), Some(3));
+        assert_eq!(&content, b"123");
+    }
+
+    #[test]
+    fn concurrent_writer() {
+        let log = shared(b"");
+        for i in 0..100u8 {
+            log.write().unwrap().push(i);
+        }
+
+        let mut reader = SharedVecReadBacker::with_capacity(3, Arc::clone(&log)).unwrap();
+        let writer = {
+            let log = Arc::clone(&log);
+            thread::spawn(move || {
+                for i in 100..200u8 {
+                    log.write().unwrap().push(i);
+                    thread::yield_now();
+                }
+            })
+        };
+
+        let mut content = Vec::new();
+        reader.read_back_to_end(&mut content).unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(content, (0..100).collect::<Vec<u8>>());
+        assert_eq!(log.read().unwrap().len(), 200);
+    }
+
+    #[test]
+    fn truncated_below_position() {
+        let log = shared(b"first\nsecond\n");
+        let mut reader = SharedVecReadBacker::with_capacity(7, Arc::clone(&log)).unwrap();
+
+        // the first chunk is copied before the truncation
+        assert_eq!(
+            reader.read_back_fill_buf().ok(),
+            Some(b"second\n".as_slice())
+        );
+        reader.read_back_consume(7);
+
+        let writer = {
+            let log = Arc::clone(&log);
+            thread::spawn(move || log.write().unwrap().truncate(2))
+        };
+        writer.join().unwrap();
+
+        assert_eq!(reader.read_back_fill_buf().ok(), Some(b"".as_slice()));
+        assert_eq!(reader.position(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity has to be greater than 0")]
+    fn zero_capacity() {
+        let _ = SharedVecReadBacker::with_capacity(0, shared(b"data"));
+    }
+}
@@ -0,0 +1,318 @@
+use std::{
+    borrow::Cow,
+    io::{self, Read},
+    process::ChildStdout,
+};
+
+use crate::{read_back::footer_too_short_error, BufReadBack, ReadBack};
+
+/// A [`ReadBack`]er for sources which can't seek, like pipes or sockets.
+///
+/// Reading back requires to go from the end of the source towards its start, which isn't possible with a plain
+/// [`Read`]er. `ReadBackSlurp` therefore reads ("slurps") everything which is left in the source into memory first
+/// and reads it back from there.
+///
+/// # Example
+/// ```
+/// use std::io::{BufReader, Read};
+/// use read_collection::{BufReadBack, ReadBackSlurp};
+///
+/// fn main() {
+///     let source = b"HEADfirst\nsecond\n";
+///     let mut reader = BufReader::new(source.as_slice());
+///
+///     // parse the header forward...
+///     let mut header = [0; 4];
+///     reader.read_exact(&mut header).unwrap();
+///     assert_eq!(&header, b"HEAD");
+///
+///     // ... and read the body back
+///     let mut body = ReadBackSlurp::from_reader_buffered(reader).unwrap();
+///     let mut last_line = Vec::new();
+///     body.read_back_skip_until(b'\n').unwrap();
+///     body.read_back_until(b'\n', &mut last_line).unwrap();
+///     assert_eq!(&last_line, b"\nsecond");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReadBackSlurp {
+    buf: Vec<u8>,
+    /// The index (exclusively) until which `buf` hasn't been read back yet.
+    pos: usize,
+}
+
+impl ReadBackSlurp {
+    /// Reads all remaining bytes of `reader` into memory so that they can be read back.
+    ///
+    /// Only the bytes which haven't been read from `reader` yet are read back. If `reader` is a
+    /// [`BufReader`] which has already been used, the bytes which are still in its buffer are included, so
+    /// it's fine to parse some bytes forward first and to pass the `BufReader` afterwards.
+    ///
+    /// [`BufReader`]: std::io::BufReader
+    pub fn from_reader_buffered<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        Ok(Self::from(buf))
+    }
+
+    /// Reads the whole output of a child process into memory so that it can be read back, for example to look
+    /// at the tail of the output first.
+    ///
+    /// This waits until the child closes its stdout, which usually happens when it exits.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::process::{Command, Stdio};
+    /// use read_collection::{BufReadBack, ReadBackSlurp};
+    ///
+    /// fn main() {
+    ///     let mut child = Command::new("ls").stdout(Stdio::piped()).spawn().unwrap();
+    ///
+    ///     let output = ReadBackSlurp::from_child_stdout(child.stdout.take().unwrap()).unwrap();
+    ///     let last_line = output.read_back_lines().next();
+    ///     child.wait().unwrap();
+    /// }
+    /// ```
+    pub fn from_child_stdout(stdout: ChildStdout) -> io::Result<Self> {
+        Self::from_reader_buffered(stdout)
+    }
+
+    /// Returns the bytes which haven't been read back yet.
+    pub fn get_ref(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Consumes the `ReadBackSlurp`, returning all slurped bytes, including the ones which have already been
+    /// read back.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Reads back the previous line without copying it if possible and returns `None` at the start of the
+    /// slurped bytes.
+    ///
+    /// The `\n` (or `\r\n`) in front of the line is read back as well but isn't part of the returned line, so a
+    /// newline at the very end yields an empty line first, like [`read_back_line`] does. Since all bytes are in
+    /// memory, the line is borrowed from the `ReadBackSlurp` as long as it's valid UTF-8. Otherwise invalid
+    /// sequences are replaced like in [`String::from_utf8_lossy`], which allocates.
+    ///
+    /// This isn't a method of [`BufReadBack`] since a generic reader has to consume the line before returning,
+    /// which ends the borrow of its buffer, so it could only hand out owned lines. A [`BufReadBacker`] borrows the
+    /// buffered bytes of a line with [`read_back_line_ref`] instead, and [`read_back_records`] borrows each record
+    /// until the next one is read back.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use read_collection::ReadBackSlurp;
+    ///
+    /// fn main() {
+    ///     let mut slurp = ReadBackSlurp::from(b"first\r\nsecond".to_vec());
+    ///
+    ///     assert_eq!(slurp.read_back_line_cow().unwrap(), Some(Cow::Borrowed("second")));
+    ///     assert_eq!(slurp.read_back_line_cow().unwrap(), Some(Cow::Borrowed("first")));
+    ///     assert_eq!(slurp.read_back_line_cow().unwrap(), None);
+    /// }
+    /// ```
+    ///
+    /// [`read_back_line`]: BufReadBack::read_back_line
+    /// [`BufReadBacker`]: crate::BufReadBacker
+    /// [`read_back_line_ref`]: crate::BufReadBacker::read_back_line_ref
+    /// [`read_back_records`]: BufReadBack::read_back_records
+    pub fn read_back_line_cow(&mut self) -> io::Result<Option<Cow<'_, str>>> {
+        if self.pos == 0 {
+            return Ok(None);
+        }
+
+        let end = self.pos;
+        let start = match memchr::memrchr(b'\n', &self.buf[..end]) {
+            Some(newline) => {
+                self.pos = newline;
+                if newline > 0 && self.buf[newline - 1] == b'\r' {
+                    self.pos -= 1;
+                }
+                newline + 1
+            }
+            None => {
+                self.pos = 0;
+                0
+            }
+        };
+
+        Ok(Some(String::from_utf8_lossy(&self.buf[start..end])))
+    }
+}
+
+/// Reads back the bytes of the vector, starting at its end.
+impl From<Vec<u8>> for ReadBackSlurp {
+    fn from(buf: Vec<u8>) -> Self {
+        let pos = buf.len();
+        Self { buf, pos }
+    }
+}
+
+impl ReadBack for ReadBackSlurp {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = self.get_ref().read_back(buf)?;
+        self.pos -= amount;
+
+        Ok(amount)
+    }
+
+    fn read_back_count_bytes(&mut self) -> io::Result<u64> {
+        let amount = self.pos;
+        self.pos = 0;
+
+        Ok(amount as u64)
+    }
+
+    fn read_back_skip_footer(&mut self, n: usize) -> io::Result<()> {
+        if n > self.pos {
+            self.pos = 0;
+            return Err(footer_too_short_error());
+        }
+
+        self.pos -= n;
+        Ok(())
+    }
+}
+
+impl BufReadBack for ReadBackSlurp {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.get_ref())
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    /// A reader which can't seek, like a pipe.
+    struct Pipe<'a>(&'a [u8]);
+
+    impl Read for Pipe<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            // hand out at most two bytes at once to make `BufReader` work for it
+            let max = std::cmp::min(buf.len(), 2);
+            self.0.read(&mut buf[..max])
+        }
+    }
+
+    #[test]
+    fn header_forward_body_back() {
+        let source = b"\x00\x01\x02\x03body";
+        let mut reader = BufReader::with_capacity(3, Pipe(source));
+
+        let mut header = [0; 4];
+        reader.read_exact(&mut header).unwrap();
+        assert_eq!(header, [0, 1, 2, 3]);
+
+        let mut slurp = ReadBackSlurp::from_reader_buffered(reader).unwrap();
+        let mut buffer = [0; 4];
+        slurp.read_back_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer, b"body");
+        assert_eq!(slurp.read_back(&mut buffer).ok(), Some(0));
+    }
+
+    #[test]
+    fn consumed_reader() {
+        let mut reader = Pipe(b"data");
+        reader.read_to_end(&mut Vec::new()).unwrap();
+
+        let mut slurp = ReadBackSlurp::from_reader_buffered(reader).unwrap();
+        assert!(slurp.read_back_fill_buf().unwrap().is_empty());
+    }
+
+    #[test]
+    fn fill_buf_and_consume() {
+        let mut slurp = ReadBackSlurp::from(vec![1, 2, 3]);
+
+        assert_eq!(slurp.read_back_fill_buf().ok(), Some([1, 2, 3].as_slice()));
+        slurp.read_back_consume(2);
+        assert_eq!(slurp.read_back_fill_buf().ok(), Some([1].as_slice()));
+        slurp.read_back_consume(2);
+        assert!(slurp.read_back_fill_buf().unwrap().is_empty());
+
+        assert_eq!(slurp.into_inner(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn output_of_a_command() {
+        // stands in for the output of a child process
+        let output = Pipe(b"line 1\nline 2\nline 3\n");
+
+        let lines = ReadBackSlurp::from_reader_buffered(output)
+            .unwrap()
+            .read_back_lines()
+            .map(|line| line.unwrap())
+            .collect::<Vec<String>>();
+        assert_eq!(lines, ["line 3", "line 2", "line 1"]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn child_stdout() {
+        use std::process::{Command, Stdio};
+
+        let mut child = Command::new("printf")
+            .arg("first\\nsecond\\n")
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut slurp = ReadBackSlurp::from_child_stdout(child.stdout.take().unwrap()).unwrap();
+        assert!(child.wait().unwrap().success());
+
+        let mut last_line = Vec::new();
+        slurp.read_back_skip_until(b'\n').unwrap();
+        slurp.read_back_until(b'\n', &mut last_line).unwrap();
+        assert_eq!(&last_line, b"\nsecond");
+    }
+
+    mod read_back_line_cow {
+        use super::*;
+
+        #[test]
+        fn borrowed() {
+            let mut slurp = ReadBackSlurp::from(b"first\n\nthird\n".to_vec());
+
+            let expected = ["", "third", "", "first"];
+            for line in expected {
+                let cow = slurp.read_back_line_cow().unwrap().unwrap();
+                assert!(matches!(cow, Cow::Borrowed(_)));
+                assert_eq!(cow, line);
+            }
+        }
+
+        #[test]
+        fn owned() {
+            let mut slurp = ReadBackSlurp::from(b"valid\ninv\xFFalid".to_vec());
+
+            let cow = slurp.read_back_line_cow().unwrap().unwrap();
+            assert!(matches!(cow, Cow::Owned(_)));
+            assert_eq!(cow, "inv\u{FFFD}alid");
+
+            assert_eq!(
+                slurp.read_back_line_cow().unwrap(),
+                Some(Cow::Borrowed("valid"))
+            );
+        }
+
+        #[test]
+        fn none_at_the_start() {
+            let mut slurp = ReadBackSlurp::from(Vec::new());
+            assert_eq!(slurp.read_back_line_cow().unwrap(), None);
+
+            let mut slurp = ReadBackSlurp::from(b"line".to_vec());
+            assert!(slurp.read_back_line_cow().unwrap().is_some());
+            assert_eq!(slurp.read_back_line_cow().unwrap(), None);
+            assert_eq!(slurp.read_back_line_cow().unwrap(), None);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// A path in the temporary directory for the tests, whose file or directory is removed once it's dropped, even if
+/// the test panics.
+pub(crate) struct TempPath {
+    path: PathBuf,
+}
+
+impl TempPath {
+    /// Creates the path for the test `name`, without creating anything at it.
+    pub(crate) fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("read_collection_{}_{}", name, std::process::id()));
+
+        Self { path }
+    }
+}
+
+impl Deref for TempPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempPath {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = if self.path.is_dir() {
+            fs::remove_dir_all(&self.path)
+        } else {
+            fs::remove_file(&self.path)
+        };
+    }
+}
@@ -0,0 +1,204 @@
+//! Property tests for the core contract: reading back yields the same bytes and lines as reading forward,
+//! just in reversed order.
+use std::{
+    io::{self, BufRead, Cursor, Read},
+    sync::{Arc, RwLock},
+};
+
+use proptest::prelude::*;
+use read_collection::{
+    BufReadBack, BufReadBacker, ReadBack, ReadBackSlurp, RevCursor, RevRangeReader, RevToForward,
+    SharedVecReadBacker,
+};
+
+/// Strings which are full of line breaks, carriage returns and NULs.
+fn text() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a\r\n\0]{0,32}",
+        any::<String>(),
+        "[a-z ]{0,8}(\r?\n[a-z ]{0,8}){0,8}\n?",
+    ]
+}
+
+/// Consumes at most `step` bytes after each `read_back_fill_buf` call and checks that the buffer only becomes empty
+/// once all `len` bytes have been read back.
+fn assert_fill_buf_empty_only_at_front<R: BufReadBack>(
+    name: &str,
+    mut reader: R,
+    len: usize,
+    step: usize,
+) -> Result<(), TestCaseError> {
+    let mut consumed = 0;
+    loop {
+        let available = reader.read_back_fill_buf().unwrap().len();
+        if available == 0 {
+            break;
+        }
+
+        let amount = available.min(step);
+        reader.read_back_consume(amount);
+        consumed += amount;
+    }
+
+    prop_assert_eq!(consumed, len, "{}", name);
+    prop_assert!(reader.read_back_fill_buf().unwrap().is_empty(), "{}", name);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn read_back_fill_buf_is_empty_only_at_front(
+        data in any::<Vec<u8>>(),
+        capacity in 1usize..16,
+        step in 1usize..16,
+    ) {
+        let len = data.len();
+
+        assert_fill_buf_empty_only_at_front("&[u8]", data.as_slice(), len, step)?;
+        assert_fill_buf_empty_only_at_front("RevCursor", RevCursor::new(data.as_slice()), len, step)?;
+        assert_fill_buf_empty_only_at_front(
+            "ReadBackSlurp",
+            ReadBackSlurp::from_reader_buffered(data.as_slice()).unwrap(),
+            len,
+            step,
+        )?;
+
+        let mut cursor = Cursor::new(data.as_slice());
+        cursor.set_position(len as u64);
+        assert_fill_buf_empty_only_at_front("Cursor<&[u8]>", cursor, len, step)?;
+
+        let mut owned = data.clone();
+        let mut cursor = Cursor::new(owned.as_mut_slice());
+        cursor.set_position(len as u64);
+        assert_fill_buf_empty_only_at_front("Cursor<&mut [u8]>", cursor, len, step)?;
+
+        let mut cursor = Cursor::new(data.clone());
+        cursor.set_position(len as u64);
+        assert_fill_buf_empty_only_at_front("Cursor<Vec<u8>>", cursor, len, step)?;
+
+        assert_fill_buf_empty_only_at_front(
+            "BufReadBacker",
+            BufReadBacker::with_capacity(capacity, Cursor::new(data.as_slice())).unwrap(),
+            len,
+            step,
+        )?;
+        assert_fill_buf_empty_only_at_front(
+            "SharedVecReadBacker",
+            SharedVecReadBacker::with_capacity(capacity, Arc::new(RwLock::new(data.clone()))).unwrap(),
+            len,
+            step,
+        )?;
+
+        // responds with a single byte at most, so every chunk needs several requests
+        let fetch = |range: std::ops::Range<u64>| -> io::Result<Vec<u8>> {
+            let start = range.start as usize;
+            Ok(data[start..start + 1].to_vec())
+        };
+        assert_fill_buf_empty_only_at_front(
+            "RevRangeReader",
+            RevRangeReader::with_chunk_size(capacity as u64, len as u64, fetch),
+            len,
+            step,
+        )?;
+
+        let (front, back) = data.split_at(len / 2);
+        assert_fill_buf_empty_only_at_front("ReadBackChain", front.read_back_chain(back), len, step)?;
+        assert_fill_buf_empty_only_at_front(
+            "ReadBackTake",
+            data.as_slice().read_back_take(capacity as u64),
+            len.min(capacity),
+            step,
+        )?;
+        assert_fill_buf_empty_only_at_front("Empty", io::empty(), 0, step)?;
+    }
+
+    #[test]
+    fn read_back_to_end_same_as_read_to_end(data in any::<Vec<u8>>()) {
+        let mut forward = Vec::new();
+        data.as_slice().read_to_end(&mut forward).unwrap();
+
+        let mut back = Vec::new();
+        data.as_slice().read_back_to_end(&mut back).unwrap();
+        prop_assert_eq!(&back, &forward);
+
+        let mut cursor = Cursor::new(data.as_slice());
+        cursor.set_position(data.len() as u64);
+        let mut back = Vec::new();
+        cursor.read_back_to_end(&mut back).unwrap();
+        prop_assert_eq!(&back, &forward);
+    }
+
+    #[test]
+    fn read_back_to_end_appends_like_read_to_end(prefix in any::<Vec<u8>>(), data in any::<Vec<u8>>()) {
+        let mut forward = prefix.clone();
+        data.as_slice().read_to_end(&mut forward).unwrap();
+
+        // goes through the default implementation
+        let mut back = prefix;
+        let amount = data.as_slice().read_back_take(u64::MAX).read_back_to_end(&mut back).unwrap();
+        prop_assert_eq!(amount, data.len());
+        prop_assert_eq!(&back, &forward);
+    }
+
+    #[test]
+    fn read_back_bytes_are_reversed(data in any::<Vec<u8>>()) {
+        let mut back = data
+            .as_slice()
+            .read_back_bytes()
+            .collect::<std::io::Result<Vec<u8>>>()
+            .unwrap();
+        back.reverse();
+        prop_assert_eq!(&back, &data);
+
+        let mut back = data
+            .as_slice()
+            .read_back_bytes_buffered()
+            .collect::<std::io::Result<Vec<u8>>>()
+            .unwrap();
+        back.reverse();
+        prop_assert_eq!(&back, &data);
+    }
+
+    #[test]
+    fn rev_to_forward_is_reversed(data in any::<Vec<u8>>()) {
+        let mut reversed = Vec::new();
+        RevToForward::new(data.as_slice()).read_to_end(&mut reversed).unwrap();
+        reversed.reverse();
+
+        prop_assert_eq!(&reversed, &data);
+    }
+
+    #[test]
+    fn read_back_lines_same_as_lines(data in text()) {
+        let forward = data
+            .as_bytes()
+            .lines()
+            .collect::<std::io::Result<Vec<String>>>()
+            .unwrap();
+
+        let mut back = data
+            .as_bytes()
+            .read_back_lines()
+            .collect::<std::io::Result<Vec<String>>>()
+            .unwrap();
+        back.reverse();
+
+        prop_assert_eq!(back, forward);
+    }
+
+    #[test]
+    fn read_back_split_same_as_split(data in "[a\0]{0,32}", capacity in 1usize..8) {
+        let mut forward = BufRead::split(data.as_bytes(), b'\0')
+            .collect::<std::io::Result<Vec<Vec<u8>>>>()
+            .unwrap();
+        forward.reverse();
+
+        let back = BufReadBacker::with_capacity(capacity, Cursor::new(data.as_bytes()))
+            .unwrap()
+            .read_back_split(b'\0')
+            .collect::<std::io::Result<Vec<Vec<u8>>>>()
+            .unwrap();
+
+        prop_assert_eq!(back, forward);
+    }
+}
@@ -1 +1,35 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+mod pipe;
 mod same_as_read;
+mod sparse;
+mod utf8;
+
+/// A file in the temporary directory which is removed once it's dropped, even if the test panics.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    /// Creates the temporary file for the test `name` with the given content.
+    fn new(name: &str, content: &[u8]) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("read_collection_{}_{}", name, std::process::id()));
+        fs::write(&path, content).unwrap();
+
+        Self { path }
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(unix)]
+
+use std::{
+    fs::File,
+    io::{ErrorKind, Write},
+    os::fd::OwnedFd,
+};
+
+use read_collection::{ReadBack, ReadBackSlurp};
+
+#[test]
+fn read_back_pipe_suggests_slurp() {
+    let (reader, mut writer) = std::io::pipe().unwrap();
+    writer.write_all(b"some data").unwrap();
+    drop(writer);
+
+    let mut file = File::from(OwnedFd::from(reader));
+    let mut buffer = [0; 4];
+    let err = file.read_back(&mut buffer).unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    assert!(
+        err.to_string()
+            .contains("ReadBackSlurp::from_reader_buffered"),
+        "unexpected error message: {}",
+        err
+    );
+
+    // ... and the suggestion actually works
+    let mut slurp = ReadBackSlurp::from_reader_buffered(file).unwrap();
+    slurp.read_back_exact(&mut buffer).unwrap();
+    assert_eq!(&buffer, b"data");
+}
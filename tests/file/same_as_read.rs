@@ -1,9 +1,13 @@
 use std::{
     fs::File,
-    io::{Read, Seek},
+    io::{BufRead, BufReader, IoSliceMut, Read, Seek},
 };
 
-use read_collection::ReadBack;
+use read_collection::{
+    read_back_copy, BufReadBack, BufReadBacker, LineEnding, ReadBack, RevSeek, RevSeekFrom,
+};
+
+use super::TempFile;
 
 fn get_file1() -> File {
     File::open("./tests/file/test_file1.txt").unwrap()
@@ -20,7 +24,7 @@ fn read_vs_rev_read() {
     let mut read_buffer = [0u8; 5];
     let mut rev_read_buffer = read_buffer;
 
-    file.read(&mut read_buffer).unwrap();
+    file.read_exact(&mut read_buffer).unwrap();
     file.read_back(&mut rev_read_buffer).unwrap();
 
     assert_eq!(read_buffer, rev_read_buffer);
@@ -46,6 +50,20 @@ fn read_to_end_vs_rev_read_to_end() {
     assert_eq!(read_amount, rev_read_amount);
 }
 
+#[test]
+fn read_back_to_end_vs_fs_read() {
+    let content = std::fs::read("./tests/file/test_file2.txt").unwrap();
+    let mut file = get_file2();
+    file.seek(std::io::SeekFrom::End(0)).unwrap();
+
+    let mut buffer = b"existing".to_vec();
+    let amount = file.read_back_to_end(&mut buffer).unwrap();
+
+    assert_eq!(amount, content.len());
+    assert_eq!(&buffer[..8], b"existing");
+    assert_eq!(&buffer[8..], content.as_slice());
+}
+
 #[test]
 fn read_to_string_vs_rev_read_to_string() {
     let mut file = get_file1();
@@ -79,7 +97,10 @@ fn read_bytes_vs_rev_read_bytes() {
     let mut file2 = get_file1();
     file2.seek(std::io::SeekFrom::End(0)).unwrap();
 
-    let read_buffer = file.bytes().map(|b| b.unwrap()).collect::<Vec<u8>>();
+    let read_buffer = BufReader::new(file)
+        .bytes()
+        .map(|b| b.unwrap())
+        .collect::<Vec<u8>>();
     // todo!("Issue: If the cursor reached the start of the file => How do we differ between the first time we reach there and 'ok, we've read all bytes now'?");
     let mut rev_read_buffer = file2
         .read_back_bytes()
@@ -102,7 +123,7 @@ fn read_chain_vs_rev_read_chain() {
     rev_read_file2.seek(std::io::SeekFrom::End(0)).unwrap();
 
     let mut read_chain = read_file1.chain(read_file2);
-    let mut rev_read_chain = rev_read_file2.read_back_chain(rev_read_file1);
+    let mut rev_read_chain = rev_read_file1.read_back_chain(rev_read_file2);
 
     let mut read_buffer = Vec::new();
     let mut rev_read_buffer = Vec::new();
@@ -114,3 +135,234 @@ fn read_chain_vs_rev_read_chain() {
 
     assert_eq!(read_buffer, rev_read_buffer);
 }
+
+#[test]
+fn read_back_to_end_tracked_from_mid_stream() {
+    let mut file = get_file1();
+    let mut read_buffer = [0u8; 20];
+    file.read_exact(&mut read_buffer).unwrap();
+
+    let mut rev_read_buffer = Vec::new();
+    assert_eq!(
+        file.read_back_to_end_tracked(&mut rev_read_buffer).ok(),
+        Some((read_buffer.len(), 0))
+    );
+    assert_eq!(read_buffer.as_slice(), rev_read_buffer.as_slice());
+}
+
+#[test]
+fn read_back_to_end_tracked_with_limit() {
+    let content = std::fs::read("./tests/file/test_file1.txt").unwrap();
+    let mut file = get_file1();
+    let end = file.seek(std::io::SeekFrom::End(0)).unwrap();
+
+    let mut take = file.read_back_take(8);
+    let mut rev_read_buffer = Vec::new();
+    assert_eq!(
+        take.read_back_to_end_tracked(&mut rev_read_buffer).ok(),
+        Some((8, end - 8))
+    );
+    assert_eq!(rev_read_buffer.as_slice(), &content[content.len() - 8..]);
+}
+
+#[test]
+fn read_back_count_bytes_from_end() {
+    let mut file = get_file1();
+    let end = file.seek(std::io::SeekFrom::End(0)).unwrap();
+
+    assert_eq!(file.read_back_count_bytes().ok(), Some(end));
+    assert_eq!(file.stream_position().ok(), Some(0));
+}
+
+#[test]
+fn read_back_skip_footer_seeks() {
+    let mut file = get_file1();
+    let end = file.seek(std::io::SeekFrom::End(0)).unwrap();
+
+    file.read_back_skip_footer(16).unwrap();
+    assert_eq!(file.stream_position().ok(), Some(end - 16));
+
+    let err = file.read_back_skip_footer(end as usize).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    assert_eq!(file.stream_position().ok(), Some(0));
+}
+
+#[test]
+fn read_back_index_records_vs_forward_index() {
+    let content = std::fs::read("./tests/file/test_file1.txt").unwrap();
+    let mut reader = read_collection::ReadBackSlurp::from_reader_buffered(get_file1()).unwrap();
+
+    let index = reader.read_back_index_records(b'\n').unwrap();
+
+    let mut lines = BufReader::new(content.as_slice())
+        .lines()
+        .map(|line| line.unwrap());
+    for &(start, end) in index.iter().rev() {
+        let line = &content[start as usize..end as usize];
+        assert_eq!(line, lines.next().unwrap().as_bytes());
+    }
+    assert!(lines.next().is_none());
+}
+
+#[test]
+fn buf_read_backer_lines_vs_lines() {
+    let reader = read_collection::BufReadBacker::with_capacity(7, get_file2()).unwrap();
+
+    let mut rev_lines = reader
+        .read_back_lines()
+        .map(|line| line.unwrap())
+        .collect::<Vec<String>>();
+    rev_lines.reverse();
+
+    let lines = BufReader::new(get_file2())
+        .lines()
+        .map(|line| line.unwrap())
+        .collect::<Vec<String>>();
+    assert_eq!(rev_lines, lines);
+}
+
+#[test]
+fn read_back_temp_file_in_chunks() {
+    let content = (0..10_000u32).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+    let temp = TempFile::new("read_back_temp_file_in_chunks", &content);
+
+    let mut file = File::open(temp.path()).unwrap();
+    file.seek(std::io::SeekFrom::End(0)).unwrap();
+
+    let mut chunks = Vec::new();
+    let mut buffer = [0u8; 777];
+    loop {
+        let amount = file.read_back(&mut buffer).unwrap();
+        if amount == 0 {
+            break;
+        }
+        chunks.push(buffer[..amount].to_vec());
+    }
+    chunks.reverse();
+    assert_eq!(chunks.concat(), content);
+
+    // doesn't need to seek to the end first
+    let mut rev_read_buffer = Vec::new();
+    BufReadBacker::new(File::open(temp.path()).unwrap())
+        .unwrap()
+        .read_back_to_end(&mut rev_read_buffer)
+        .unwrap();
+
+    assert_eq!(rev_read_buffer, content);
+}
+
+#[test]
+fn rev_seek_vs_file_contents() {
+    let content = std::fs::read("./tests/file/test_file1.txt").unwrap();
+    let mut reader = BufReadBacker::with_capacity(16, get_file1()).unwrap();
+
+    for back in [0, 1, 17, content.len() as u64 / 2, content.len() as u64] {
+        assert_eq!(reader.rev_seek(RevSeekFrom::Back(back)).ok(), Some(back));
+
+        let expected_len = std::cmp::min(content.len() - back as usize, 40);
+        let mut buffer = vec![0; expected_len];
+        reader.read_back_exact(&mut buffer).unwrap();
+
+        let end = content.len() - back as usize;
+        assert_eq!(buffer, &content[end - expected_len..end], "back: {}", back);
+    }
+}
+
+#[test]
+fn read_back_vectored_vs_file_contents() {
+    let content = std::fs::read("./tests/file/test_file1.txt").unwrap();
+    let mut file = get_file1();
+    file.seek(std::io::SeekFrom::End(0)).unwrap();
+
+    let (mut a, mut b, mut c) = ([0u8; 7], [0u8; 3], [0u8; 11]);
+    let amount = file
+        .read_back_vectored(&mut [
+            IoSliceMut::new(&mut a),
+            IoSliceMut::new(&mut b),
+            IoSliceMut::new(&mut c),
+        ])
+        .unwrap();
+    assert_eq!(amount, 21);
+    assert_eq!(
+        [&a[..], &b[..], &c[..]].concat(),
+        &content[content.len() - 21..]
+    );
+
+    let mut rest = Vec::new();
+    file.read_back_to_end(&mut rest).unwrap();
+    assert_eq!(rest, &content[..content.len() - 21]);
+}
+
+#[test]
+fn read_back_copy_temp_file() {
+    let content = (0..20_000u32).map(|i| (i % 241) as u8).collect::<Vec<u8>>();
+    let temp = TempFile::new("read_back_copy_temp_file", &content);
+
+    let mut file = File::open(temp.path()).unwrap();
+    file.seek(std::io::SeekFrom::End(0)).unwrap();
+
+    let mut copied = Vec::new();
+    let amount = read_back_copy(&mut file, &mut copied).unwrap();
+    assert_eq!(amount, content.len() as u64);
+    assert_eq!(copied, content);
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn async_read_back_to_end_vs_fs_read() {
+    use read_collection::{AsyncBufReadBacker, AsyncReadBackExt};
+
+    let content = (0..20_000u32).map(|i| (i % 239) as u8).collect::<Vec<u8>>();
+    let temp = TempFile::new("async_read_back_to_end_vs_fs_read", &content);
+
+    let file = tokio::fs::File::open(temp.path()).await.unwrap();
+    let mut reader = AsyncBufReadBacker::with_capacity(1000, file).await.unwrap();
+
+    let mut tail = [0; 1500];
+    reader.read_back_exact(&mut tail).await.unwrap();
+
+    let mut rest = Vec::new();
+    let amount = reader.read_back_to_end(&mut rest).await.unwrap();
+    assert_eq!(tail, content[content.len() - 1500..]);
+    assert_eq!(amount, content.len() - 1500);
+    assert_eq!(rest, content[..content.len() - 1500]);
+}
+
+#[test]
+fn line_endings_of_mixed_file() {
+    let content = (0..500)
+        .map(|i| match i % 3 {
+            0 => format!("unix {}\n", i),
+            1 => format!("windows {}\r\n", i),
+            _ => format!("old mac {}\r", i),
+        })
+        .collect::<String>();
+    let temp = TempFile::new("line_endings_of_mixed_file", content.as_bytes());
+
+    let lines_with = |ending: LineEnding| {
+        BufReadBacker::with_capacity(64, File::open(temp.path()).unwrap())
+            .unwrap()
+            .read_back_lines()
+            .line_ending(ending)
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    };
+    let any = lines_with(LineEnding::Any);
+    let crlf = lines_with(LineEnding::CrLf);
+
+    let mut expected_any = content
+        .split_inclusive(['\n', '\r'])
+        .map(|line| line.trim_end_matches(['\n', '\r']))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>();
+    expected_any.reverse();
+    assert_eq!(any, expected_any);
+
+    let mut expected_crlf = BufReader::new(content.as_bytes())
+        .lines()
+        .collect::<Result<Vec<String>, _>>()
+        .unwrap();
+    expected_crlf.reverse();
+    // the lone `\r`s stay in the lines, but never the one of a `\r\n`
+    assert_eq!(crlf, expected_crlf);
+}
@@ -0,0 +1,67 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Seek, SeekFrom, Write},
+};
+
+use read_collection::ReadBack;
+
+use super::TempFile;
+
+const HOLE_SIZE: u64 = 1024 * 1024;
+
+/// Creates a file which contains a hole of [`HOLE_SIZE`] bytes between its head and its tail.
+fn create_sparse_file(name: &str) -> TempFile {
+    let temp = TempFile::new(name, b"head");
+
+    let mut file = OpenOptions::new().write(true).open(temp.path()).unwrap();
+    file.seek(SeekFrom::End(HOLE_SIZE as i64)).unwrap();
+    file.write_all(b"tail").unwrap();
+
+    temp
+}
+
+#[test]
+fn read_back_across_hole() {
+    let temp = create_sparse_file("read_back_across_hole");
+    let expected = fs::read(temp.path()).unwrap();
+
+    let mut file = File::open(temp.path()).unwrap();
+    file.seek(SeekFrom::End(0)).unwrap();
+
+    let mut buffer = vec![1; expected.len()];
+    file.read_back_exact(&mut buffer).unwrap();
+
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn no_short_reads_in_hole() {
+    let temp = create_sparse_file("no_short_reads_in_hole");
+
+    let mut file = File::open(temp.path()).unwrap();
+    let mut remaining = file.seek(SeekFrom::End(0)).unwrap();
+
+    let mut buffer = [1u8; 4096];
+    let mut is_tail = true;
+    while remaining > 0 {
+        let amount = file.read_back(&mut buffer).unwrap();
+        let expected_amount = std::cmp::min(remaining, buffer.len() as u64) as usize;
+        assert_eq!(amount, expected_amount);
+
+        let read = &buffer[..amount];
+        if is_tail {
+            assert!(read.ends_with(b"tail"));
+            assert!(read[..read.len() - 4].iter().all(|&b| b == 0));
+            is_tail = false;
+        } else if remaining > amount as u64 {
+            assert!(read.iter().all(|&b| b == 0));
+        } else {
+            assert!(read.starts_with(b"head"));
+            assert!(read[4..].iter().all(|&b| b == 0));
+        }
+
+        remaining -= amount as u64;
+    }
+
+    assert_eq!(file.read_back(&mut buffer).ok(), Some(0));
+}
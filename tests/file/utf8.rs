@@ -0,0 +1,24 @@
+use std::{
+    fs::File,
+    io::{ErrorKind, Seek, SeekFrom},
+};
+
+use read_collection::ReadBack;
+
+#[test]
+fn read_back_to_string_truncated_at_front() {
+    // the file has been sliced in the middle of an "é"
+    let mut file = File::open("./tests/file/truncated_utf8.txt").unwrap();
+    file.seek(SeekFrom::End(0)).unwrap();
+
+    let mut buffer = String::new();
+    let err = file.read_back_to_string(&mut buffer).unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(
+        err.to_string().contains("truncated at the front"),
+        "unexpected error message: {}",
+        err
+    );
+    assert!(buffer.is_empty());
+}
@@ -1 +1,3 @@
+mod contract;
 pub mod file;
+mod rev_lines;
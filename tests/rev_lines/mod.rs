@@ -0,0 +1,73 @@
+use std::{
+    cell::Cell,
+    io::{self, ErrorKind},
+    rc::Rc,
+};
+
+use read_collection::{BufReadBack, ReadBack};
+
+/// A reader over `data` which fails as soon as it has to read back in front of `fail_at`.
+struct FailingReader<'a> {
+    data: &'a [u8],
+    fail_at: usize,
+    failures: Rc<Cell<usize>>,
+}
+
+impl<'a> FailingReader<'a> {
+    fn new(data: &'a [u8], fail_at: usize, failures: Rc<Cell<usize>>) -> Self {
+        Self {
+            data,
+            fail_at,
+            failures,
+        }
+    }
+}
+
+impl ReadBack for FailingReader<'_> {
+    fn read_back(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = std::cmp::min(buf.len(), self.read_back_fill_buf()?.len());
+        let remaining = self.read_back_fill_buf()?;
+        buf[..amount].copy_from_slice(&remaining[remaining.len() - amount..]);
+        self.read_back_consume(amount);
+        Ok(amount)
+    }
+}
+
+impl BufReadBack for FailingReader<'_> {
+    fn read_back_fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.data.len() <= self.fail_at {
+            self.failures.set(self.failures.get() + 1);
+            return Err(io::Error::other("injected error"));
+        }
+
+        Ok(&self.data[self.fail_at..])
+    }
+
+    fn read_back_consume(&mut self, amt: usize) {
+        let end = self.data.len().saturating_sub(amt);
+        self.data = &self.data[..end];
+    }
+}
+
+#[test]
+fn collect_into_result() {
+    let data = b"first\nsecond\nthird";
+
+    let lines: io::Result<Vec<String>> = data.as_slice().read_back_lines().collect();
+    assert_eq!(lines.unwrap(), ["third", "second", "first"]);
+}
+
+#[test]
+fn collect_into_result_stops_at_first_error() {
+    let data = b"first\nsecond\nthird";
+    // fails once "second" has to be read back
+    let failures = Rc::new(Cell::new(0));
+    let reader = FailingReader::new(data, 10, failures.clone());
+
+    let lines: io::Result<Vec<String>> = reader.read_back_lines().collect();
+    let err = lines.unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert_eq!(err.to_string(), "injected error");
+    assert_eq!(failures.get(), 1);
+}